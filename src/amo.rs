@@ -0,0 +1,209 @@
+//! After-market orders (AMOs).
+//!
+//! Fyers accepts regular orders while a segment is trading and AMOs in
+//! the window before and after, which it sends to the exchange when the
+//! market opens. Orders placed outside both are rejected.
+//!
+//! A [`MarketCalendar`] knows these windows for each [`Segment`], in IST,
+//! along with weekends and the exchange holidays it is given.
+//! [`MarketCalendar::fyers`] has the Fyers timings at the time of writing.
+//! Timings and holidays change from time to time, so override them with
+//! [`MarketCalendar::set_session`] and [`MarketCalendar::add_holiday`].
+//!
+//! Attach a calendar with [`Fyers::with_auto_amo`] to have
+//! [`Fyers::place_order`] mark orders as AMOs during the AMO window.
+//! [`Fyers::amo_orders`] and [`Fyers::cancel_amo_orders`] list and cancel
+//! the pending AMOs.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use fyers::amo::{MarketCalendar, MarketPhase};
+//! use fyers::charges::Segment;
+//! use fyers::ist_datetime;
+//!
+//! let mut calendar = MarketCalendar::fyers();
+//! calendar.add_holiday(NaiveDate::from_ymd_opt(2025, 1, 26).unwrap());
+//!
+//! // Thursday evening
+//! let time = ist_datetime(2025, 1, 2, 18, 0);
+//! assert_eq!(calendar.phase(Segment::NseEquity, time), Some(MarketPhase::Amo));
+//! assert_eq!(calendar.phase(Segment::CommodityFutures, time), Some(MarketPhase::Open));
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+
+use crate::charges::Segment;
+use crate::datetime::IST;
+
+#[cfg(doc)]
+use crate::Fyers;
+
+/// Trading and AMO times of a segment on a trading day, in IST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Session {
+    /// Regular orders are accepted from this time
+    pub open: NaiveTime,
+    /// Regular orders are accepted until this time
+    pub close: NaiveTime,
+    /// AMOs are accepted from this time after the close
+    pub amo_start: NaiveTime,
+    /// AMOs are accepted until this time before the open
+    pub amo_end: NaiveTime,
+}
+
+impl Session {
+    fn hm(open: (u32, u32), close: (u32, u32), amo: [(u32, u32); 2]) -> Self {
+        Self {
+            open: time(open),
+            close: time(close),
+            amo_start: time(amo[0]),
+            amo_end: time(amo[1]),
+        }
+    }
+}
+
+fn time((hour, minute): (u32, u32)) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+/// Which orders a segment accepts at a given time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketPhase {
+    /// Regular orders are accepted
+    Open,
+    /// Only AMOs are accepted
+    Amo,
+    /// No orders are accepted
+    Closed,
+}
+
+/// Trading and AMO windows for each segment.
+///
+/// Saturdays, Sundays and holidays have no session, so AMOs are accepted
+/// all day. Holidays apply to every segment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketCalendar {
+    sessions: HashMap<Segment, Session>,
+    holidays: HashSet<NaiveDate>,
+}
+
+impl MarketCalendar {
+    /// A calendar without any sessions or holidays.
+    pub fn empty() -> Self {
+        Self {
+            sessions: HashMap::new(),
+            holidays: HashSet::new(),
+        }
+    }
+
+    /// Fyers trading and AMO timings for every segment, without holidays.
+    ///
+    /// The commodity close is the one in force while the US observes
+    /// daylight saving time. Set a later one with
+    /// [`set_session`](MarketCalendar::set_session) for the rest of the
+    /// year.
+    pub fn fyers() -> Self {
+        let equity = Session::hm((9, 0), (15, 30), [(15, 45), (8, 57)]);
+        let derivatives = Session::hm((9, 15), (15, 30), [(15, 45), (8, 57)]);
+        let currency = Session::hm((9, 0), (17, 0), [(17, 15), (8, 57)]);
+        let commodity = Session::hm((9, 0), (23, 30), [(23, 45), (8, 57)]);
+
+        let mut calendar = Self::empty();
+
+        for (segment, session) in [
+            (Segment::NseEquity, equity),
+            (Segment::BseEquity, equity),
+            (Segment::NseFutures, derivatives),
+            (Segment::NseOptions, derivatives),
+            (Segment::BseFutures, derivatives),
+            (Segment::BseOptions, derivatives),
+            (Segment::CurrencyFutures, currency),
+            (Segment::CurrencyOptions, currency),
+            (Segment::CommodityFutures, commodity),
+            (Segment::CommodityOptions, commodity),
+        ] {
+            calendar.set_session(segment, session);
+        }
+
+        calendar
+    }
+
+    /// Set the session times of a segment.
+    pub fn set_session(&mut self, segment: Segment, session: Session) {
+        self.sessions.insert(segment, session);
+    }
+
+    /// The session times of a segment, if set.
+    pub fn session(&self, segment: Segment) -> Option<&Session> {
+        self.sessions.get(&segment)
+    }
+
+    /// Add an exchange holiday.
+    pub fn add_holiday(&mut self, date: NaiveDate) {
+        self.holidays.insert(date);
+    }
+
+    /// Add several exchange holidays.
+    pub fn with_holidays(mut self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(dates);
+        self
+    }
+
+    /// Whether `date` is a weekday that isn't a holiday.
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.holidays.contains(&date)
+    }
+
+    /// Which orders `segment` accepts at `time`.
+    ///
+    /// Returns `None` if the segment has no session.
+    pub fn phase(&self, segment: Segment, time: DateTime<Utc>) -> Option<MarketPhase> {
+        let session = self.session(segment)?;
+        let local = time.with_timezone(&IST);
+
+        if !self.is_trading_day(local.date_naive()) {
+            return Some(MarketPhase::Amo);
+        }
+
+        let now = local.time();
+
+        let phase = if session.open <= now && now < session.close {
+            MarketPhase::Open
+        } else if now >= session.amo_start || now < session.amo_end {
+            MarketPhase::Amo
+        } else {
+            MarketPhase::Closed
+        };
+
+        Some(phase)
+    }
+
+    /// The first time from `time` onwards at which `segment` accepts
+    /// regular orders or AMOs.
+    ///
+    /// Returns `None` if the segment has no session.
+    pub fn next_accepting(&self, segment: Segment, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.phase(segment, time)? != MarketPhase::Closed {
+            return Some(time);
+        }
+
+        // Only trading days have closed periods: between the end of the
+        // AMO window and the open, or between the close and the start of
+        // the next AMO window
+        let session = self.session(segment)?;
+        let local = time.with_timezone(&IST);
+        let next = if local.time() < session.open {
+            session.open
+        } else {
+            session.amo_start
+        };
+
+        IST.from_local_datetime(&local.date_naive().and_time(next))
+            .single()
+            .map(|t| t.with_timezone(&Utc))
+    }
+}
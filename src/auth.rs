@@ -7,31 +7,52 @@
 //!    using [`generate_tokens`].
 //! 3. Refresh an expired access token using an existing refresh token with
 //!    [`refresh_tokens`].
+//! 4. Invalidate an access token that is no longer needed with [`logout`].
+//!
+//! These are also available on [`AuthClient`], which reuses one HTTP client
+//! and can be pointed at a different base URL, such as a local mock.
 //!
 //! The initial authorization flow is interactive: the user must open the
 //! generated URL in a browser and complete the login process before tokens
-//! can be issued.
+//! can be issued. [`RedirectListener`] and [`login_interactive`] catch the
+//! redirect on a local port, so the URL doesn't have to be copied over.
+//! With the `totp-login` feature, `HeadlessLogin` runs the same login
+//! without a browser, using a TOTP secret.
 //!
 //! Refresh tokens have a limited validity period (usually 15 days), during which new access
 //! tokens can be generated without requiring the user to authenticate again.
+//! Access tokens expire at the end of the day (IST). Use
+//! [`Tokens::should_refresh`] or [`TokenInfo`] to find out when.
 //!
 //! This module is intentionally lightweight and only provides the
 //! building blocks for authentication.
-//! Token storage, refresh scheduling, and lifecycle management are
-//! expected to be handled by the application.
+//! A [`Fyers`](crate::Fyers) client can refresh its own access token through a
+//! [`TokenProvider`] such as [`RefreshTokenProvider`], and tokens can be
+//! persisted with a [`TokenStore`] such as [`FileTokenStore`].
 
-use reqwest::Client;
 use url::Url;
 
+mod client;
 mod error;
+#[cfg(feature = "totp-login")]
+mod headless;
 mod helpers;
+mod provider;
+mod redirect;
+mod store;
+mod token_info;
 mod types;
 
-pub use error::AuthError;
+pub use client::AuthClient;
+pub use error::{AuthError, TokenStoreError};
+#[cfg(feature = "totp-login")]
+pub use headless::HeadlessLogin;
+pub use provider::{RefreshFuture, RefreshTokenProvider, TokenProvider};
+pub use redirect::{RedirectListener, login_interactive};
+pub use store::{FileTokenStore, MemoryTokenStore, StoredTokens, TokenStore};
+pub use token_info::TokenInfo;
 pub use types::Tokens;
 
-use self::types::{ApiStatus, GenerateTokenRequest, GenerateTokenResponse, RefreshTokenRequest};
-
 /// Generates the Fyers authentication URL for the first step of the auth flow.
 ///
 /// This URL must be opened by the user in a browser. After successful login,
@@ -40,14 +61,8 @@ use self::types::{ApiStatus, GenerateTokenRequest, GenerateTokenResponse, Refres
 ///
 /// Pass in that URL to the [`generate_tokens`] function.
 pub fn generate_url(client_id: &str, redirect_uri: &str, state: &str) -> Result<Url, AuthError> {
-    let mut url = Url::parse("https://api-t1.fyers.in/api/v3/generate-authcode")?;
-    url.query_pairs_mut()
-        .append_pair("client_id", client_id)
-        .append_pair("redirect_uri", redirect_uri)
-        .append_pair("state", state)
-        .append_pair("response_type", "code");
-
-    Ok(url)
+    // The secret key isn't needed for this
+    AuthClient::new(client_id, "").generate_url(redirect_uri, state)
 }
 
 /// Exchanges an authorization code for access and refresh tokens.
@@ -78,45 +93,9 @@ pub async fn generate_tokens(
     secret_key: &str,
     url: &str,
 ) -> Result<Tokens, AuthError> {
-    let app_id_hash = helpers::compute_app_id_hash(client_id, secret_key);
-
-    let parsed_url = Url::parse(url)?;
-
-    let auth_code =
-        helpers::get_query_param(&parsed_url, "auth_code").ok_or(AuthError::MissingAuthCode)?;
-
-    let client = Client::new();
-
-    let response = client
-        .post("https://api-t1.fyers.in/api/v3/validate-authcode")
-        .json(&GenerateTokenRequest::new(&app_id_hash, &auth_code))
-        .send()
-        .await?;
-
-    let api_response = response.json::<GenerateTokenResponse>().await?;
-
-    match api_response.s {
-        ApiStatus::Error => Err(AuthError::Api {
-            code: api_response.code,
-            message: api_response.message,
-        }),
-        ApiStatus::Ok => {
-            let access_token = api_response.access_token.ok_or_else(|| AuthError::Api {
-                code: api_response.code,
-                message: "missing access_token in success response".into(),
-            })?;
-
-            let refresh_token = api_response.refresh_token.ok_or_else(|| AuthError::Api {
-                code: api_response.code,
-                message: "missing refresh_token in success response".into(),
-            })?;
-
-            Ok(Tokens {
-                access_token,
-                refresh_token,
-            })
-        }
-    }
+    AuthClient::new(client_id, secret_key)
+        .generate_tokens(url)
+        .await
 }
 
 /// Generates a new access token using an existing refresh token.
@@ -148,36 +127,31 @@ pub async fn refresh_tokens(
     refresh_token: &str,
     pin: &str,
 ) -> Result<Tokens, AuthError> {
-    let app_id_hash = helpers::compute_app_id_hash(client_id, secret_key);
-
-    let client = Client::new();
-
-    let response = client
-        .post("https://api-t1.fyers.in/api/v3/validate-refresh-token")
-        .json(&RefreshTokenRequest::new(&app_id_hash, refresh_token, pin))
-        .send()
-        .await?;
-
-    let api_response = response.json::<GenerateTokenResponse>().await?;
-
-    match api_response.s {
-        ApiStatus::Error => Err(AuthError::Api {
-            code: api_response.code,
-            message: api_response.message,
-        }),
-        ApiStatus::Ok => {
-            let access_token = api_response.access_token.ok_or_else(|| AuthError::Api {
-                code: api_response.code,
-                message: "missing access_token in refresh response".into(),
-            })?;
+    AuthClient::new(client_id, secret_key)
+        .refresh_tokens(refresh_token, pin)
+        .await
+}
 
-            Ok(Tokens {
-                access_token,
-                // Refresh token isn't returned usually so just return the existing one
-                refresh_token: api_response
-                    .refresh_token
-                    .unwrap_or_else(|| refresh_token.to_string()),
-            })
-        }
-    }
+/// Invalidates an access token.
+///
+/// Use this when a token is no longer needed or may have leaked. The
+/// token can't be used for any further requests, but the refresh token
+/// can still be used to obtain a new one.
+///
+/// [`Fyers::logout`](crate::Fyers::logout) does the same for the token of
+/// a client.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use fyers::auth::logout;
+///
+/// logout("your_client_id", "your_access_token").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn logout(client_id: &str, access_token: &str) -> Result<(), AuthError> {
+    // The secret key isn't needed for this
+    AuthClient::new(client_id, "").logout(access_token).await
 }
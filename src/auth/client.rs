@@ -0,0 +1,218 @@
+use std::fmt;
+
+use reqwest::Client;
+use reqwest::header::AUTHORIZATION;
+use url::Url;
+
+use super::types::{
+    ApiStatus, GenerateTokenRequest, GenerateTokenResponse, RefreshTokenRequest, StatusResponse,
+};
+use super::{AuthError, RedirectListener, Tokens, helpers};
+
+/// Client for the Fyers authentication API.
+///
+/// Holds the app credentials, the base URL and the HTTP client used by
+/// every call, so the same connection pool is reused and the base URL can
+/// point to a local mock in tests.
+///
+/// The free functions in [`auth`](super), like
+/// [`generate_tokens`](super::generate_tokens), use an `AuthClient` with
+/// the default settings.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run() -> Result<(), fyers::auth::AuthError> {
+/// use fyers::auth::AuthClient;
+///
+/// let auth = AuthClient::new("your_client_id", "your_secret_key");
+///
+/// let url = auth.generate_url("https://example.com/redirect", "some_state")?;
+/// println!("Log in at {url}");
+///
+/// let tokens = auth
+///     .generate_tokens("https://example.com/redirect?auth_code=XYZ")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AuthClient {
+    client_id: String,
+    secret_key: String,
+    base_url: String,
+    http: Client,
+}
+
+impl AuthClient {
+    /// Create a client for an app.
+    pub fn new(client_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            secret_key: secret_key.into(),
+            base_url: "https://api-t1.fyers.in/api/v3".into(),
+            http: Client::new(),
+        }
+    }
+
+    /// Set the base URL of the authentication API.
+    ///
+    /// Defaults to `https://api-t1.fyers.in/api/v3`.
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    /// Use an existing [`reqwest::Client`].
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http = client;
+        self
+    }
+
+    /// Generates the URL the user has to log in at.
+    ///
+    /// See [`generate_url`](super::generate_url).
+    pub fn generate_url(&self, redirect_uri: &str, state: &str) -> Result<Url, AuthError> {
+        let mut url = Url::parse(&format!("{}/generate-authcode", self.base_url))?;
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", redirect_uri)
+            .append_pair("state", state)
+            .append_pair("response_type", "code");
+
+        Ok(url)
+    }
+
+    /// Exchanges the auth code in a redirect URL for tokens.
+    ///
+    /// See [`generate_tokens`](super::generate_tokens).
+    pub async fn generate_tokens(&self, url: &str) -> Result<Tokens, AuthError> {
+        let app_id_hash = helpers::compute_app_id_hash(&self.client_id, &self.secret_key);
+
+        let parsed_url = Url::parse(url)?;
+
+        let auth_code =
+            helpers::get_query_param(&parsed_url, "auth_code").ok_or(AuthError::MissingAuthCode)?;
+
+        let response = self
+            .http
+            .post(format!("{}/validate-authcode", self.base_url))
+            .json(&GenerateTokenRequest::new(&app_id_hash, &auth_code))
+            .send()
+            .await?;
+
+        let api_response = response.json::<GenerateTokenResponse>().await?;
+
+        match api_response.s {
+            ApiStatus::Error => Err(AuthError::Api {
+                code: api_response.code,
+                message: api_response.message,
+            }),
+            ApiStatus::Ok => {
+                let access_token = api_response.access_token.ok_or_else(|| AuthError::Api {
+                    code: api_response.code,
+                    message: "missing access_token in success response".into(),
+                })?;
+
+                let refresh_token = api_response.refresh_token.ok_or_else(|| AuthError::Api {
+                    code: api_response.code,
+                    message: "missing refresh_token in success response".into(),
+                })?;
+
+                Ok(Tokens {
+                    access_token,
+                    refresh_token,
+                })
+            }
+        }
+    }
+
+    /// Generates a new access token using a refresh token.
+    ///
+    /// See [`refresh_tokens`](super::refresh_tokens).
+    pub async fn refresh_tokens(
+        &self,
+        refresh_token: &str,
+        pin: &str,
+    ) -> Result<Tokens, AuthError> {
+        let app_id_hash = helpers::compute_app_id_hash(&self.client_id, &self.secret_key);
+
+        let response = self
+            .http
+            .post(format!("{}/validate-refresh-token", self.base_url))
+            .json(&RefreshTokenRequest::new(&app_id_hash, refresh_token, pin))
+            .send()
+            .await?;
+
+        let api_response = response.json::<GenerateTokenResponse>().await?;
+
+        match api_response.s {
+            ApiStatus::Error => Err(AuthError::Api {
+                code: api_response.code,
+                message: api_response.message,
+            }),
+            ApiStatus::Ok => {
+                let access_token = api_response.access_token.ok_or_else(|| AuthError::Api {
+                    code: api_response.code,
+                    message: "missing access_token in refresh response".into(),
+                })?;
+
+                Ok(Tokens {
+                    access_token,
+                    // Refresh token isn't returned usually so just return the existing one
+                    refresh_token: api_response
+                        .refresh_token
+                        .unwrap_or_else(|| refresh_token.to_string()),
+                })
+            }
+        }
+    }
+
+    /// Invalidates an access token.
+    ///
+    /// See [`logout`](super::logout).
+    pub async fn logout(&self, access_token: &str) -> Result<(), AuthError> {
+        let response = self
+            .http
+            .post(format!("{}/logout", self.base_url))
+            .header(AUTHORIZATION, format!("{}:{access_token}", self.client_id))
+            .json(&serde_json::json!({}))
+            .send()
+            .await?;
+
+        let api_response = response.json::<StatusResponse>().await?;
+
+        match api_response.s {
+            ApiStatus::Error => Err(AuthError::Api {
+                code: api_response.code,
+                message: api_response.message,
+            }),
+            ApiStatus::Ok => Ok(()),
+        }
+    }
+
+    /// Runs the whole browser login in one call.
+    ///
+    /// See [`login_interactive`](super::login_interactive).
+    pub async fn login_interactive(
+        &self,
+        redirect_uri: &str,
+        open: impl FnOnce(&Url),
+    ) -> Result<Tokens, AuthError> {
+        let listener = RedirectListener::bind(redirect_uri).await?;
+        let state = helpers::random_state();
+
+        open(&self.generate_url(listener.redirect_uri(), &state)?);
+
+        listener.wait_for_tokens(self, &state).await
+    }
+}
+
+impl fmt::Debug for AuthClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthClient")
+            .field("client_id", &self.client_id)
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,7 +1,11 @@
 use thiserror::Error;
 
 /// Errors that could occur during authentication.
+///
+/// This enum is marked as `#[non_exhaustive]` so new variants may be added
+/// in future releases without breaking changes.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum AuthError {
     /// Invalid URL passed to generate_tokens
     #[error("invalid URL")]
@@ -11,6 +15,22 @@ pub enum AuthError {
     #[error("auth_code not found in URL")]
     MissingAuthCode,
 
+    /// The access token couldn't be decoded
+    #[error("malformed access token: {0}")]
+    MalformedToken(String),
+
+    /// The redirect URI can't be listened on
+    #[error("redirect URI must be a plain http URL on this machine: {0}")]
+    InvalidRedirectUri(String),
+
+    /// IO error while listening for the login redirect
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The TOTP secret isn't valid base32
+    #[error("invalid TOTP secret")]
+    InvalidTotpSecret,
+
     /// HTTP error during authentication
     #[error("http error")]
     Http(#[from] reqwest::Error),
@@ -24,3 +44,28 @@ pub enum AuthError {
         message: String,
     },
 }
+
+/// Errors that could occur while loading or saving tokens.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum TokenStoreError {
+    /// Reading or writing the token file failed
+    #[error("token store io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The stored tokens couldn't be serialized or deserialized
+    #[error("invalid token file: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Encrypting the tokens failed
+    #[error("failed to encrypt tokens")]
+    Encryption,
+
+    /// Decrypting the tokens failed, usually because of a wrong passphrase
+    #[error("failed to decrypt tokens (wrong passphrase or corrupted file)")]
+    Decryption,
+
+    /// The number of key derivation rounds is too low or too high
+    #[error("kdf rounds must be between 1000 and 10000000, got {0}")]
+    InvalidKdfRounds(u32),
+}
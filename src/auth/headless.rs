@@ -0,0 +1,327 @@
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::types::ApiStatus;
+use super::{AuthClient, AuthError, RefreshFuture, TokenProvider, Tokens};
+
+mod totp;
+
+// Don't send a code that is about to expire
+const MIN_CODE_VALIDITY: u64 = 5;
+
+/// Log in without a browser, using a TOTP secret.
+///
+/// Runs the same steps as the Fyers login page: request a login OTP,
+/// verify a TOTP code, verify the PIN and obtain an auth code, which is
+/// then exchanged for [`Tokens`] like [`generate_tokens`](super::generate_tokens)
+/// does.
+///
+/// External TOTP must be enabled on the account. The TOTP secret is the
+/// base32 key shown when setting it up.
+///
+/// `HeadlessLogin` also implements [`TokenProvider`], so a client can log
+/// in again by itself once the refresh token has expired too.
+///
+/// Requires the `totp-login` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run() -> Result<(), fyers::auth::AuthError> {
+/// use fyers::auth::HeadlessLogin;
+///
+/// let tokens = HeadlessLogin::new(
+///     "your_client_id",
+///     "your_secret_key",
+///     "https://example.com/redirect",
+///     "your_fyers_id",
+///     "your_totp_secret",
+///     "your_pin",
+/// )
+/// .login()
+/// .await?;
+///
+/// println!("Access token: {}", tokens.access_token);
+/// # Ok(())
+/// # }
+/// ```
+pub struct HeadlessLogin {
+    client_id: String,
+    secret_key: String,
+    redirect_uri: String,
+    fy_id: String,
+    totp_secret: String,
+    pin: String,
+    state: String,
+    login_url: String,
+    api_url: String,
+    http: Client,
+}
+
+impl HeadlessLogin {
+    /// Create a login flow for an app and a user.
+    pub fn new(
+        client_id: impl Into<String>,
+        secret_key: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        fy_id: impl Into<String>,
+        totp_secret: impl Into<String>,
+        pin: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            secret_key: secret_key.into(),
+            redirect_uri: redirect_uri.into(),
+            fy_id: fy_id.into(),
+            totp_secret: totp_secret.into(),
+            pin: pin.into(),
+            state: "headless".into(),
+            login_url: "https://api-t2.fyers.in/vagator/v2".into(),
+            api_url: "https://api-t1.fyers.in/api/v3".into(),
+            http: Client::new(),
+        }
+    }
+
+    /// Set the `state` sent along with the auth code request.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = state.into();
+        self
+    }
+
+    /// Set the base URL of the login API, used for the OTP and PIN steps.
+    ///
+    /// Defaults to `https://api-t2.fyers.in/vagator/v2`.
+    pub fn login_url(mut self, url: impl Into<String>) -> Self {
+        self.login_url = url.into();
+        self
+    }
+
+    /// Set the base URL of the API used to obtain and validate the auth code.
+    ///
+    /// Defaults to `https://api-t1.fyers.in/api/v3`.
+    pub fn api_url(mut self, url: impl Into<String>) -> Self {
+        self.api_url = url.into();
+        self
+    }
+
+    /// Use an existing [`reqwest::Client`].
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http = client;
+        self
+    }
+
+    /// Log in and return fresh tokens.
+    pub async fn login(&self) -> Result<Tokens, AuthError> {
+        let redirect = self.auth_code_redirect().await?;
+
+        AuthClient::new(&self.client_id, &self.secret_key)
+            .base_url(&self.api_url)
+            .http_client(self.http.clone())
+            .generate_tokens(&redirect)
+            .await
+    }
+
+    // Run the login steps and return the redirect URL holding the auth code
+    async fn auth_code_redirect(&self) -> Result<String, AuthError> {
+        // Check the secret before anything is sent
+        totp::totp(&self.totp_secret, 0)?;
+
+        let otp = self
+            .post_step(
+                "send_login_otp_v2",
+                &SendLoginOtpRequest {
+                    fy_id: BASE64.encode(&self.fy_id),
+                    app_id: "2",
+                },
+            )
+            .await?;
+        let request_key = require(otp.request_key, otp.code, "request_key")?;
+
+        let code = self.current_totp().await?;
+        let verified = self
+            .post_step(
+                "verify_otp",
+                &VerifyOtpRequest {
+                    request_key: &request_key,
+                    otp: &code,
+                },
+            )
+            .await?;
+        let request_key = require(verified.request_key, verified.code, "request_key")?;
+
+        let pin = self
+            .post_step(
+                "verify_pin_v2",
+                &VerifyPinRequest {
+                    request_key: &request_key,
+                    identity_type: "pin",
+                    identifier: BASE64.encode(&self.pin),
+                },
+            )
+            .await?;
+        let access_token = require(
+            pin.data.and_then(|data| data.access_token),
+            pin.code,
+            "access_token",
+        )?;
+
+        // A client ID looks like "APPID-100", where 100 is the app type
+        let (app_id, app_type) = self
+            .client_id
+            .split_once('-')
+            .unwrap_or((&self.client_id, "100"));
+
+        let response = self
+            .http
+            .post(format!("{}/token", self.api_url))
+            .bearer_auth(access_token)
+            .json(&AuthCodeRequest {
+                fyers_id: &self.fy_id,
+                app_id,
+                redirect_uri: &self.redirect_uri,
+                app_type,
+                code_challenge: "",
+                state: &self.state,
+                scope: "",
+                nonce: "",
+                response_type: "code",
+                create_cookie: true,
+            })
+            .send()
+            .await?;
+
+        let token = check(response.json::<StepResponse>().await?)?;
+        require(token.url, token.code, "Url")
+    }
+
+    // Send one of the login steps
+    async fn post_step<B: Serialize>(
+        &self,
+        step: &str,
+        body: &B,
+    ) -> Result<StepResponse, AuthError> {
+        let response = self
+            .http
+            .post(format!("{}/{step}", self.login_url))
+            .json(body)
+            .send()
+            .await?;
+
+        // Failed steps come with an error status, but still have a JSON body
+        check(response.json::<StepResponse>().await?)
+    }
+
+    // A TOTP code that stays valid for at least a few more seconds
+    async fn current_totp(&self) -> Result<String, AuthError> {
+        let mut now = unix_time();
+        let remaining = totp::STEP - now % totp::STEP;
+
+        if remaining < MIN_CODE_VALIDITY {
+            tokio::time::sleep(Duration::from_secs(remaining)).await;
+            now += remaining;
+        }
+
+        totp::totp(&self.totp_secret, now)
+    }
+}
+
+impl TokenProvider for HeadlessLogin {
+    fn refresh(&self) -> RefreshFuture<'_> {
+        Box::pin(self.login())
+    }
+}
+
+impl fmt::Debug for HeadlessLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HeadlessLogin")
+            .field("client_id", &self.client_id)
+            .field("fy_id", &self.fy_id)
+            .field("login_url", &self.login_url)
+            .field("api_url", &self.api_url)
+            .finish_non_exhaustive()
+    }
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+fn check(response: StepResponse) -> Result<StepResponse, AuthError> {
+    let failed =
+        matches!(response.s, Some(ApiStatus::Error)) || response.code.is_some_and(|code| code < 0);
+
+    if failed {
+        return Err(AuthError::Api {
+            code: response.code.unwrap_or(-1),
+            message: response.message.unwrap_or_default(),
+        });
+    }
+
+    Ok(response)
+}
+
+fn require<T>(value: Option<T>, code: Option<i32>, field: &str) -> Result<T, AuthError> {
+    value.ok_or_else(|| AuthError::Api {
+        code: code.unwrap_or_default(),
+        message: format!("missing {field} in login response"),
+    })
+}
+
+#[derive(Serialize)]
+struct SendLoginOtpRequest {
+    fy_id: String,
+    app_id: &'static str,
+}
+
+#[derive(Serialize)]
+struct VerifyOtpRequest<'a> {
+    request_key: &'a str,
+    otp: &'a str,
+}
+
+#[derive(Serialize)]
+struct VerifyPinRequest<'a> {
+    request_key: &'a str,
+    identity_type: &'a str,
+    identifier: String,
+}
+
+#[derive(Serialize)]
+struct AuthCodeRequest<'a> {
+    fyers_id: &'a str,
+    app_id: &'a str,
+    redirect_uri: &'a str,
+    #[serde(rename = "appType")]
+    app_type: &'a str,
+    code_challenge: &'a str,
+    state: &'a str,
+    scope: &'a str,
+    nonce: &'a str,
+    response_type: &'a str,
+    create_cookie: bool,
+}
+
+// The login steps share one loosely typed response
+#[derive(Deserialize)]
+struct StepResponse {
+    s: Option<ApiStatus>,
+    code: Option<i32>,
+    message: Option<String>,
+    request_key: Option<String>,
+    data: Option<StepData>,
+    #[serde(rename = "Url")]
+    url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StepData {
+    access_token: Option<String>,
+}
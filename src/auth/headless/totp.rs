@@ -0,0 +1,86 @@
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+use crate::auth::AuthError;
+
+// Length of a TOTP time step in seconds
+pub(super) const STEP: u64 = 30;
+
+// Generate a 6 digit RFC 6238 code for a base32 `secret` at `unix_time`
+pub(super) fn totp(secret: &str, unix_time: u64) -> Result<String, AuthError> {
+    let key = decode_base32(secret).ok_or(AuthError::InvalidTotpSecret)?;
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(&key).map_err(|_| AuthError::InvalidTotpSecret)?;
+    mac.update(&(unix_time / STEP).to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    // Dynamic truncation as described in RFC 4226
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let code = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+
+    Ok(format!("{:06}", code % 1_000_000))
+}
+
+// Decode RFC 4648 base32, ignoring case, spaces and padding
+fn decode_base32(input: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for c in input.chars().filter(|c| !c.is_whitespace() && *c != '=') {
+        let value = match c.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u32 - 'A' as u32,
+            c @ '2'..='7' => c as u32 - '2' as u32 + 26,
+            _ => return None,
+        };
+
+        buffer = (buffer << 5) | value;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    (!bytes.is_empty()).then_some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "12345678901234567890" from the RFC 6238 test vectors
+    const SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    #[test]
+    fn rfc_6238_vectors() {
+        assert_eq!(totp(SECRET, 59).unwrap(), "287082");
+        assert_eq!(totp(SECRET, 1_111_111_109).unwrap(), "081804");
+        assert_eq!(totp(SECRET, 1_234_567_890).unwrap(), "005924");
+        assert_eq!(totp(SECRET, 2_000_000_000).unwrap(), "279037");
+    }
+
+    #[test]
+    fn secret_is_case_and_space_insensitive() {
+        assert_eq!(
+            totp("gezd gnbv gy3t qojq gezd gnbv gy3t qojq", 59).unwrap(),
+            "287082"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_secret() {
+        assert!(matches!(
+            totp("not base32!", 59),
+            Err(AuthError::InvalidTotpSecret)
+        ));
+        assert!(matches!(totp("", 59), Err(AuthError::InvalidTotpSecret)));
+    }
+}
@@ -16,3 +16,17 @@ pub fn get_query_param(url: &Url, key: &str) -> Option<String> {
         .find(|(k, _)| k == key)
         .map(|(_, v)| v.into_owned())
 }
+
+// A random value for the `state` parameter of the login URL
+//
+// `RandomState` is keyed from OS randomness, so its hashes can't be
+// predicted from outside the process.
+pub fn random_state() -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let high = RandomState::new().build_hasher().finish();
+    let low = RandomState::new().build_hasher().finish();
+
+    format!("{high:016x}{low:016x}")
+}
@@ -0,0 +1,134 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+use super::{AuthClient, AuthError, Tokens};
+
+#[cfg(doc)]
+use crate::{Fyers, FyersBuilder};
+
+/// A boxed future returned by [`TokenProvider::refresh`].
+pub type RefreshFuture<'a> = Pin<Box<dyn Future<Output = Result<Tokens, AuthError>> + Send + 'a>>;
+
+/// A source of fresh tokens for a [`Fyers`] client.
+///
+/// When a request fails because the access token has expired or is
+/// invalid, the client calls [`refresh`](TokenProvider::refresh), swaps in
+/// the new access token for itself and all of its clones, and retries the
+/// request once.
+///
+/// [`RefreshTokenProvider`] implements this using a refresh token. Implement
+/// the trait yourself to obtain tokens some other way.
+///
+/// Attach a provider with [`FyersBuilder::token_provider`].
+pub trait TokenProvider: Send + Sync {
+    /// Obtain new tokens.
+    fn refresh(&self) -> RefreshFuture<'_>;
+}
+
+type RefreshCallback = Box<dyn Fn(&Tokens) + Send + Sync>;
+
+/// A [`TokenProvider`] that uses a refresh token.
+///
+/// Refresh tokens are valid for 15 days. Use
+/// [`on_refresh`](RefreshTokenProvider::on_refresh) to persist the new
+/// tokens whenever they change.
+///
+/// # Example
+///
+/// ```no_run
+/// use fyers::Fyers;
+/// use fyers::auth::RefreshTokenProvider;
+///
+/// # fn run() -> Result<(), fyers::FyersError> {
+/// let provider = RefreshTokenProvider::new(
+///     "your_client_id",
+///     "your_secret_key",
+///     "existing_refresh_token",
+///     "your_pin",
+/// )
+/// .on_refresh(|tokens| {
+///     // Save the tokens somewhere
+///     println!("New access token: {}", tokens.access_token);
+/// });
+///
+/// let fyers = Fyers::builder("your_client_id", "existing_access_token")
+///     .token_provider(provider)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RefreshTokenProvider {
+    auth: AuthClient,
+    pin: String,
+    refresh_token: Mutex<String>,
+    on_refresh: Option<RefreshCallback>,
+}
+
+impl RefreshTokenProvider {
+    /// Create a provider from a refresh token.
+    pub fn new(
+        client_id: impl Into<String>,
+        secret_key: impl Into<String>,
+        refresh_token: impl Into<String>,
+        pin: impl Into<String>,
+    ) -> Self {
+        Self::with_client(AuthClient::new(client_id, secret_key), refresh_token, pin)
+    }
+
+    /// Create a provider that refreshes tokens through `auth`.
+    pub fn with_client(
+        auth: AuthClient,
+        refresh_token: impl Into<String>,
+        pin: impl Into<String>,
+    ) -> Self {
+        Self {
+            auth,
+            pin: pin.into(),
+            refresh_token: Mutex::new(refresh_token.into()),
+            on_refresh: None,
+        }
+    }
+
+    /// Call `callback` with the new tokens after every successful refresh.
+    pub fn on_refresh<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Tokens) + Send + Sync + 'static,
+    {
+        self.on_refresh = Some(Box::new(callback));
+        self
+    }
+}
+
+impl TokenProvider for RefreshTokenProvider {
+    fn refresh(&self) -> RefreshFuture<'_> {
+        Box::pin(async move {
+            let refresh_token = self
+                .refresh_token
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone();
+
+            let tokens = self.auth.refresh_tokens(&refresh_token, &self.pin).await?;
+
+            // Fyers may hand out a new refresh token
+            *self.refresh_token.lock().unwrap_or_else(|e| e.into_inner()) =
+                tokens.refresh_token.clone();
+
+            if let Some(callback) = &self.on_refresh {
+                callback(&tokens);
+            }
+
+            Ok(tokens)
+        })
+    }
+}
+
+impl fmt::Debug for RefreshTokenProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefreshTokenProvider")
+            .field("auth", &self.auth)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,245 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinSet;
+use url::Url;
+
+use super::{AuthClient, AuthError, Tokens, helpers};
+
+#[cfg(doc)]
+use super::generate_url;
+
+// Largest request head we are willing to read
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+
+// How long a connection may take to send its request head. Browsers open
+// connections ahead of time that may never send anything.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A local HTTP listener that captures the redirect after a browser login.
+///
+/// Bind it to your app's `redirect_uri`, which must point to this machine
+/// (`localhost`, `127.0.0.1` or `[::1]`) over plain HTTP. Once the user has
+/// logged in, Fyers redirects the browser to the listener, which checks the
+/// `state` parameter against the one passed to [`generate_url`] and picks up
+/// the `auth_code`.
+///
+/// [`login_interactive`] does all of this in one call.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run() -> Result<(), fyers::auth::AuthError> {
+/// use fyers::auth::{AuthClient, RedirectListener};
+///
+/// let auth = AuthClient::new("your_client_id", "your_secret_key");
+/// let listener = RedirectListener::bind("http://127.0.0.1:8080/callback").await?;
+///
+/// let url = auth.generate_url(listener.redirect_uri(), "some_state")?;
+/// println!("Log in at {url}");
+///
+/// let tokens = listener.wait_for_tokens(&auth, "some_state").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RedirectListener {
+    listener: TcpListener,
+    redirect_uri: Url,
+}
+
+impl RedirectListener {
+    /// Start listening on `redirect_uri`.
+    ///
+    /// Port `0` binds a free port, which is then reflected in
+    /// [`redirect_uri`](Self::redirect_uri).
+    pub async fn bind(redirect_uri: &str) -> Result<Self, AuthError> {
+        let mut redirect_uri = Url::parse(redirect_uri)?;
+
+        let ip = match redirect_uri.host() {
+            _ if redirect_uri.scheme() != "http" => None,
+            Some(url::Host::Domain("localhost")) => Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+            Some(url::Host::Ipv4(ip)) if ip.is_loopback() => Some(IpAddr::V4(ip)),
+            Some(url::Host::Ipv6(ip)) if ip.is_loopback() => Some(IpAddr::V6(ip)),
+            _ => None,
+        }
+        .ok_or_else(|| AuthError::InvalidRedirectUri(redirect_uri.to_string()))?;
+
+        let port = redirect_uri.port_or_known_default().unwrap_or(80);
+        let listener = TcpListener::bind(SocketAddr::new(ip, port)).await?;
+
+        if port == 0 {
+            let port = listener.local_addr()?.port();
+            // Can't fail for http URLs
+            let _ = redirect_uri.set_port(Some(port));
+        }
+
+        Ok(Self {
+            listener,
+            redirect_uri,
+        })
+    }
+
+    /// The redirect URI the listener is bound to.
+    ///
+    /// Pass this to [`generate_url`]. It must match the redirect URI of
+    /// your app exactly.
+    pub fn redirect_uri(&self) -> &str {
+        self.redirect_uri.as_str()
+    }
+
+    /// Wait for the login redirect and return the full redirect URL.
+    ///
+    /// Requests for other paths, like the browser asking for a favicon,
+    /// get a `404` and are otherwise ignored. So do redirects carrying a
+    /// different `state`, which get a `400`. Connections are read
+    /// concurrently, so an idle one doesn't hold up the others.
+    ///
+    /// Fails with [`AuthError::MissingAuthCode`] if the redirect has no
+    /// auth code, such as when the user cancelled the login.
+    pub async fn wait_for_redirect(&self, state: &str) -> Result<Url, AuthError> {
+        let mut requests = JoinSet::new();
+
+        loop {
+            let (mut stream, target) = tokio::select! {
+                accepted = self.listener.accept() => {
+                    let (stream, _) = accepted?;
+                    requests.spawn(read_request(stream));
+                    continue;
+                }
+                Some(request) = requests.join_next() => match request {
+                    Ok(Some(request)) => request,
+                    // A client that disconnects early or never sends a
+                    // request shouldn't end the login
+                    _ => continue,
+                },
+            };
+
+            let Ok(url) = self.redirect_uri.join(&target) else {
+                respond(&mut stream, "400 Bad Request", "Invalid request.").await;
+                continue;
+            };
+
+            if url.path() != self.redirect_uri.path() {
+                respond(&mut stream, "404 Not Found", "Not found.").await;
+                continue;
+            }
+
+            if helpers::get_query_param(&url, "state").as_deref() != Some(state) {
+                respond(
+                    &mut stream,
+                    "400 Bad Request",
+                    "Login failed: state mismatch.",
+                )
+                .await;
+                continue;
+            }
+
+            if helpers::get_query_param(&url, "auth_code").is_none() {
+                respond(&mut stream, "400 Bad Request", "Login failed.").await;
+                return Err(AuthError::MissingAuthCode);
+            }
+
+            respond(
+                &mut stream,
+                "200 OK",
+                "Login complete. You can close this window.",
+            )
+            .await;
+            return Ok(url);
+        }
+    }
+
+    /// Wait for the login redirect and exchange its auth code for tokens.
+    ///
+    /// See [`wait_for_redirect`](Self::wait_for_redirect).
+    pub async fn wait_for_tokens(
+        &self,
+        auth: &AuthClient,
+        state: &str,
+    ) -> Result<Tokens, AuthError> {
+        let url = self.wait_for_redirect(state).await?;
+        auth.generate_tokens(url.as_str()).await
+    }
+}
+
+/// Runs the whole browser login in one call.
+///
+/// Binds a [`RedirectListener`] on `redirect_uri`, generates the login URL
+/// with a random `state` and hands it to `open`, which should show it to
+/// the user or open it in a browser. The tokens are returned once the user
+/// has logged in.
+///
+/// This waits for as long as the user takes. Wrap it in
+/// [`tokio::time::timeout`] to give up at some point.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run() -> Result<(), fyers::auth::AuthError> {
+/// use fyers::auth::login_interactive;
+///
+/// let tokens = login_interactive(
+///     "your_client_id",
+///     "your_secret_key",
+///     "http://127.0.0.1:8080/callback",
+///     |url| println!("Log in at {url}"),
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn login_interactive(
+    client_id: &str,
+    secret_key: &str,
+    redirect_uri: &str,
+    open: impl FnOnce(&Url),
+) -> Result<Tokens, AuthError> {
+    AuthClient::new(client_id, secret_key)
+        .login_interactive(redirect_uri, open)
+        .await
+}
+
+// Read the request target of a connection, giving up on connections that
+// take too long
+async fn read_request(mut stream: TcpStream) -> Option<(TcpStream, String)> {
+    let read = tokio::time::timeout(REQUEST_TIMEOUT, read_request_target(&mut stream)).await;
+    let target = read.ok()?.ok()??;
+    Some((stream, target))
+}
+
+// Read the request head and return the request target of a GET request
+async fn read_request_target(stream: &mut TcpStream) -> std::io::Result<Option<String>> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+
+    while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 || buf.len() + n > MAX_REQUEST_SIZE {
+            return Ok(None);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let head = String::from_utf8_lossy(&buf);
+    let mut parts = head.lines().next().unwrap_or_default().split(' ');
+
+    match (parts.next(), parts.next()) {
+        (Some("GET"), Some(target)) => Ok(Some(target.to_string())),
+        _ => Ok(None),
+    }
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, message: &str) {
+    let body = format!("<!DOCTYPE html><html><body><p>{message}</p></body></html>");
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    // The browser going away doesn't affect the login
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
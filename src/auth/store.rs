@@ -0,0 +1,338 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{TokenStoreError, Tokens};
+use crate::{datetime, utils};
+
+/// How long a refresh token stays valid after it was issued.
+const REFRESH_TOKEN_VALIDITY: Duration = Duration::days(15);
+
+/// [`Tokens`] together with their expiry times.
+///
+/// The access token expires at the end of the day (IST) on which it was
+/// issued, and the refresh token 15 days after it was issued.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredTokens {
+    /// The tokens themselves.
+    pub tokens: Tokens,
+
+    /// When the access token expires.
+    pub access_expires_at: DateTime<Utc>,
+
+    /// When the refresh token expires.
+    pub refresh_expires_at: DateTime<Utc>,
+}
+
+impl StoredTokens {
+    /// Wrap tokens that were just issued.
+    pub fn new(tokens: Tokens) -> Self {
+        Self::issued_at(tokens, Utc::now())
+    }
+
+    /// Wrap tokens that were issued at the given time.
+    pub fn issued_at(tokens: Tokens, issued_at: DateTime<Utc>) -> Self {
+        Self {
+            tokens,
+            access_expires_at: datetime::next_ist_midnight(issued_at),
+            refresh_expires_at: issued_at + REFRESH_TOKEN_VALIDITY,
+        }
+    }
+
+    /// Replace the tokens after a refresh.
+    ///
+    /// The refresh token expiry only moves forward if Fyers issued a new
+    /// refresh token.
+    pub fn refreshed(&self, tokens: Tokens) -> Self {
+        let mut refreshed = Self::new(tokens);
+
+        if refreshed.tokens.refresh_token == self.tokens.refresh_token {
+            refreshed.refresh_expires_at = self.refresh_expires_at;
+        }
+
+        refreshed
+    }
+
+    /// Whether the access token has expired.
+    pub fn is_access_expired(&self) -> bool {
+        Utc::now() >= self.access_expires_at
+    }
+
+    /// Whether the refresh token has expired.
+    ///
+    /// Once it has, the user has to log in again.
+    pub fn is_refresh_expired(&self) -> bool {
+        Utc::now() >= self.refresh_expires_at
+    }
+}
+
+/// Persistent storage for [`StoredTokens`].
+///
+/// The crate ships [`MemoryTokenStore`] and [`FileTokenStore`].
+/// Implement this trait to keep tokens somewhere else, like a database.
+///
+/// # Example
+///
+/// Persist tokens whenever a [`RefreshTokenProvider`](super::RefreshTokenProvider)
+/// refreshes them:
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use fyers::auth::{FileTokenStore, RefreshTokenProvider, TokenStore};
+///
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let store = Arc::new(FileTokenStore::new("tokens.json"));
+/// let stored = store.load()?.expect("log in first");
+///
+/// let provider = RefreshTokenProvider::new(
+///     "your_client_id",
+///     "your_secret_key",
+///     &stored.tokens.refresh_token,
+///     "your_pin",
+/// )
+/// .on_refresh(move |tokens| {
+///     if let Err(e) = store.save(&stored.refreshed(tokens.clone())) {
+///         eprintln!("failed to save tokens: {e}");
+///     }
+/// });
+/// # Ok(())
+/// # }
+/// ```
+pub trait TokenStore: Send + Sync {
+    /// Load the stored tokens, or `None` if nothing has been saved yet.
+    fn load(&self) -> Result<Option<StoredTokens>, TokenStoreError>;
+
+    /// Save the tokens, replacing anything saved before.
+    fn save(&self, tokens: &StoredTokens) -> Result<(), TokenStoreError>;
+}
+
+impl<T: TokenStore + ?Sized> TokenStore for std::sync::Arc<T> {
+    fn load(&self) -> Result<Option<StoredTokens>, TokenStoreError> {
+        (**self).load()
+    }
+
+    fn save(&self, tokens: &StoredTokens) -> Result<(), TokenStoreError> {
+        (**self).save(tokens)
+    }
+}
+
+/// A [`TokenStore`] that keeps tokens in memory.
+///
+/// Mostly useful for tests and short-lived processes.
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    tokens: Mutex<Option<StoredTokens>>,
+}
+
+impl MemoryTokenStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Result<Option<StoredTokens>, TokenStoreError> {
+        Ok(self
+            .tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone())
+    }
+
+    fn save(&self, tokens: &StoredTokens) -> Result<(), TokenStoreError> {
+        *self.tokens.lock().unwrap_or_else(|e| e.into_inner()) = Some(tokens.clone());
+        Ok(())
+    }
+}
+
+/// A [`TokenStore`] that keeps tokens in a JSON file.
+///
+/// The file is replaced atomically on every save and, on Unix, is only
+/// readable by the current user.
+///
+/// With the `encrypted-store` feature, `FileTokenStore::encrypted`
+/// encrypts the file with a passphrase.
+#[derive(Debug)]
+pub struct FileTokenStore {
+    path: PathBuf,
+    #[cfg(feature = "encrypted-store")]
+    passphrase: Option<String>,
+    #[cfg(feature = "encrypted-store")]
+    kdf_rounds: u32,
+}
+
+impl FileTokenStore {
+    /// Store tokens as plain JSON at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            #[cfg(feature = "encrypted-store")]
+            passphrase: None,
+            #[cfg(feature = "encrypted-store")]
+            kdf_rounds: encryption::DEFAULT_KDF_ROUNDS,
+        }
+    }
+
+    /// Store tokens at `path`, encrypted with a key derived from
+    /// `passphrase`.
+    ///
+    /// Uses PBKDF2-HMAC-SHA256 for key derivation and ChaCha20-Poly1305
+    /// for encryption.
+    #[cfg(feature = "encrypted-store")]
+    pub fn encrypted(path: impl Into<PathBuf>, passphrase: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            passphrase: Some(passphrase.into()),
+            kdf_rounds: encryption::DEFAULT_KDF_ROUNDS,
+        }
+    }
+
+    /// Set the number of PBKDF2 rounds used when saving.
+    ///
+    /// Defaults to **600,000**. Files saved with a different number of
+    /// rounds can still be loaded.
+    ///
+    /// Must be between 1,000 and 10,000,000, or saving fails with
+    /// [`TokenStoreError::InvalidKdfRounds`]. Files with rounds outside
+    /// that range are rejected on load too.
+    #[cfg(feature = "encrypted-store")]
+    pub fn kdf_rounds(mut self, rounds: u32) -> Self {
+        self.kdf_rounds = rounds;
+        self
+    }
+
+    /// The path of the token file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn encode(&self, tokens: &StoredTokens) -> Result<Vec<u8>, TokenStoreError> {
+        let json = serde_json::to_vec_pretty(tokens)?;
+
+        #[cfg(feature = "encrypted-store")]
+        if let Some(passphrase) = &self.passphrase {
+            return encryption::seal(passphrase, self.kdf_rounds, &json);
+        }
+
+        Ok(json)
+    }
+
+    fn decode(&self, contents: &[u8]) -> Result<StoredTokens, TokenStoreError> {
+        #[cfg(feature = "encrypted-store")]
+        if let Some(passphrase) = &self.passphrase {
+            let json = encryption::open(passphrase, contents)?;
+            return Ok(serde_json::from_slice(&json)?);
+        }
+
+        Ok(serde_json::from_slice(contents)?)
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> Result<Option<StoredTokens>, TokenStoreError> {
+        match fs::read(&self.path) {
+            Ok(contents) => self.decode(&contents).map(Some),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self, tokens: &StoredTokens) -> Result<(), TokenStoreError> {
+        let contents = self.encode(tokens)?;
+        utils::write_file_atomic(&self.path, &contents)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "encrypted-store")]
+mod encryption {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+    use serde::{Deserialize, Serialize};
+    use sha2::Sha256;
+
+    use crate::auth::TokenStoreError;
+
+    pub(super) const DEFAULT_KDF_ROUNDS: u32 = 600_000;
+    // The RFC 8018 minimum, and a cap so a tampered file can't make
+    // loading spin for minutes
+    const MIN_KDF_ROUNDS: u32 = 1_000;
+    const MAX_KDF_ROUNDS: u32 = 10_000_000;
+    const SALT_LEN: usize = 16;
+    const NONCE_LEN: usize = 12;
+
+    // On-disk format of an encrypted token file
+    #[derive(Serialize, Deserialize)]
+    struct Envelope {
+        version: u8,
+        rounds: u32,
+        salt: String,
+        nonce: String,
+        ciphertext: String,
+    }
+
+    pub(super) fn seal(
+        passphrase: &str,
+        rounds: u32,
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, TokenStoreError> {
+        check_rounds(rounds)?;
+
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        getrandom::getrandom(&mut salt).map_err(|_| TokenStoreError::Encryption)?;
+        getrandom::getrandom(&mut nonce).map_err(|_| TokenStoreError::Encryption)?;
+
+        let cipher = cipher(passphrase, &salt, rounds);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| TokenStoreError::Encryption)?;
+
+        let envelope = Envelope {
+            version: 1,
+            rounds,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        };
+
+        Ok(serde_json::to_vec_pretty(&envelope)?)
+    }
+
+    pub(super) fn open(passphrase: &str, contents: &[u8]) -> Result<Vec<u8>, TokenStoreError> {
+        let envelope: Envelope = serde_json::from_slice(contents)?;
+        check_rounds(envelope.rounds)?;
+
+        let salt = hex::decode(&envelope.salt).map_err(|_| TokenStoreError::Decryption)?;
+        let nonce = hex::decode(&envelope.nonce).map_err(|_| TokenStoreError::Decryption)?;
+        let ciphertext =
+            hex::decode(&envelope.ciphertext).map_err(|_| TokenStoreError::Decryption)?;
+
+        if envelope.version != 1 || nonce.len() != NONCE_LEN {
+            return Err(TokenStoreError::Decryption);
+        }
+
+        cipher(passphrase, &salt, envelope.rounds)
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| TokenStoreError::Decryption)
+    }
+
+    fn check_rounds(rounds: u32) -> Result<(), TokenStoreError> {
+        if !(MIN_KDF_ROUNDS..=MAX_KDF_ROUNDS).contains(&rounds) {
+            return Err(TokenStoreError::InvalidKdfRounds(rounds));
+        }
+        Ok(())
+    }
+
+    fn cipher(passphrase: &str, salt: &[u8], rounds: u32) -> ChaCha20Poly1305 {
+        let mut key = [0u8; 32];
+        pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, rounds, &mut key);
+        ChaCha20Poly1305::new(Key::from_slice(&key))
+    }
+}
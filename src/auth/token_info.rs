@@ -0,0 +1,122 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL;
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+
+use super::{AuthError, Tokens};
+use crate::datetime;
+
+/// Claims decoded from an access token.
+///
+/// Access tokens are JWTs. They are decoded without verifying the
+/// signature, so only use this for tokens you got from Fyers yourself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TokenInfo {
+    /// When the token was issued.
+    pub issued_at: DateTime<Utc>,
+
+    /// When the token expires, according to the token itself.
+    ///
+    /// Fyers also invalidates access tokens at the end of the day (IST).
+    /// [`Tokens::access_expires_at`] takes both into account.
+    pub expires_at: DateTime<Utc>,
+
+    /// The Fyers ID of the user.
+    pub fy_id: String,
+
+    /// The scopes granted to the token.
+    pub scopes: Vec<String>,
+}
+
+impl TokenInfo {
+    /// Decode the claims of an access token.
+    pub fn decode(access_token: &str) -> Result<Self, AuthError> {
+        let payload = access_token
+            .split('.')
+            .nth(1)
+            .ok_or_else(|| malformed("not a JWT"))?;
+
+        let payload = BASE64_URL
+            .decode(payload.trim_end_matches('='))
+            .map_err(|_| malformed("payload isn't valid base64"))?;
+
+        let claims: Claims = serde_json::from_slice(&payload)
+            .map_err(|e| malformed(&format!("invalid claims: {e}")))?;
+
+        Ok(Self {
+            issued_at: timestamp(claims.iat)?,
+            expires_at: timestamp(claims.exp)?,
+            fy_id: claims.fy_id,
+            scopes: match claims.aud {
+                Audience::One(scope) => vec![scope],
+                Audience::Many(scopes) => scopes,
+            },
+        })
+    }
+
+    /// When Fyers stops accepting the token.
+    ///
+    /// This is the earlier of the expiry in the token and the end of the
+    /// day (IST) on which it was issued.
+    pub fn access_expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+            .min(datetime::next_ist_midnight(self.issued_at))
+    }
+}
+
+impl Tokens {
+    /// Decode the claims of the access token.
+    ///
+    /// See [`TokenInfo`].
+    pub fn info(&self) -> Result<TokenInfo, AuthError> {
+        TokenInfo::decode(&self.access_token)
+    }
+
+    /// When the access token expires.
+    ///
+    /// See [`TokenInfo::access_expires_at`].
+    pub fn access_expires_at(&self) -> Result<DateTime<Utc>, AuthError> {
+        Ok(self.info()?.access_expires_at())
+    }
+
+    /// Whether the access token expires within `margin` from now.
+    ///
+    /// Also returns `true` if the access token can't be decoded, since it
+    /// most likely won't be accepted either.
+    pub fn should_refresh(&self, margin: Duration) -> bool {
+        self.access_expires_at()
+            .map_or(true, |expires_at| Utc::now() + margin >= expires_at)
+    }
+}
+
+#[derive(Deserialize)]
+struct Claims {
+    iat: i64,
+    exp: i64,
+    fy_id: String,
+    #[serde(default)]
+    aud: Audience,
+}
+
+// `aud` may be a single string or a list
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Audience {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Default for Audience {
+    fn default() -> Self {
+        Self::Many(Vec::new())
+    }
+}
+
+fn timestamp(secs: i64) -> Result<DateTime<Utc>, AuthError> {
+    DateTime::from_timestamp(secs, 0).ok_or_else(|| malformed("timestamp out of range"))
+}
+
+fn malformed(reason: &str) -> AuthError {
+    AuthError::MalformedToken(reason.to_string())
+}
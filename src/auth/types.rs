@@ -58,8 +58,15 @@ pub(crate) struct GenerateTokenResponse {
     pub(crate) refresh_token: Option<String>,
 }
 
-/// Tokens obtained after successful authentication.
 #[derive(Debug, Deserialize)]
+pub(crate) struct StatusResponse {
+    pub(crate) s: ApiStatus,
+    pub(crate) code: i32,
+    pub(crate) message: String,
+}
+
+/// Tokens obtained after successful authentication.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Tokens {
     /// Your access token
     pub access_token: String,
@@ -0,0 +1,234 @@
+//! Backtesting strategies over historical candles.
+//!
+//! A [`Strategy`] sees one completed candle at a time and trades through
+//! a [`Broker`], placing the same [`OrderRequest`] values it would place
+//! live. [`Backtest`] runs it against a [`PaperTrader`], so orders are
+//! filled with the rules described in the [`paper`](crate::paper) module,
+//! plus the configured [`Slippage`] and [`ChargeTable`].
+//!
+//! Orders placed on a candle are matched from the next candle onwards, so
+//! a market order fills at the next open and a strategy never trades on
+//! prices it hasn't seen.
+//!
+//! Candles can come from [`Fyers::history`] via
+//! [`run_history`](Backtest::run_history), or from anywhere else via
+//! [`run`](Backtest::run). [`Candle`] implements `Serialize` and
+//! `Deserialize`, so a history download can be saved to a file and
+//! replayed.
+//!
+//! # Example
+//!
+//! ```
+//! use fyers::backtest::{Backtest, Strategy};
+//! use fyers::charges::ChargeTable;
+//! use fyers::{Broker, Candle, FyersError, OrderRequest, OrderType, ProductType, Side, Validity};
+//!
+//! // Buy once and hold
+//! struct BuyAndHold {
+//!     bought: bool,
+//! }
+//!
+//! impl Strategy for BuyAndHold {
+//!     async fn on_candle<B: Broker>(
+//!         &mut self,
+//!         broker: &B,
+//!         symbol: &str,
+//!         _candle: &Candle,
+//!     ) -> Result<(), FyersError> {
+//!         if !self.bought {
+//!             let order = OrderRequest::builder(
+//!                 symbol,
+//!                 10,
+//!                 OrderType::Market,
+//!                 Side::Buy,
+//!                 ProductType::Cnc,
+//!                 Validity::Day,
+//!             )
+//!             .build();
+//!             broker.place_order(&order).await?;
+//!             self.bought = true;
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), FyersError> {
+//! let candles: Vec<Candle> = serde_json::from_str(
+//!     "[[1735702200, 100, 102, 99, 101, 1000], [1735702260, 101, 105, 100, 104, 1000]]",
+//! )?;
+//!
+//! let report = Backtest::new(10_000.0)
+//!     .charges(ChargeTable::fyers())
+//!     .run(&mut BuyAndHold { bought: false }, "NSE:SBIN-EQ", candles)
+//!     .await?;
+//!
+//! assert_eq!(report.trades[0].price, 101.0);
+//! println!("Net P&L: {:.2}", report.net_pnl());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+
+use crate::charges::ChargeTable;
+use crate::paper::{Funds, PaperTrader, Slippage, Trade};
+use crate::{Broker, Candle, Fyers, FyersError, HistoryRequest, OrderDetails};
+
+#[cfg(doc)]
+use crate::OrderRequest;
+
+/// A trading strategy driven by candles.
+///
+/// The strategy is generic over the [`Broker`], so the same code can
+/// trade through [`Fyers`] or a [`PaperTrader`].
+pub trait Strategy {
+    /// Called with each completed candle of `symbol`, oldest first.
+    ///
+    /// Returning an error stops the backtest.
+    fn on_candle<B: Broker>(
+        &mut self,
+        broker: &B,
+        symbol: &str,
+        candle: &Candle,
+    ) -> impl Future<Output = Result<(), FyersError>>;
+}
+
+/// Runs a [`Strategy`] over historical candles.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct Backtest {
+    funds: f64,
+    slippage: Slippage,
+    charges: ChargeTable,
+}
+
+/// The equity of a backtest after a candle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EquityPoint {
+    /// Open time of the candle
+    pub time: DateTime<Utc>,
+    /// Cash plus open positions valued at the candle close
+    pub equity: f64,
+}
+
+/// The outcome of a [`Backtest`].
+#[derive(Debug, Clone)]
+pub struct BacktestReport {
+    /// Starting cash
+    pub initial_funds: f64,
+    /// Funds after the last candle
+    pub funds: Funds,
+    /// Every fill, oldest first
+    pub trades: Vec<Trade>,
+    /// Every order placed by the strategy, including unfilled ones
+    pub orders: Vec<OrderDetails>,
+    /// Equity after each candle
+    pub equity_curve: Vec<EquityPoint>,
+}
+
+impl Backtest {
+    /// Create a backtest with the given starting cash.
+    ///
+    /// Fills are free and exact unless [`slippage`](Backtest::slippage)
+    /// and [`charges`](Backtest::charges) are set.
+    pub fn new(funds: f64) -> Self {
+        Self {
+            funds,
+            slippage: Slippage::None,
+            charges: ChargeTable::empty(),
+        }
+    }
+
+    /// Set the slippage applied to market and stop order fills.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`Slippage::Percent`] isn't from 0 up to 100, or
+    /// [`Slippage::Points`] is negative.
+    pub fn slippage(mut self, slippage: Slippage) -> Self {
+        slippage.check();
+        self.slippage = slippage;
+        self
+    }
+
+    /// Set the brokerage and statutory charges deducted on every fill.
+    pub fn charges(mut self, charges: ChargeTable) -> Self {
+        self.charges = charges;
+        self
+    }
+
+    /// Run `strategy` over `candles` of `symbol`.
+    ///
+    /// Candles must be in time order.
+    pub async fn run<S: Strategy>(
+        &self,
+        strategy: &mut S,
+        symbol: &str,
+        candles: impl IntoIterator<Item = Candle>,
+    ) -> Result<BacktestReport, FyersError> {
+        let paper = PaperTrader::new(self.funds)
+            .with_slippage(self.slippage)
+            .with_charges(self.charges.clone())
+            .match_on_update_only();
+
+        let mut equity_curve = Vec::new();
+
+        for candle in candles {
+            // Orders from earlier candles trade on this one first
+            paper.update_candle(symbol, &candle);
+            strategy.on_candle(&paper, symbol, &candle).await?;
+
+            equity_curve.push(EquityPoint {
+                time: candle.time,
+                equity: paper.funds().equity,
+            });
+        }
+
+        Ok(BacktestReport {
+            initial_funds: self.funds,
+            funds: paper.funds(),
+            trades: paper.trades(),
+            orders: paper.orders().await?,
+            equity_curve,
+        })
+    }
+
+    /// Download candles with [`Fyers::history`] and run `strategy` over
+    /// them.
+    pub async fn run_history<S: Strategy>(
+        &self,
+        strategy: &mut S,
+        fyers: &Fyers,
+        request: &HistoryRequest,
+    ) -> Result<BacktestReport, FyersError> {
+        let candles = fyers.history(request).await?;
+        self.run(strategy, &request.symbol, candles).await
+    }
+}
+
+impl BacktestReport {
+    /// Final equity minus the starting cash, after charges.
+    pub fn net_pnl(&self) -> f64 {
+        self.funds.equity - self.initial_funds
+    }
+
+    /// The largest fall in equity from a previous high, as a fraction of
+    /// that high.
+    pub fn max_drawdown(&self) -> f64 {
+        let mut peak = self.initial_funds;
+        let mut max_drawdown: f64 = 0.0;
+
+        for point in &self.equity_curve {
+            peak = peak.max(point.equity);
+            if peak > 0.0 {
+                max_drawdown = max_drawdown.max((peak - point.equity) / peak);
+            }
+        }
+
+        max_drawdown
+    }
+}
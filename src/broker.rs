@@ -0,0 +1,95 @@
+use std::future::Future;
+
+use crate::{
+    ExitPositionResult, Fyers, FyersError, ModifyOrderRequest, Order, OrderDetails, OrderRequest,
+    Position,
+};
+
+#[cfg(doc)]
+use crate::paper::PaperTrader;
+
+/// The trading operations shared by [`Fyers`] and [`PaperTrader`].
+///
+/// Write strategies against this trait to run them live or as a dry run
+/// with the same [`OrderRequest`] values.
+///
+/// # Example
+///
+/// ```
+/// use fyers::{Broker, FyersError, OrderRequest, OrderType, ProductType, Side, Validity};
+///
+/// async fn buy_one(broker: &impl Broker, symbol: &str) -> Result<String, FyersError> {
+///     let order = OrderRequest::builder(
+///         symbol,
+///         1,
+///         OrderType::Market,
+///         Side::Buy,
+///         ProductType::Intraday,
+///         Validity::Day,
+///     )
+///     .build();
+///
+///     Ok(broker.place_order(&order).await?.id)
+/// }
+/// ```
+pub trait Broker {
+    /// Place a single order.
+    fn place_order(
+        &self,
+        order: &OrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send;
+
+    /// Cancel a pending order by its order ID.
+    fn cancel_order(&self, order_id: &str) -> impl Future<Output = Result<(), FyersError>> + Send;
+
+    /// Modify a pending order.
+    fn modify_order(
+        &self,
+        modify: &ModifyOrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send;
+
+    /// Fetch all orders placed during the current trading day.
+    fn orders(&self) -> impl Future<Output = Result<Vec<OrderDetails>, FyersError>> + Send;
+
+    /// Fetch the net positions for the current trading day.
+    fn positions(&self) -> impl Future<Output = Result<Vec<Position>, FyersError>> + Send;
+
+    /// Exit all open positions.
+    fn exit_all_positions(
+        &self,
+    ) -> impl Future<Output = Result<ExitPositionResult, FyersError>> + Send;
+}
+
+impl Broker for Fyers {
+    fn place_order(
+        &self,
+        order: &OrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        Fyers::place_order(self, order)
+    }
+
+    fn cancel_order(&self, order_id: &str) -> impl Future<Output = Result<(), FyersError>> + Send {
+        Fyers::cancel_order(self, order_id)
+    }
+
+    fn modify_order(
+        &self,
+        modify: &ModifyOrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        Fyers::modify_order(self, modify)
+    }
+
+    fn orders(&self) -> impl Future<Output = Result<Vec<OrderDetails>, FyersError>> + Send {
+        Fyers::orders(self)
+    }
+
+    fn positions(&self) -> impl Future<Output = Result<Vec<Position>, FyersError>> + Send {
+        Fyers::positions(self)
+    }
+
+    fn exit_all_positions(
+        &self,
+    ) -> impl Future<Output = Result<ExitPositionResult, FyersError>> + Send {
+        Fyers::exit_all_positions(self)
+    }
+}
@@ -0,0 +1,248 @@
+//! Brokerage and statutory charges.
+//!
+//! A [`ChargeTable`] holds the [`ChargeRates`] for each [`Segment`] and
+//! [`ProductType`], and works out the [`ChargeBreakdown`] of an order or a
+//! fill: brokerage, STT/CTT, exchange transaction charges, SEBI fees, GST
+//! and stamp duty.
+//!
+//! [`ChargeTable::fyers`] has the Fyers brokerage plan and the statutory
+//! rates at the time of writing. Rates change from time to time, so
+//! override them with [`ChargeTable::set`] when they do.
+//!
+//! # Example
+//!
+//! ```
+//! use fyers::charges::{ChargeTable, Segment};
+//! use fyers::{ProductType, Side};
+//!
+//! let table = ChargeTable::fyers();
+//!
+//! let buy = table.calculate(Segment::NseEquity, ProductType::Intraday, Side::Buy, 100, 100.0);
+//! let sell = table.calculate(Segment::NseEquity, ProductType::Intraday, Side::Sell, 100, 101.0);
+//!
+//! let gross = 100.0 * (101.0 - 100.0);
+//! let net = gross - buy.total() - sell.total();
+//! assert!(net < gross);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::paper::Trade;
+use crate::{OrderRequest, ProductType, Side};
+
+mod rates;
+mod segment;
+
+pub use rates::{Brokerage, ChargeRates};
+pub use segment::Segment;
+
+const PRODUCT_TYPES: [ProductType; 6] = [
+    ProductType::Cnc,
+    ProductType::Intraday,
+    ProductType::Margin,
+    ProductType::Co,
+    ProductType::Bo,
+    ProductType::Mtf,
+];
+
+const SEGMENTS: [Segment; 10] = [
+    Segment::NseEquity,
+    Segment::BseEquity,
+    Segment::NseFutures,
+    Segment::NseOptions,
+    Segment::BseFutures,
+    Segment::BseOptions,
+    Segment::CurrencyFutures,
+    Segment::CurrencyOptions,
+    Segment::CommodityFutures,
+    Segment::CommodityOptions,
+];
+
+/// Charges for a single order or fill.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ChargeBreakdown {
+    /// Brokerage
+    pub brokerage: f64,
+    /// Securities (or Commodities) Transaction Tax
+    pub stt: f64,
+    /// Exchange transaction charges
+    pub exchange: f64,
+    /// SEBI turnover fees
+    pub sebi: f64,
+    /// GST on brokerage, exchange and SEBI charges
+    pub gst: f64,
+    /// Stamp duty
+    pub stamp_duty: f64,
+}
+
+impl ChargeBreakdown {
+    /// Sum of all charges.
+    pub fn total(&self) -> f64 {
+        self.brokerage + self.stt + self.exchange + self.sebi + self.gst + self.stamp_duty
+    }
+}
+
+impl std::ops::AddAssign for ChargeBreakdown {
+    fn add_assign(&mut self, other: Self) {
+        self.brokerage += other.brokerage;
+        self.stt += other.stt;
+        self.exchange += other.exchange;
+        self.sebi += other.sebi;
+        self.gst += other.gst;
+        self.stamp_duty += other.stamp_duty;
+    }
+}
+
+impl std::iter::Sum for ChargeBreakdown {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |mut total, charges| {
+            total += charges;
+            total
+        })
+    }
+}
+
+/// Charge rates for each segment and product.
+///
+/// Segments and products without rates are charged nothing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChargeTable {
+    rates: HashMap<(Segment, ProductType), ChargeRates>,
+}
+
+impl ChargeTable {
+    /// A table without any rates.
+    pub fn empty() -> Self {
+        Self {
+            rates: HashMap::new(),
+        }
+    }
+
+    /// The same rates for every segment and product.
+    ///
+    /// Useful for a rough cost model, e.g. a flat brokerage on every order.
+    pub fn uniform(rates: ChargeRates) -> Self {
+        let mut table = Self::empty();
+        for segment in SEGMENTS {
+            for product_type in PRODUCT_TYPES {
+                table.set(segment, product_type, rates);
+            }
+        }
+        table
+    }
+
+    /// Fyers brokerage with the current statutory rates for every segment.
+    ///
+    /// Equity delivery rates apply to `Cnc` and `Mtf`, and intraday rates
+    /// to every other product.
+    pub fn fyers() -> Self {
+        let mut table = Self::empty();
+
+        for product_type in PRODUCT_TYPES {
+            let delivery = matches!(product_type, ProductType::Cnc | ProductType::Mtf);
+            let equity = if delivery {
+                rates::EQUITY_DELIVERY
+            } else {
+                rates::EQUITY_INTRADAY
+            };
+
+            let segments = [
+                (Segment::NseEquity, equity),
+                (
+                    Segment::BseEquity,
+                    ChargeRates {
+                        exchange: rates::BSE_EXCHANGE,
+                        ..equity
+                    },
+                ),
+                (Segment::NseFutures, rates::FUTURES),
+                (
+                    Segment::BseFutures,
+                    ChargeRates {
+                        exchange: 0.0,
+                        ..rates::FUTURES
+                    },
+                ),
+                (Segment::NseOptions, rates::OPTIONS),
+                (
+                    Segment::BseOptions,
+                    ChargeRates {
+                        exchange: rates::BSE_OPTIONS_EXCHANGE,
+                        ..rates::OPTIONS
+                    },
+                ),
+                (Segment::CurrencyFutures, rates::CURRENCY_FUTURES),
+                (Segment::CurrencyOptions, rates::CURRENCY_OPTIONS),
+                (Segment::CommodityFutures, rates::COMMODITY_FUTURES),
+                (Segment::CommodityOptions, rates::COMMODITY_OPTIONS),
+            ];
+
+            for (segment, rates) in segments {
+                table.set(segment, product_type, rates);
+            }
+        }
+
+        table
+    }
+
+    /// Set the rates for a segment and product.
+    pub fn set(&mut self, segment: Segment, product_type: ProductType, rates: ChargeRates) {
+        self.rates.insert((segment, product_type), rates);
+    }
+
+    /// The rates for a segment and product, if set.
+    pub fn rates(&self, segment: Segment, product_type: ProductType) -> Option<&ChargeRates> {
+        self.rates.get(&(segment, product_type))
+    }
+
+    /// Charges for trading `qty` at `price`.
+    ///
+    /// For options, `price` is the premium.
+    pub fn calculate(
+        &self,
+        segment: Segment,
+        product_type: ProductType,
+        side: Side,
+        qty: u32,
+        price: f64,
+    ) -> ChargeBreakdown {
+        let Some(rates) = self.rates(segment, product_type) else {
+            return ChargeBreakdown::default();
+        };
+
+        let value = qty as f64 * price;
+        let brokerage = rates.brokerage.amount(value);
+        let exchange = value * rates.exchange;
+        let sebi = value * rates.sebi;
+
+        let (stt, stamp_duty) = match side {
+            Side::Buy => (value * rates.stt_buy, value * rates.stamp_duty),
+            Side::Sell => (value * rates.stt_sell, 0.0),
+        };
+
+        ChargeBreakdown {
+            brokerage,
+            stt,
+            exchange,
+            sebi,
+            gst: (brokerage + exchange + sebi) * rates.gst,
+            stamp_duty,
+        }
+    }
+
+    /// Charges for an order if it fills in full at `price`.
+    pub fn for_order(&self, order: &OrderRequest, segment: Segment, price: f64) -> ChargeBreakdown {
+        self.calculate(segment, order.product_type, order.side, order.qty, price)
+    }
+
+    /// Charges for a paper fill.
+    pub fn for_trade(&self, trade: &Trade, segment: Segment) -> ChargeBreakdown {
+        self.calculate(
+            segment,
+            trade.product_type,
+            trade.side,
+            trade.qty,
+            trade.price,
+        )
+    }
+}
@@ -0,0 +1,152 @@
+/// How brokerage is charged per executed order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Brokerage {
+    /// No brokerage.
+    Free,
+
+    /// A fixed amount per order.
+    Flat(f64),
+
+    /// A fraction of the traded value, capped per order.
+    Percent {
+        /// Fraction of the traded value, e.g. `0.0003` for 0.03%
+        rate: f64,
+        /// Maximum brokerage per order
+        max: f64,
+    },
+}
+
+impl Brokerage {
+    pub(crate) fn amount(self, value: f64) -> f64 {
+        match self {
+            Brokerage::Free => 0.0,
+            Brokerage::Flat(amount) => amount,
+            Brokerage::Percent { rate, max } => (value * rate).min(max),
+        }
+    }
+}
+
+/// Rates for one segment and product.
+///
+/// Every rate except `brokerage` is a fraction of the traded value (the
+/// premium for options), e.g. `0.00025` for 0.025%.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChargeRates {
+    /// Brokerage per order
+    pub brokerage: Brokerage,
+    /// STT (or CTT for commodities) on buys
+    pub stt_buy: f64,
+    /// STT (or CTT for commodities) on sells
+    pub stt_sell: f64,
+    /// Exchange transaction charges
+    pub exchange: f64,
+    /// SEBI turnover fees
+    pub sebi: f64,
+    /// GST on brokerage, exchange and SEBI charges
+    pub gst: f64,
+    /// Stamp duty on buys
+    pub stamp_duty: f64,
+}
+
+impl ChargeRates {
+    /// No charges at all.
+    pub const FREE: ChargeRates = ChargeRates {
+        brokerage: Brokerage::Free,
+        stt_buy: 0.0,
+        stt_sell: 0.0,
+        exchange: 0.0,
+        sebi: 0.0,
+        gst: 0.0,
+        stamp_duty: 0.0,
+    };
+}
+
+// SEBI charges Rs 10 per crore
+const SEBI: f64 = 0.000001;
+const GST: f64 = 0.18;
+
+// Fyers brokerage on everything but options
+const PERCENT: Brokerage = Brokerage::Percent {
+    rate: 0.0003,
+    max: 20.0,
+};
+const FLAT: Brokerage = Brokerage::Flat(20.0);
+
+pub(super) const EQUITY_INTRADAY: ChargeRates = ChargeRates {
+    brokerage: PERCENT,
+    stt_buy: 0.0,
+    stt_sell: 0.00025,
+    exchange: 0.0000297,
+    sebi: SEBI,
+    gst: GST,
+    stamp_duty: 0.00003,
+};
+
+pub(super) const EQUITY_DELIVERY: ChargeRates = ChargeRates {
+    brokerage: Brokerage::Percent {
+        rate: 0.003,
+        max: 20.0,
+    },
+    stt_buy: 0.001,
+    stt_sell: 0.001,
+    stamp_duty: 0.00015,
+    ..EQUITY_INTRADAY
+};
+
+pub(super) const BSE_EXCHANGE: f64 = 0.0000375;
+
+pub(super) const FUTURES: ChargeRates = ChargeRates {
+    brokerage: PERCENT,
+    stt_buy: 0.0,
+    stt_sell: 0.0002,
+    exchange: 0.0000173,
+    sebi: SEBI,
+    gst: GST,
+    stamp_duty: 0.00002,
+};
+
+pub(super) const OPTIONS: ChargeRates = ChargeRates {
+    brokerage: FLAT,
+    stt_buy: 0.0,
+    stt_sell: 0.001,
+    exchange: 0.0003503,
+    sebi: SEBI,
+    gst: GST,
+    stamp_duty: 0.00003,
+};
+
+pub(super) const BSE_OPTIONS_EXCHANGE: f64 = 0.000325;
+
+pub(super) const CURRENCY_FUTURES: ChargeRates = ChargeRates {
+    brokerage: PERCENT,
+    stt_buy: 0.0,
+    stt_sell: 0.0,
+    exchange: 0.0000035,
+    sebi: SEBI,
+    gst: GST,
+    stamp_duty: 0.000001,
+};
+
+pub(super) const CURRENCY_OPTIONS: ChargeRates = ChargeRates {
+    brokerage: FLAT,
+    exchange: 0.000311,
+    ..CURRENCY_FUTURES
+};
+
+pub(super) const COMMODITY_FUTURES: ChargeRates = ChargeRates {
+    brokerage: PERCENT,
+    stt_buy: 0.0,
+    stt_sell: 0.0001,
+    exchange: 0.000021,
+    sebi: SEBI,
+    gst: GST,
+    stamp_duty: 0.00002,
+};
+
+pub(super) const COMMODITY_OPTIONS: ChargeRates = ChargeRates {
+    brokerage: FLAT,
+    stt_sell: 0.0005,
+    exchange: 0.000418,
+    stamp_duty: 0.00003,
+    ..COMMODITY_FUTURES
+};
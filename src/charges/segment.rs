@@ -0,0 +1,59 @@
+/// Exchange segment of an instrument, which decides the statutory charges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Segment {
+    /// NSE cash market
+    NseEquity,
+    /// BSE cash market
+    BseEquity,
+    /// NSE equity and index futures
+    NseFutures,
+    /// NSE equity and index options
+    NseOptions,
+    /// BSE equity and index futures
+    BseFutures,
+    /// BSE equity and index options
+    BseOptions,
+    /// NSE currency futures
+    CurrencyFutures,
+    /// NSE currency options
+    CurrencyOptions,
+    /// MCX commodity futures
+    CommodityFutures,
+    /// MCX commodity options
+    CommodityOptions,
+}
+
+// Currency pairs traded on NSE
+const CURRENCIES: [&str; 4] = ["USDINR", "EURINR", "GBPINR", "JPYINR"];
+
+impl Segment {
+    /// Work out the segment from a Fyers symbol such as `NSE:SBIN-EQ`,
+    /// `NSE:NIFTY25JANFUT` or `MCX:CRUDEOIL25JAN6000CE`.
+    ///
+    /// Returns `None` when the exchange or instrument type isn't
+    /// recognised.
+    pub fn from_symbol(symbol: &str) -> Option<Segment> {
+        let (exchange, name) = symbol.split_once(':')?;
+
+        let future = name.ends_with("FUT");
+        let option = name.ends_with("CE") || name.ends_with("PE");
+        let currency = CURRENCIES.iter().any(|c| name.starts_with(c));
+
+        let segment = match exchange {
+            "NSE" if currency && future => Segment::CurrencyFutures,
+            "NSE" if currency && option => Segment::CurrencyOptions,
+            "NSE" if future => Segment::NseFutures,
+            "NSE" if option => Segment::NseOptions,
+            "NSE" if name.contains('-') => Segment::NseEquity,
+            "BSE" if future => Segment::BseFutures,
+            "BSE" if option => Segment::BseOptions,
+            "BSE" if name.contains('-') => Segment::BseEquity,
+            "MCX" if future => Segment::CommodityFutures,
+            "MCX" if option => Segment::CommodityOptions,
+            _ => return None,
+        };
+
+        Some(segment)
+    }
+}
@@ -1,16 +1,33 @@
 //! Fyers Client.
+use std::sync::Arc;
+
 use reqwest::header::{AUTHORIZATION, HeaderValue};
 
-use crate::FyersError;
+use crate::amo::MarketCalendar;
+use crate::endpoints::Endpoint;
 use crate::models::api_response::{ApiResponse, ApiStatus};
+use crate::{FyersError, RateLimiter, RetryConfig, RetryPolicy, retry};
+
+mod builder;
+mod credentials;
+
+pub use builder::FyersBuilder;
+
+use self::credentials::Credentials;
 
 /// Asynchronous Fyers client.
 ///
 /// Implements endpoints as associated methods.
+///
+/// Create one with [`Fyers::new`], or with [`Fyers::builder`] to
+/// configure the underlying HTTP client.
 #[derive(Clone)]
 pub struct Fyers {
     http: reqwest::Client,
-    auth_header: HeaderValue,
+    credentials: Arc<Credentials>,
+    rate_limiter: Option<RateLimiter>,
+    pub(crate) retry: RetryConfig,
+    pub(crate) amo_calendar: Option<Arc<MarketCalendar>>,
     pub(crate) base_urls: BaseUrls,
 }
 
@@ -19,7 +36,7 @@ pub struct Fyers {
 // Edit this struct and its default implementation
 // to add more base URLs.
 // The endpoints can then choose the base URL it needs.
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub(crate) struct BaseUrls {
     pub api_v3: String,
     pub data: String,
@@ -36,49 +53,159 @@ impl Default for BaseUrls {
 
 impl Fyers {
     /// Create a new Fyers client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client ID or access token can't be used in an HTTP
+    /// header. Use [`Fyers::builder`] to handle this as an error instead.
     pub fn new(client_id: &str, access_token: &str) -> Self {
-        let http = reqwest::Client::builder()
-            .user_agent("fyers/0.1 (https://github.com/fushinori/fyers)")
+        FyersBuilder::new(client_id, access_token)
             .build()
-            .unwrap();
+            .expect("failed to create Fyers client")
+    }
 
-        let mut auth_header =
-            HeaderValue::from_str(&format!("{}:{}", client_id, access_token)).unwrap();
-        auth_header.set_sensitive(true);
+    /// Creates a [`FyersBuilder`] to construct a [`Fyers`] client.
+    /// This is the same as [`FyersBuilder::new()`].
+    pub fn builder(client_id: impl Into<String>, access_token: impl Into<String>) -> FyersBuilder {
+        FyersBuilder::new(client_id, access_token)
+    }
 
-        Self {
-            http,
-            auth_header,
-            base_urls: BaseUrls::default(),
-        }
+    /// Attach a client-side [`RateLimiter`].
+    ///
+    /// Every request made through this client, and through all of its
+    /// clones, first takes a permit from the limiter. Pass a clone of the
+    /// same limiter to several clients to make them share one quota.
+    ///
+    /// No rate limiting is done by default.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Replace the access token used by this client and all of its clones.
+    ///
+    /// Fails if the access token can't be used in an HTTP header.
+    pub fn update_access_token(&self, access_token: &str) -> Result<(), FyersError> {
+        self.credentials.set_access_token(access_token)
+    }
+
+    /// Retry transient failures according to `retry`.
+    ///
+    /// See [`RetryConfig`] for what is retried and how non-idempotent
+    /// requests are handled.
+    ///
+    /// Nothing is retried by default.
+    pub fn with_retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Mark orders as AMOs when they are placed during the AMO window.
+    ///
+    /// [`Fyers::place_order`] looks up the [`MarketPhase`] of the order's
+    /// segment in `calendar`, and sets
+    /// [`offline_order`](crate::OrderBuilder::offline_order) when only
+    /// AMOs are accepted. Orders whose segment isn't known are sent as
+    /// they are, as are bracket and cover orders, which can't be AMOs.
+    ///
+    /// Orders are sent as they are by default.
+    ///
+    /// [`MarketPhase`]: crate::amo::MarketPhase
+    pub fn with_auto_amo(mut self, calendar: MarketCalendar) -> Self {
+        self.amo_calendar = Some(Arc::new(calendar));
+        self
     }
 
     // Create a client with custom base URLs.
     //
-    // Mainly intended for testing.
+    // Kept for backwards compatibility.
     #[doc(hidden)]
+    #[deprecated(note = "use `Fyers::builder` with `api_url` and `data_url` instead")]
     pub fn with_base_urls(
         client_id: &str,
         access_token: &str,
         api_v3: impl Into<String>,
         data: impl Into<String>,
     ) -> Self {
-        let mut auth_header =
-            HeaderValue::from_str(&format!("{}:{}", client_id, access_token)).unwrap();
-        auth_header.set_sensitive(true);
-
-        Self {
-            http: reqwest::Client::new(),
-            auth_header,
-            base_urls: BaseUrls {
-                api_v3: api_v3.into(),
-                data: data.into(),
-            },
-        }
+        FyersBuilder::new(client_id, access_token)
+            .api_url(api_v3)
+            .data_url(data)
+            .build()
+            .expect("failed to create Fyers client")
     }
 
     // Send requests and validate the response
+    //
+    // If the access token is rejected and a token provider is set,
+    // the token is refreshed and the request is sent once more.
     async fn send_and_validate(
+        &self,
+        endpoint: Endpoint,
+        req: reqwest::RequestBuilder,
+    ) -> Result<serde_json::Value, FyersError> {
+        // Keep a copy around in case the token has to be refreshed
+        let fallback = req.try_clone();
+        let auth_header = self.credentials.auth_header();
+
+        match (
+            self.send_with_retries(endpoint, req, &auth_header).await,
+            fallback,
+        ) {
+            // Refreshing a token only to log it out makes no sense
+            (Err(FyersError::TokenExpired | FyersError::InvalidToken), Some(req))
+                if endpoint != Endpoint::Logout && self.credentials.can_refresh() =>
+            {
+                self.credentials.refresh(&auth_header).await?;
+
+                let auth_header = self.credentials.auth_header();
+                self.send_with_retries(endpoint, req, &auth_header).await
+            }
+            (result, _) => result,
+        }
+    }
+
+    // Transient failures are retried according to the retry config,
+    // but only for idempotent endpoints.
+    async fn send_with_retries(
+        &self,
+        endpoint: Endpoint,
+        req: reqwest::RequestBuilder,
+        auth_header: &HeaderValue,
+    ) -> Result<serde_json::Value, FyersError> {
+        let req = req.header(AUTHORIZATION, auth_header.clone());
+
+        let policy = if endpoint.is_idempotent() {
+            self.retry.policy(endpoint)
+        } else {
+            RetryPolicy::none()
+        };
+
+        let mut retries = 0;
+
+        loop {
+            // A local rate limit error isn't transient, so that the
+            // fail-fast policy actually fails fast
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire(endpoint.api()).await?;
+            }
+
+            // Only streaming bodies can't be cloned, and we never send those
+            let Some(attempt) = req.try_clone() else {
+                return self.send_once(req).await;
+            };
+
+            match self.send_once(attempt).await {
+                Err(err) if retries < policy.max_retries() && retry::is_transient(&err) => {
+                    tokio::time::sleep(policy.backoff(retries)).await;
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    // Send a single request and validate the response
+    async fn send_once(
         &self,
         req: reqwest::RequestBuilder,
     ) -> Result<serde_json::Value, FyersError> {
@@ -108,61 +235,66 @@ impl Fyers {
     }
 
     // GET request helper
-    pub(crate) async fn get(&self, url: &str) -> Result<serde_json::Value, FyersError> {
-        self.send_and_validate(
-            self.http
-                .get(url)
-                .header(AUTHORIZATION, self.auth_header.clone()),
-        )
-        .await
+    pub(crate) async fn get(
+        &self,
+        endpoint: Endpoint,
+        url: &str,
+    ) -> Result<serde_json::Value, FyersError> {
+        self.send_and_validate(endpoint, self.http.get(url)).await
     }
 
     // GET request with query params helper
     pub(crate) async fn get_query<Q>(
         &self,
+        endpoint: Endpoint,
         url: &str,
         query: &Q,
     ) -> Result<serde_json::Value, FyersError>
     where
         Q: serde::Serialize + ?Sized,
     {
-        self.send_and_validate(
-            self.http
-                .get(url)
-                .query(query)
-                .header(AUTHORIZATION, self.auth_header.clone()),
-        )
-        .await
+        self.send_and_validate(endpoint, self.http.get(url).query(query))
+            .await
     }
 
     // POST request helper
-    pub(crate) async fn post<B>(&self, url: &str, body: &B) -> Result<serde_json::Value, FyersError>
+    pub(crate) async fn post<B>(
+        &self,
+        endpoint: Endpoint,
+        url: &str,
+        body: &B,
+    ) -> Result<serde_json::Value, FyersError>
+    where
+        B: serde::Serialize,
+    {
+        self.send_and_validate(endpoint, self.http.post(url).json(body))
+            .await
+    }
+
+    // PATCH request helper
+    pub(crate) async fn patch<B>(
+        &self,
+        endpoint: Endpoint,
+        url: &str,
+        body: &B,
+    ) -> Result<serde_json::Value, FyersError>
     where
         B: serde::Serialize,
     {
-        self.send_and_validate(
-            self.http
-                .post(url)
-                .header(AUTHORIZATION, self.auth_header.clone())
-                .json(body),
-        )
-        .await
+        self.send_and_validate(endpoint, self.http.patch(url).json(body))
+            .await
     }
 
     pub(crate) async fn delete<B>(
         &self,
+        endpoint: Endpoint,
         url: &str,
         body: &B,
     ) -> Result<serde_json::Value, FyersError>
     where
         B: serde::Serialize,
     {
-        self.send_and_validate(
-            self.http
-                .delete(url)
-                .header(AUTHORIZATION, self.auth_header.clone())
-                .json(body),
-        )
-        .await
+        self.send_and_validate(endpoint, self.http.delete(url).json(body))
+            .await
     }
 }
@@ -0,0 +1,217 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{BaseUrls, Credentials, Fyers};
+use crate::amo::MarketCalendar;
+use crate::auth::TokenProvider;
+use crate::{FyersError, RateLimiter, RetryConfig};
+
+/// Builder for creating a [`Fyers`] client.
+///
+/// Use this instead of [`Fyers::new`] to configure the underlying HTTP
+/// client or to point the client at a different server, such as a sandbox
+/// or a local mock.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use fyers::Fyers;
+///
+/// # fn run() -> Result<(), fyers::FyersError> {
+/// let fyers = Fyers::builder("CLIENT_ID", "ACCESS_TOKEN")
+///     .connect_timeout(Duration::from_secs(5))
+///     .read_timeout(Duration::from_secs(10))
+///     .user_agent_suffix("my-bot/1.0")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "builders must be finalized with .build()"]
+pub struct FyersBuilder {
+    client_id: String,
+    access_token: String,
+    http: Option<reqwest::Client>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    proxy: Option<reqwest::Proxy>,
+    user_agent_suffix: Option<String>,
+    base_urls: BaseUrls,
+    rate_limiter: Option<RateLimiter>,
+    retry: RetryConfig,
+    amo_calendar: Option<MarketCalendar>,
+    token_provider: Option<Box<dyn TokenProvider>>,
+}
+
+impl FyersBuilder {
+    /// Create a new builder with the required credentials.
+    pub fn new(client_id: impl Into<String>, access_token: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            access_token: access_token.into(),
+            http: None,
+            connect_timeout: None,
+            read_timeout: None,
+            proxy: None,
+            user_agent_suffix: None,
+            base_urls: BaseUrls::default(),
+            rate_limiter: None,
+            retry: RetryConfig::none(),
+            amo_calendar: None,
+            token_provider: None,
+        }
+    }
+
+    /// Use an existing [`reqwest::Client`] instead of building one.
+    ///
+    /// The client is used as-is, so it can't be combined with
+    /// [`connect_timeout`](Self::connect_timeout),
+    /// [`read_timeout`](Self::read_timeout), [`proxy`](Self::proxy) or
+    /// [`user_agent_suffix`](Self::user_agent_suffix). Configure those on
+    /// the client itself.
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http = Some(client);
+        self
+    }
+
+    /// Set a timeout for establishing a connection.
+    ///
+    /// No timeout by default.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set a timeout for each read from the server.
+    ///
+    /// No timeout by default.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Send all requests through a proxy.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Append a string to the default user agent.
+    ///
+    /// Useful for identifying your application in request logs.
+    pub fn user_agent_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Set the base URL of the trading API.
+    ///
+    /// Defaults to `https://api-t1.fyers.in/api/v3`.
+    pub fn api_url(mut self, url: impl Into<String>) -> Self {
+        self.base_urls.api_v3 = url.into();
+        self
+    }
+
+    /// Set the base URL of the data API.
+    ///
+    /// Defaults to `https://api-t1.fyers.in/data`.
+    pub fn data_url(mut self, url: impl Into<String>) -> Self {
+        self.base_urls.data = url.into();
+        self
+    }
+
+    /// Attach a client-side [`RateLimiter`].
+    ///
+    /// See [`Fyers::with_rate_limiter`].
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Retry transient failures according to `retry`.
+    ///
+    /// See [`Fyers::with_retry_config`].
+    pub fn retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Mark orders as AMOs when they are placed during the AMO window.
+    ///
+    /// See [`Fyers::with_auto_amo`].
+    pub fn auto_amo(mut self, calendar: MarketCalendar) -> Self {
+        self.amo_calendar = Some(calendar);
+        self
+    }
+
+    /// Refresh the access token with `provider` when it expires.
+    ///
+    /// A request rejected with [`FyersError::TokenExpired`] or
+    /// [`FyersError::InvalidToken`] triggers a refresh, after which the
+    /// request is retried once with the new token. The new token is shared
+    /// by all clones of the client.
+    ///
+    /// See [`RefreshTokenProvider`](crate::auth::RefreshTokenProvider).
+    pub fn token_provider(mut self, provider: impl TokenProvider + 'static) -> Self {
+        self.token_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Return a [`Fyers`] client with the desired configuration.
+    ///
+    /// Fails if the client ID or access token can't be used in an HTTP
+    /// header, or if the HTTP client can't be built.
+    pub fn build(self) -> Result<Fyers, FyersError> {
+        let credentials =
+            Credentials::new(self.client_id, &self.access_token, self.token_provider)?;
+
+        let http = match self.http {
+            Some(http) => {
+                if self.connect_timeout.is_some()
+                    || self.read_timeout.is_some()
+                    || self.proxy.is_some()
+                    || self.user_agent_suffix.is_some()
+                {
+                    return Err(FyersError::InvalidConfig(
+                        "timeouts, proxy and user agent can't be set with a custom http client"
+                            .into(),
+                    ));
+                }
+
+                http
+            }
+            None => {
+                let mut user_agent = format!(
+                    "fyers/{} (https://github.com/fushinori/fyers)",
+                    env!("CARGO_PKG_VERSION")
+                );
+                if let Some(suffix) = &self.user_agent_suffix {
+                    user_agent = format!("{user_agent} {suffix}");
+                }
+
+                let mut builder = reqwest::Client::builder().user_agent(user_agent);
+
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.read_timeout {
+                    builder = builder.read_timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+
+                builder.build()?
+            }
+        };
+
+        Ok(Fyers {
+            http,
+            credentials: Arc::new(credentials),
+            rate_limiter: self.rate_limiter,
+            retry: self.retry,
+            amo_calendar: self.amo_calendar.map(Arc::new),
+            base_urls: self.base_urls,
+        })
+    }
+}
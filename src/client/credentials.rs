@@ -0,0 +1,78 @@
+use std::sync::RwLock;
+
+use reqwest::header::HeaderValue;
+
+use crate::FyersError;
+use crate::auth::TokenProvider;
+
+// Credentials shared by a client and all of its clones.
+pub(crate) struct Credentials {
+    client_id: String,
+    auth_header: RwLock<HeaderValue>,
+    provider: Option<Box<dyn TokenProvider>>,
+    // Makes sure concurrent requests hitting an expired token
+    // only refresh it once
+    refresh_lock: tokio::sync::Mutex<()>,
+}
+
+impl Credentials {
+    pub(crate) fn new(
+        client_id: String,
+        access_token: &str,
+        provider: Option<Box<dyn TokenProvider>>,
+    ) -> Result<Self, FyersError> {
+        let auth_header = auth_header(&client_id, access_token)?;
+
+        Ok(Self {
+            client_id,
+            auth_header: RwLock::new(auth_header),
+            provider,
+            refresh_lock: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    pub(crate) fn auth_header(&self) -> HeaderValue {
+        self.auth_header
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub(crate) fn set_access_token(&self, access_token: &str) -> Result<(), FyersError> {
+        let auth_header = auth_header(&self.client_id, access_token)?;
+        *self.auth_header.write().unwrap_or_else(|e| e.into_inner()) = auth_header;
+        Ok(())
+    }
+
+    pub(crate) fn can_refresh(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    // Refresh the access token after `stale` was rejected.
+    pub(crate) async fn refresh(&self, stale: &HeaderValue) -> Result<(), FyersError> {
+        let Some(provider) = &self.provider else {
+            return Ok(());
+        };
+
+        let _guard = self.refresh_lock.lock().await;
+
+        // Someone else already refreshed it while we were waiting
+        if self.auth_header() != *stale {
+            return Ok(());
+        }
+
+        let tokens = provider.refresh().await.map_err(FyersError::TokenRefresh)?;
+
+        self.set_access_token(&tokens.access_token)
+    }
+}
+
+fn auth_header(client_id: &str, access_token: &str) -> Result<HeaderValue, FyersError> {
+    let mut auth_header =
+        HeaderValue::from_str(&format!("{client_id}:{access_token}")).map_err(|_| {
+            FyersError::InvalidConfig("client id and access token must be visible ASCII".into())
+        })?;
+    auth_header.set_sensitive(true);
+
+    Ok(auth_header)
+}
@@ -11,3 +11,12 @@ pub fn ist_datetime(year: i32, month: u32, day: u32, hour: u32, minute: u32) ->
         .unwrap()
         .with_timezone(&Utc)
 }
+
+// The first IST midnight strictly after `time`.
+pub(crate) fn next_ist_midnight(time: DateTime<Utc>) -> DateTime<Utc> {
+    let tomorrow = time.with_timezone(&IST).date_naive() + chrono::Days::new(1);
+
+    IST.from_local_datetime(&tomorrow.and_time(chrono::NaiveTime::MIN))
+        .unwrap()
+        .with_timezone(&Utc)
+}
@@ -1,4 +1,67 @@
 mod history;
+mod logout;
 mod orders;
 mod positions;
 mod profile;
+mod quotes;
+
+#[cfg(doc)]
+use crate::Fyers;
+
+/// Identifies a Fyers API endpoint.
+///
+/// Used to configure per-endpoint behaviour, such as retries with
+/// [`RetryConfig::endpoint`](crate::RetryConfig::endpoint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Endpoint {
+    /// [`Fyers::profile`]
+    Profile,
+    /// [`Fyers::history`]
+    History,
+    /// [`Fyers::quotes`]
+    Quotes,
+    /// [`Fyers::orders`]
+    Orders,
+    /// [`Fyers::place_order`]
+    PlaceOrder,
+    /// [`Fyers::modify_order`]
+    ModifyOrder,
+    /// [`Fyers::cancel_order`]
+    CancelOrder,
+    /// [`Fyers::positions`]
+    Positions,
+    /// [`Fyers::cancel_pending_orders`]
+    CancelPendingOrders,
+    /// [`Fyers::exit_all_positions`]
+    ExitAllPositions,
+    /// [`Fyers::logout`]
+    Logout,
+}
+
+// The API an endpoint belongs to.
+//
+// Fyers enforces its quotas separately for each of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ApiKind {
+    Data,
+    Orders,
+}
+
+impl Endpoint {
+    pub(crate) fn api(self) -> ApiKind {
+        match self {
+            Endpoint::History | Endpoint::Quotes => ApiKind::Data,
+            _ => ApiKind::Orders,
+        }
+    }
+
+    /// Whether sending the same request twice has the same effect as
+    /// sending it once.
+    ///
+    /// Requests to endpoints that aren't idempotent, like placing an order,
+    /// are never retried blindly.
+    pub fn is_idempotent(self) -> bool {
+        !matches!(self, Endpoint::PlaceOrder | Endpoint::ExitAllPositions)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::endpoints::Endpoint;
 use crate::models::history::history_request::HistoryRequest;
 use crate::models::history::history_response::{Candle, HistoryResponse};
 use crate::{Fyers, FyersError};
@@ -30,7 +31,9 @@ impl Fyers {
         history_request: &HistoryRequest,
     ) -> Result<Vec<Candle>, FyersError> {
         let url = format!("{}/history", self.base_urls.data);
-        let response = self.get_query(&url, &history_request).await?;
+        let response = self
+            .get_query(Endpoint::History, &url, &history_request)
+            .await?;
         let api_response: HistoryResponse = serde_json::from_value(response)?;
         Ok(api_response.candles)
     }
@@ -0,0 +1,23 @@
+use crate::endpoints::Endpoint;
+use crate::{Fyers, FyersError};
+
+impl Fyers {
+    /// Invalidate the access token used by this client.
+    ///
+    /// The client and all of its clones can't be used afterwards, unless
+    /// a new access token is set with [`Fyers::update_access_token`] or
+    /// obtained through a [`TokenProvider`](crate::auth::TokenProvider).
+    ///
+    /// Fyers replies with nothing but a status message, which is checked
+    /// like any other response, so there is nothing to return.
+    ///
+    /// An already expired or invalid token isn't refreshed just to be
+    /// logged out, the error is returned instead.
+    pub async fn logout(&self) -> Result<(), FyersError> {
+        let url = format!("{}/logout", self.base_urls.api_v3);
+        self.post(Endpoint::Logout, &url, &serde_json::json!({}))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,2 +1,6 @@
+mod amo_orders;
 mod cancel_order;
+mod modify_order;
+mod order_book;
+mod order_groups;
 mod place_order;
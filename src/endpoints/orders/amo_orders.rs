@@ -0,0 +1,30 @@
+use crate::{Fyers, FyersError, OrderDetails};
+
+impl Fyers {
+    /// Fetch the AMOs (After Market Orders) that are still pending.
+    pub async fn amo_orders(&self) -> Result<Vec<OrderDetails>, FyersError> {
+        let orders = self
+            .orders()
+            .await?
+            .into_iter()
+            .filter(|o| o.offline_order && o.status.is_open())
+            .collect();
+
+        Ok(orders)
+    }
+
+    /// Cancel every pending AMO (After Market Order).
+    ///
+    /// Returns the IDs of the cancelled orders. Stops at the first order
+    /// that fails to cancel.
+    pub async fn cancel_amo_orders(&self) -> Result<Vec<String>, FyersError> {
+        let mut cancelled = Vec::new();
+
+        for order in self.amo_orders().await? {
+            self.cancel_order(&order.id).await?;
+            cancelled.push(order.id);
+        }
+
+        Ok(cancelled)
+    }
+}
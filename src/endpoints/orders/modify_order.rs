@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+use crate::endpoints::Endpoint;
+use crate::{Fyers, FyersError, ModifyOrderRequest, Order};
+
+impl Fyers {
+    /// Modify a pending order.
+    ///
+    /// Use [`ModifyOrderRequest::builder`] to construct the request.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fyers::ModifyOrderRequest;
+    /// # use fyers::Fyers;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let fyers = Fyers::new("id", "token");
+    /// let modify = ModifyOrderRequest::builder("808058117761")
+    ///     .limit_price(580.5)
+    ///     .build();
+    ///
+    /// fyers.modify_order(&modify).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn modify_order(&self, modify: &ModifyOrderRequest) -> Result<Order, FyersError> {
+        let url = format!("{}/orders/sync", self.base_urls.api_v3);
+        let response = self.patch(Endpoint::ModifyOrder, &url, modify).await?;
+        Ok(Order::deserialize(response)?)
+    }
+}
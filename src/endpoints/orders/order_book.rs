@@ -0,0 +1,15 @@
+use crate::endpoints::Endpoint;
+use crate::models::orders::order_details::OrderBookResponse;
+use crate::{Fyers, FyersError, OrderDetails};
+
+impl Fyers {
+    /// Fetch all orders placed during the current trading day.
+    ///
+    /// Returns the order book as a vector of [`OrderDetails`].
+    pub async fn orders(&self) -> Result<Vec<OrderDetails>, FyersError> {
+        let url = format!("{}/orders", self.base_urls.api_v3);
+        let response = self.get(Endpoint::Orders, &url).await?;
+        let api_response: OrderBookResponse = serde_json::from_value(response)?;
+        Ok(api_response.order_book)
+    }
+}
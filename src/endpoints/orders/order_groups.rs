@@ -0,0 +1,70 @@
+use crate::{Fyers, FyersError, ModifyOrderRequest, Order, OrderDetails, OrderGroup, OrderType};
+
+impl Fyers {
+    /// Fetch the bracket and cover orders placed during the current
+    /// trading day, grouped with their legs.
+    pub async fn order_groups(&self) -> Result<Vec<OrderGroup>, FyersError> {
+        let orders = self.orders().await?;
+        Ok(OrderGroup::from_orders(&orders))
+    }
+
+    /// Move the stop loss of a bracket or cover order to `stop_price`.
+    ///
+    /// A stop-limit leg keeps the distance between its stop and limit
+    /// price.
+    pub async fn modify_stop_loss(
+        &self,
+        group: &OrderGroup,
+        stop_price: f64,
+    ) -> Result<Order, FyersError> {
+        let leg = open_leg(group.stop_loss.as_ref(), "stop loss")?;
+        let mut modify = ModifyOrderRequest::builder(&leg.id).stop_price(stop_price);
+
+        if leg.order_type == OrderType::StopLimit {
+            modify = modify.limit_price(leg.limit_price + stop_price - leg.stop_price);
+        }
+
+        self.modify_order(&modify.build()).await
+    }
+
+    /// Move the target of a bracket order to `limit_price`.
+    pub async fn modify_target(
+        &self,
+        group: &OrderGroup,
+        limit_price: f64,
+    ) -> Result<Order, FyersError> {
+        let leg = open_leg(group.target.as_ref(), "target")?;
+        let modify = ModifyOrderRequest::builder(&leg.id)
+            .limit_price(limit_price)
+            .build();
+
+        self.modify_order(&modify).await
+    }
+
+    /// Exit a bracket or cover order as one unit.
+    ///
+    /// An unfilled entry is cancelled. Otherwise the stop loss leg (or the
+    /// target leg, if only that is left) is cancelled, which makes Fyers
+    /// square off the position at market and cancel the other leg.
+    ///
+    /// Does nothing if `group` has no open orders. Fetch it again with
+    /// [`Fyers::order_groups`] right before exiting, as its legs may have
+    /// changed.
+    pub async fn exit_order_group(&self, group: &OrderGroup) -> Result<(), FyersError> {
+        let order = if group.entry.status.is_open() {
+            Some(&group.entry)
+        } else {
+            group.legs().find(|leg| leg.status.is_open())
+        };
+
+        match order {
+            Some(order) => self.cancel_order(&order.id).await,
+            None => Ok(()),
+        }
+    }
+}
+
+fn open_leg<'a>(leg: Option<&'a OrderDetails>, name: &str) -> Result<&'a OrderDetails, FyersError> {
+    leg.filter(|leg| leg.status.is_open())
+        .ok_or_else(|| FyersError::InvalidParams(format!("no open {name} leg")))
+}
@@ -1,15 +1,31 @@
+use chrono::Utc;
 use serde::Deserialize;
 
+use crate::amo::MarketPhase;
+use crate::charges::Segment;
+
+use crate::endpoints::Endpoint;
 use crate::models::orders::place_order_request::OrderRequest;
-use crate::{Fyers, FyersError, Order};
+use crate::{Fyers, FyersError, Order, ProductType, retry};
+
+#[cfg(doc)]
+use crate::RetryConfig;
 
 impl Fyers {
     /// Place a single order.
     ///
-    /// Returns the placed [`Order`] on success.
+    /// Returns the placed [`Order`] on success. Use
+    /// [`Fyers::place_order_handle`] instead to wait for the order to fill.
     ///
     /// Use [`OrderRequest::builder`] to construct the order.
     ///
+    /// When a [`RetryConfig`] is set, a failed attempt is only retried
+    /// once the order book shows no order with the same tag. Give every
+    /// order a unique tag to make use of this.
+    ///
+    /// With [`Fyers::with_auto_amo`], orders placed while only AMOs are
+    /// accepted are sent as AMOs.
+    ///
     /// # Example
     /// ```no_run
     /// use fyers::{OrderRequest, OrderType, Side, ProductType, Validity};
@@ -34,8 +50,70 @@ impl Fyers {
     /// # }
     /// ```
     pub async fn place_order(&self, order: &OrderRequest) -> Result<Order, FyersError> {
+        let amo = self.as_amo(order);
+        let order = amo.as_ref().unwrap_or(order);
+
         let url = format!("{}/orders/sync", self.base_urls.api_v3);
-        let response = self.post(&url, order).await?;
-        Ok(Order::deserialize(response)?)
+        let policy = self.retry.policy(Endpoint::PlaceOrder);
+        let mut retries = 0;
+
+        loop {
+            let err = match self.post(Endpoint::PlaceOrder, &url, order).await {
+                Ok(response) => return Ok(Order::deserialize(response)?),
+                Err(err) => err,
+            };
+
+            if retries >= policy.max_retries() || !retry::is_transient(&err) {
+                return Err(err);
+            }
+
+            // A rate limited order was rejected outright. For anything else
+            // the order may have reached Fyers, so look for it before
+            // placing it again.
+            if !matches!(err, FyersError::RateLimited) {
+                let Some(tag) = order.order_tag.as_deref() else {
+                    return Err(err);
+                };
+
+                match self.find_order_by_tag(tag).await {
+                    Ok(Some(existing)) => return Ok(existing),
+                    Ok(None) => {}
+                    // We can't tell whether the order landed
+                    Err(_) => return Err(err),
+                }
+            }
+
+            tokio::time::sleep(policy.backoff(retries)).await;
+            retries += 1;
+        }
+    }
+
+    // A copy of the order marked as an AMO, if auto AMO is on and only
+    // AMOs are accepted right now
+    fn as_amo(&self, order: &OrderRequest) -> Option<OrderRequest> {
+        let calendar = self.amo_calendar.as_ref()?;
+
+        if order.offline_order || matches!(order.product_type, ProductType::Bo | ProductType::Co) {
+            return None;
+        }
+
+        let segment = Segment::from_symbol(&order.symbol)?;
+
+        (calendar.phase(segment, Utc::now())? == MarketPhase::Amo).then(|| OrderRequest {
+            offline_order: true,
+            ..order.clone()
+        })
+    }
+
+    // Look for an order with the given tag in the order book
+    async fn find_order_by_tag(&self, tag: &str) -> Result<Option<Order>, FyersError> {
+        let order = self
+            .orders()
+            .await?
+            .into_iter()
+            .find(|o| o.tag() == Some(tag))
+            .map(|o| Order { id: o.id });
+
+        Ok(order)
     }
 }
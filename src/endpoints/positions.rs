@@ -1,2 +1,3 @@
 mod cancel_pending_orders;
 mod exit_all_positions;
+mod net_positions;
@@ -1,3 +1,4 @@
+use crate::endpoints::Endpoint;
 use crate::{Fyers, FyersError};
 
 impl Fyers {
@@ -8,6 +9,7 @@ impl Fyers {
     pub async fn cancel_pending_orders(&self, position_id: &str) -> Result<(), FyersError> {
         let url = format!("{}/positions", self.base_urls.api_v3);
         self.delete(
+            Endpoint::CancelPendingOrders,
             &url,
             &serde_json::json!({"pending_orders_cancel": 1, "id": position_id}),
         )
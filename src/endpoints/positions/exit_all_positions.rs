@@ -1,3 +1,4 @@
+use crate::endpoints::Endpoint;
 use crate::{ExitPositionResult, Fyers, FyersError, models::api_response::ApiResponse};
 
 impl Fyers {
@@ -8,7 +9,11 @@ impl Fyers {
     pub async fn exit_all_positions(&self) -> Result<ExitPositionResult, FyersError> {
         let url = format!("{}/positions", self.base_urls.api_v3);
         let response = self
-            .delete(&url, &serde_json::json!({"exit_all": 1}))
+            .delete(
+                Endpoint::ExitAllPositions,
+                &url,
+                &serde_json::json!({"exit_all": 1}),
+            )
             .await?;
         let api_response: ApiResponse = serde_json::from_value(response)?;
 
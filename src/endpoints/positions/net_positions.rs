@@ -0,0 +1,15 @@
+use crate::endpoints::Endpoint;
+use crate::models::positions::position::PositionsResponse;
+use crate::{Fyers, FyersError, Position};
+
+impl Fyers {
+    /// Fetch the net positions for the current trading day.
+    ///
+    /// Closed positions are included with a `net_qty` of `0`.
+    pub async fn positions(&self) -> Result<Vec<Position>, FyersError> {
+        let url = format!("{}/positions", self.base_urls.api_v3);
+        let response = self.get(Endpoint::Positions, &url).await?;
+        let api_response: PositionsResponse = serde_json::from_value(response)?;
+        Ok(api_response.net_positions)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::endpoints::Endpoint;
+use crate::models::quotes::quote::QuotesResponse;
+use crate::{Fyers, FyersError, Quote};
+
+impl Fyers {
+    /// Fetch the latest quotes for one or more symbols.
+    ///
+    /// Fyers accepts up to 50 symbols per request.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use fyers::Fyers;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let fyers = Fyers::new("id", "token");
+    /// let quotes = fyers.quotes(&["NSE:SBIN-EQ", "NSE:IDEA-EQ"]).await?;
+    /// println!("SBIN bid {} ask {}", quotes[0].bid, quotes[0].ask);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quotes(&self, symbols: &[&str]) -> Result<Vec<Quote>, FyersError> {
+        let url = format!("{}/quotes", self.base_urls.data);
+        let query = [("symbols", symbols.join(","))];
+        let response = self.get_query(Endpoint::Quotes, &url, &query).await?;
+        let api_response: QuotesResponse = serde_json::from_value(response)?;
+        Ok(api_response.quotes.into_iter().map(Quote::from).collect())
+    }
+}
@@ -1,6 +1,9 @@
 use reqwest::StatusCode;
 use thiserror::Error;
 
+use crate::OrderValidationError;
+use crate::auth::AuthError;
+
 /// Errors that can occur when interacting with the Fyers API.
 ///
 /// This enum represents **all failures that can happen when sending requests
@@ -53,6 +56,13 @@ pub enum FyersError {
     #[error("invalid token")]
     InvalidToken,
 
+    /// The access token was rejected and refreshing it failed.
+    ///
+    /// Only returned when a [`TokenProvider`](crate::auth::TokenProvider)
+    /// is configured.
+    #[error("failed to refresh tokens: {0}")]
+    TokenRefresh(#[source] AuthError),
+
     /// One or more request parameters were invalid.
     ///
     /// The contained message is provided by the Fyers API.
@@ -81,6 +91,14 @@ pub enum FyersError {
     #[error("rate limit exceeded")]
     RateLimited,
 
+    /// A [`RateLimiter`](crate::RateLimiter) configured with
+    /// [`RateLimitPolicy::FailFast`](crate::RateLimitPolicy::FailFast)
+    /// ran out of quota, so the request wasn't sent.
+    ///
+    /// Unlike [`FyersError::RateLimited`], this is never retried.
+    #[error("local rate limit exceeded")]
+    LocalRateLimit,
+
     /// A generic error returned by the Fyers API.
     ///
     /// This is used as a fallback when an error code is not yet mapped
@@ -102,6 +120,26 @@ pub enum FyersError {
     #[error("json error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// Reading or writing a local file failed.
+    ///
+    /// Returned when saving or loading the state of a
+    /// [`TrailingStop`](crate::exits::TrailingStop).
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// An order failed client-side validation.
+    ///
+    /// Lets [`OrderBuilder::try_build`](crate::OrderBuilder::try_build) be
+    /// used with `?` where a `FyersError` is expected.
+    #[error(transparent)]
+    InvalidOrder(#[from] OrderValidationError),
+
+    /// The client was configured incorrectly.
+    ///
+    /// Returned by [`FyersBuilder::build`](crate::FyersBuilder::build).
+    #[error("invalid client configuration: {0}")]
+    InvalidConfig(String),
+
     /// A required field was missing from a successful API response.
     ///
     /// This indicates an unexpected API response format.
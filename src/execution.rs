@@ -0,0 +1,395 @@
+//! TWAP and VWAP execution of large orders.
+//!
+//! An execution works a parent order by placing child orders over a time
+//! window:
+//!
+//! - [`Execution::twap`] splits the quantity evenly across the window.
+//! - [`Execution::vwap`] splits it in proportion to the volume usually
+//!   traded at each time of day, from a [`VolumeCurve`] built with
+//!   [`Fyers::history`].
+//!
+//! The window is divided into intervals of
+//! [`interval`](ExecutionBuilder::interval), with one child order at the
+//! start of each. Children are capped by
+//! [`max_child_qty`](ExecutionBuilder::max_child_qty), the freeze quantity
+//! of the [`instrument`](ExecutionBuilder::instrument) and
+//! [`max_participation`](ExecutionBuilder::max_participation). Quantity
+//! held back by a cap moves to the next interval, and whatever is left
+//! after the last one isn't traded.
+//!
+//! [`ExecutionBuilder::build`] returns the [`ExecutionPlan`], which can be
+//! inspected and then [started](ExecutionPlan::start) on any [`Broker`].
+//! The returned [`ExecutionHandle`] pauses, resumes and cancels the
+//! execution and reports its progress.
+//!
+//! [`Iceberg`] instead works an order by showing one slice at a time at
+//! the best price, and placing the next once it fills.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use fyers::execution::{Execution, VolumeCurve};
+//! use fyers::{CandleResolution, Fyers, HistoryRequest, OrderRequest, ProductType, Side};
+//!
+//! # async fn run() -> Result<(), fyers::FyersError> {
+//! let fyers = Fyers::new("CLIENT_ID", "ACCESS_TOKEN");
+//!
+//! // Volume profile of the last week
+//! let history = HistoryRequest::builder(
+//!     "NSE:SBIN-EQ",
+//!     fyers::ist_datetime(2025, 1, 6, 9, 15),
+//!     fyers::ist_datetime(2025, 1, 10, 15, 30),
+//! )
+//! .resolution(CandleResolution::Minute5)
+//! .build();
+//! let curve = VolumeCurve::from_history(&fyers, &history).await?;
+//!
+//! let order = OrderRequest::market("NSE:SBIN-EQ", 5000, Side::Buy, ProductType::Cnc)
+//!     .order_tag("vwap")
+//!     .build();
+//!
+//! let plan = Execution::vwap(
+//!     &order,
+//!     fyers::ist_datetime(2025, 1, 13, 9, 30),
+//!     fyers::ist_datetime(2025, 1, 13, 15, 0),
+//!     curve,
+//! )
+//! .interval(Duration::from_secs(300))
+//! .max_participation(0.05)
+//! .build()?;
+//!
+//! let handle = plan.start(fyers.clone());
+//! let progress = handle.wait().await?;
+//! println!("Filled {} of {}", progress.filled_qty, progress.target_qty);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::datetime::IST;
+use crate::models::orders::order_validation;
+use crate::{Broker, FyersError, InstrumentInfo, OrderRequest};
+
+#[cfg(doc)]
+use crate::Fyers;
+
+mod iceberg;
+mod runner;
+mod volume_curve;
+
+pub use iceberg::{Iceberg, IcebergOutcome, IcebergReport, PriceSource};
+pub use runner::{ChildOrder, ExecutionHandle, ExecutionProgress, ExecutionState};
+pub use volume_curve::VolumeCurve;
+
+/// Entry point for building TWAP and VWAP executions.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug)]
+pub struct Execution;
+
+/// Builder for an [`ExecutionPlan`].
+#[must_use = "builders must be finalized with .build()"]
+#[derive(Debug, Clone)]
+pub struct ExecutionBuilder {
+    order: OrderRequest,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    interval: Duration,
+    weighting: Weighting,
+    curve: Option<VolumeCurve>,
+    max_child_qty: Option<u32>,
+    max_participation: Option<f64>,
+    instrument: Option<InstrumentInfo>,
+    tag_prefix: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Weighting {
+    Time,
+    Volume,
+}
+
+/// The child orders of an execution, before any are placed.
+#[derive(Debug, Clone)]
+pub struct ExecutionPlan {
+    /// Quantity of the parent order
+    pub target_qty: u32,
+    /// Child orders in time order
+    pub children: Vec<PlannedChild>,
+    /// Quantity the caps left out of the plan
+    pub unscheduled_qty: u32,
+}
+
+/// A child order of an [`ExecutionPlan`].
+#[derive(Debug, Clone)]
+pub struct PlannedChild {
+    /// When the child is placed
+    pub time: DateTime<Utc>,
+    /// The child order
+    pub order: OrderRequest,
+}
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(300);
+
+impl Execution {
+    /// Work `order` evenly between `start` and `end`.
+    pub fn twap(
+        order: &OrderRequest,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> ExecutionBuilder {
+        ExecutionBuilder::new(order, start, end, Weighting::Time, None)
+    }
+
+    /// Work `order` between `start` and `end` in proportion to the volume
+    /// `curve` expects in each interval.
+    ///
+    /// Falls back to an even split if the curve has no volume in the
+    /// window.
+    pub fn vwap(
+        order: &OrderRequest,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        curve: VolumeCurve,
+    ) -> ExecutionBuilder {
+        ExecutionBuilder::new(order, start, end, Weighting::Volume, Some(curve))
+    }
+}
+
+impl ExecutionBuilder {
+    fn new(
+        order: &OrderRequest,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        weighting: Weighting,
+        curve: Option<VolumeCurve>,
+    ) -> Self {
+        Self {
+            order: order.clone(),
+            start,
+            end,
+            interval: DEFAULT_INTERVAL,
+            weighting,
+            curve,
+            max_child_qty: None,
+            max_participation: None,
+            instrument: None,
+            tag_prefix: None,
+        }
+    }
+
+    /// Place a child order every `interval`.
+    ///
+    /// Defaults to five minutes.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Cap the quantity of each child order.
+    pub fn max_child_qty(mut self, qty: u32) -> Self {
+        self.max_child_qty = Some(qty);
+        self
+    }
+
+    /// Cap each child order at this fraction (e.g. `0.1` for 10%) of the
+    /// volume expected in its interval.
+    ///
+    /// The expected volume comes from the [`VolumeCurve`], so a TWAP
+    /// execution needs one set with
+    /// [`volume_curve`](ExecutionBuilder::volume_curve).
+    pub fn max_participation(mut self, fraction: f64) -> Self {
+        self.max_participation = Some(fraction);
+        self
+    }
+
+    /// Set the volume curve used for participation limits.
+    ///
+    /// This doesn't change how a TWAP execution splits the quantity.
+    pub fn volume_curve(mut self, curve: VolumeCurve) -> Self {
+        self.curve = Some(curve);
+        self
+    }
+
+    /// Size children in whole lots, keep them below the freeze quantity
+    /// and check them against the instrument's trading rules.
+    pub fn instrument(mut self, instrument: InstrumentInfo) -> Self {
+        self.instrument = Some(instrument);
+        self
+    }
+
+    /// Tag the child orders `{prefix}1`, `{prefix}2` and so on.
+    ///
+    /// Defaults to the tag of the parent order, if it has one.
+    pub fn tag_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.tag_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Return the [`ExecutionPlan`].
+    ///
+    /// Fails with [`FyersError::InvalidParams`] if the window or interval
+    /// is empty, or if a participation limit is set without a volume
+    /// curve. Child orders that break the order rules are returned as
+    /// [`FyersError::InvalidOrder`].
+    pub fn build(self) -> Result<ExecutionPlan, FyersError> {
+        let invalid = |msg: &str| Err(FyersError::InvalidParams(msg.into()));
+
+        if self.end <= self.start {
+            return invalid("execution window must end after it starts");
+        }
+        if self.interval.is_zero() {
+            return invalid("execution interval must be positive");
+        }
+        if self.max_participation.is_some() && self.curve.is_none() {
+            return invalid("participation limits need a volume curve");
+        }
+
+        let interval = TimeDelta::from_std(self.interval)
+            .map_err(|_| FyersError::InvalidParams("execution interval is too long".into()))?;
+
+        let mut times = Vec::new();
+        let mut time = self.start;
+        while time < self.end {
+            times.push(time);
+            time += interval;
+        }
+
+        // Expected volume of each interval
+        let volumes: Vec<f64> = times
+            .iter()
+            .map(|&from| {
+                let to = (from + interval).min(self.end);
+                self.curve.as_ref().map_or(0.0, |curve| {
+                    // Intervals of a day or more cover whole days first
+                    let days = (to - from).num_days() as f64;
+                    days * curve.daily_volume()
+                        + curve.volume(
+                            from.with_timezone(&IST).time(),
+                            to.with_timezone(&IST).time(),
+                        )
+                })
+            })
+            .collect();
+
+        let lot_size = self.instrument.map_or(1, |i| i.lot_size.max(1));
+        let lots = self.order.qty / lot_size;
+        let odd_qty = self.order.qty % lot_size;
+
+        let weights = match self.weighting {
+            Weighting::Volume if volumes.iter().sum::<f64>() > 0.0 => volumes.clone(),
+            _ => vec![1.0; times.len()],
+        };
+
+        let mut sizes = allocate(lots, &weights);
+
+        // Apply the caps, carrying what they hold back forward
+        let mut carry = 0;
+        for (size, volume) in sizes.iter_mut().zip(&volumes) {
+            let wanted = *size + carry;
+            let cap = self.cap_lots(lot_size, *volume).unwrap_or(u32::MAX);
+            *size = wanted.min(cap);
+            carry = wanted - *size;
+        }
+
+        let mut qty: Vec<u32> = sizes.iter().map(|lots| lots * lot_size).collect();
+
+        // A quantity that isn't a whole number of lots goes last, where
+        // validation reports it
+        if let Some(last) = qty.iter_mut().rev().find(|q| **q > 0) {
+            *last += odd_qty;
+        } else if let Some(first) = qty.first_mut() {
+            *first += odd_qty;
+        }
+
+        let prefix = self
+            .tag_prefix
+            .as_deref()
+            .or(self.order.order_tag.as_deref());
+
+        let mut children = Vec::new();
+        for (time, qty) in times.into_iter().zip(qty).filter(|(_, qty)| *qty > 0) {
+            let order = OrderRequest {
+                qty,
+                disclosed_qty: self.order.disclosed_qty.min(qty),
+                order_tag: prefix.map(|prefix| format!("{prefix}{}", children.len() + 1)),
+                is_slice_order: false,
+                ..self.order.clone()
+            };
+
+            order_validation::validate(&order, self.instrument.as_ref())?;
+            children.push(PlannedChild { time, order });
+        }
+
+        Ok(ExecutionPlan {
+            target_qty: self.order.qty,
+            children,
+            unscheduled_qty: carry * lot_size,
+        })
+    }
+
+    // The most lots a child in an interval with `volume` may have
+    fn cap_lots(&self, lot_size: u32, volume: f64) -> Option<u32> {
+        let freeze = self
+            .instrument
+            .and_then(|i| i.freeze_qty)
+            .map(|freeze_qty| freeze_qty.saturating_sub(1));
+        let participation = self
+            .max_participation
+            .map(|fraction| (fraction * volume).max(0.0) as u32);
+
+        [self.max_child_qty, freeze, participation]
+            .into_iter()
+            .flatten()
+            .min()
+            .map(|qty| qty / lot_size)
+    }
+}
+
+// Split `lots` in proportion to `weights`, handing the lots lost to
+// rounding down to the largest remainders
+fn allocate(lots: u32, weights: &[f64]) -> Vec<u32> {
+    let total: f64 = weights.iter().sum();
+    let ideal: Vec<f64> = weights.iter().map(|w| lots as f64 * w / total).collect();
+
+    let mut sizes: Vec<u32> = ideal.iter().map(|x| x.floor() as u32).collect();
+    let mut left = lots - sizes.iter().sum::<u32>();
+
+    let mut order: Vec<usize> = (0..ideal.len()).collect();
+    order.sort_by(|&a, &b| {
+        let (ra, rb) = (ideal[a].fract(), ideal[b].fract());
+        rb.total_cmp(&ra).then(a.cmp(&b))
+    });
+
+    for i in order {
+        if left == 0 {
+            break;
+        }
+        sizes[i] += 1;
+        left -= 1;
+    }
+
+    sizes
+}
+
+impl ExecutionPlan {
+    /// Total quantity of the child orders.
+    pub fn scheduled_qty(&self) -> u32 {
+        self.children.iter().map(|c| c.order.qty).sum()
+    }
+
+    /// Start placing the child orders through `broker` in the background.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn start<B>(self, broker: B) -> ExecutionHandle
+    where
+        B: Broker + Send + Sync + 'static,
+    {
+        runner::start(self, broker)
+    }
+}
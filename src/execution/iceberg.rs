@@ -0,0 +1,303 @@
+use std::future::Future;
+use std::time::Duration;
+
+use crate::models::orders::order_validation;
+use crate::{
+    Broker, Fyers, FyersError, ModifyOrderRequest, OrderDetails, OrderRequest, OrderStatus,
+    OrderType, Side, slicing,
+};
+
+#[cfg(doc)]
+use crate::paper::PaperTrader;
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_CHASE_STEPS: u32 = 5;
+
+/// Works a large limit order by showing a small slice of it at a time.
+///
+/// Only one slice of [`visible_qty`](Iceberg::new) is in the order book
+/// at once. The slice is placed at the best bid (for a buy) or ask (for a
+/// sell) from a [`PriceSource`], and the next one is placed once it has
+/// filled.
+///
+/// While a slice is open, the order book and quote are checked every
+/// [`poll_interval`](Iceberg::poll_interval). If the best price has moved
+/// away from the slice, the slice is modified to follow it. The limit
+/// price of the parent order is never crossed, and a slice is chased at
+/// most [`max_chase_steps`](Iceberg::max_chase_steps) times before the
+/// iceberg gives up and cancels it.
+///
+/// # Example
+///
+/// ```no_run
+/// use fyers::execution::Iceberg;
+/// use fyers::{Fyers, OrderRequest, ProductType, Side};
+///
+/// # async fn run() -> Result<(), fyers::FyersError> {
+/// let fyers = Fyers::new("CLIENT_ID", "ACCESS_TOKEN");
+///
+/// // Buy 5000 at up to 585, showing 100 at a time
+/// let order = OrderRequest::limit("NSE:SBIN-EQ", 5000, Side::Buy, ProductType::Cnc, 585.0)
+///     .order_tag("ice")
+///     .build();
+///
+/// let report = Iceberg::new(&order, 100)?.run(&fyers, &fyers).await?;
+/// println!("Filled {} at {:.2}", report.filled_qty, report.avg_price);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Iceberg {
+    order: OrderRequest,
+    visible_qty: u32,
+    poll_interval: Duration,
+    max_chase_steps: u32,
+}
+
+/// How an [`Iceberg`] ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcebergOutcome {
+    /// The whole quantity filled
+    Filled,
+    /// A slice was chased [`max_chase_steps`](Iceberg::max_chase_steps)
+    /// times without filling, and was cancelled
+    ChaseLimitReached,
+    /// A slice was closed without filling in full, e.g. cancelled or
+    /// rejected outside the iceberg
+    SliceClosed(OrderStatus),
+}
+
+/// The outcome of [`Iceberg::run`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcebergReport {
+    /// Quantity of the parent order
+    pub qty: u32,
+    /// Quantity filled across all slices
+    pub filled_qty: u32,
+    /// Average fill price across all slices
+    pub avg_price: f64,
+    /// IDs of the slices, oldest first
+    pub orders: Vec<String>,
+    /// Why the iceberg stopped
+    pub outcome: IcebergOutcome,
+}
+
+/// Where an [`Iceberg`] gets the price to place and chase slices at.
+///
+/// Implemented for [`Fyers`], which takes the best bid or ask from
+/// [`Fyers::quotes`], and for [`PaperTrader`], which uses the last price
+/// fed to it.
+pub trait PriceSource {
+    /// The best price on `side` of the book for `symbol`: the best bid
+    /// for a buy and the best ask for a sell.
+    fn best_price(
+        &self,
+        symbol: &str,
+        side: Side,
+    ) -> impl Future<Output = Result<f64, FyersError>> + Send;
+}
+
+impl PriceSource for Fyers {
+    async fn best_price(&self, symbol: &str, side: Side) -> Result<f64, FyersError> {
+        let quote = self
+            .quotes(&[symbol])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(FyersError::InvalidSymbol)?;
+
+        Ok(match side {
+            Side::Buy if quote.bid > 0.0 => quote.bid,
+            Side::Sell if quote.ask > 0.0 => quote.ask,
+            // An empty side of the book
+            _ => quote.ltp,
+        })
+    }
+}
+
+impl Iceberg {
+    /// Work `order`, showing `visible_qty` at a time.
+    ///
+    /// `order` must be a limit order. Its limit price is the worst price
+    /// any slice is placed at.
+    pub fn new(order: &OrderRequest, visible_qty: u32) -> Result<Self, FyersError> {
+        if order.r#type != OrderType::Limit {
+            return Err(FyersError::InvalidParams(
+                "iceberg orders must be limit orders".into(),
+            ));
+        }
+        if visible_qty == 0 {
+            return Err(FyersError::InvalidParams(
+                "iceberg visible quantity must be positive".into(),
+            ));
+        }
+
+        order_validation::validate(order, None)?;
+
+        Ok(Self {
+            order: order.clone(),
+            visible_qty,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            max_chase_steps: DEFAULT_MAX_CHASE_STEPS,
+        })
+    }
+
+    /// How often to check the order book and quote while a slice is open.
+    ///
+    /// Defaults to one second.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// How many times a slice may be moved to follow the market.
+    ///
+    /// Defaults to 5.
+    pub fn max_chase_steps(mut self, steps: u32) -> Self {
+        self.max_chase_steps = steps;
+        self
+    }
+
+    /// Place slices through `broker` at prices from `prices`, and chase
+    /// them until the order fills or the iceberg gives up.
+    ///
+    /// Slices are tagged `{tag}1`, `{tag}2` and so on when the parent
+    /// order has a tag. A rate limited slice is retried after a short
+    /// wait. Any other error leaves the open slice, if any, working.
+    pub async fn run<B: Broker, P: PriceSource>(
+        &self,
+        broker: &B,
+        prices: &P,
+    ) -> Result<IcebergReport, FyersError> {
+        let mut report = IcebergReport {
+            qty: self.order.qty,
+            filled_qty: 0,
+            avg_price: 0.0,
+            orders: Vec::new(),
+            outcome: IcebergOutcome::Filled,
+        };
+        let mut value = 0.0;
+
+        while report.filled_qty < self.order.qty {
+            let qty = self.visible_qty.min(self.order.qty - report.filled_qty);
+            let price = self.best_price(prices).await?;
+
+            let slice = OrderRequest {
+                qty,
+                limit_price: price,
+                disclosed_qty: 0,
+                order_tag: self
+                    .order
+                    .order_tag
+                    .as_ref()
+                    .map(|tag| format!("{tag}{}", report.orders.len() + 1)),
+                ..self.order.clone()
+            };
+
+            let id = slicing::place_slice(broker, &slice).await?.id;
+            report.orders.push(id.clone());
+
+            let (details, outcome) = self.work_slice(broker, prices, &id).await?;
+
+            let filled = details.filled_qty.min(qty);
+            report.filled_qty += filled;
+            value += filled as f64 * details.traded_price;
+
+            if let Some(outcome) = outcome {
+                report.outcome = outcome;
+                break;
+            }
+        }
+
+        if report.filled_qty > 0 {
+            report.avg_price = value / report.filled_qty as f64;
+        }
+
+        Ok(report)
+    }
+
+    // Wait for a slice to close, chasing the best price while it is open.
+    // Returns the slice's final state, and an outcome if the iceberg
+    // should stop.
+    async fn work_slice<B: Broker, P: PriceSource>(
+        &self,
+        broker: &B,
+        prices: &P,
+        id: &str,
+    ) -> Result<(OrderDetails, Option<IcebergOutcome>), FyersError> {
+        let mut chase_steps = 0;
+
+        loop {
+            tokio::time::sleep(self.poll_interval).await;
+
+            let details = find_order(broker, id).await?;
+
+            if !details.status.is_open() {
+                return Ok(closed(details));
+            }
+
+            let price = self.best_price(prices).await?;
+            let moved_away = match self.order.side {
+                Side::Buy => price > details.limit_price,
+                Side::Sell => price < details.limit_price,
+            };
+
+            if !moved_away {
+                continue;
+            }
+
+            if chase_steps == self.max_chase_steps {
+                let cancelled = broker.cancel_order(id).await;
+                let details = find_order(broker, id).await?;
+
+                // The slice may have filled just before the cancel
+                return match cancelled {
+                    Err(_) if !details.status.is_open() => Ok(closed(details)),
+                    Err(err) => Err(err),
+                    Ok(()) => Ok((details, Some(IcebergOutcome::ChaseLimitReached))),
+                };
+            }
+
+            let modify = ModifyOrderRequest::builder(id).limit_price(price).build();
+            if let Err(err) = broker.modify_order(&modify).await {
+                // The slice may have closed since it was looked up
+                let details = find_order(broker, id).await?;
+                if details.status.is_open() {
+                    return Err(err);
+                }
+                return Ok(closed(details));
+            }
+            chase_steps += 1;
+        }
+    }
+
+    // The best price on our side of the book, capped at the limit price
+    async fn best_price<P: PriceSource>(&self, prices: &P) -> Result<f64, FyersError> {
+        let best = prices
+            .best_price(&self.order.symbol, self.order.side)
+            .await?;
+
+        let limit = self.order.limit_price;
+        Ok(match self.order.side {
+            Side::Buy => best.min(limit),
+            Side::Sell => best.max(limit),
+        })
+    }
+}
+
+// The result of a slice that closed by itself
+fn closed(details: OrderDetails) -> (OrderDetails, Option<IcebergOutcome>) {
+    match details.status {
+        OrderStatus::Filled => (details, None),
+        status => (details, Some(IcebergOutcome::SliceClosed(status))),
+    }
+}
+
+async fn find_order<B: Broker>(broker: &B, id: &str) -> Result<OrderDetails, FyersError> {
+    broker
+        .orders()
+        .await?
+        .into_iter()
+        .find(|o| o.id == id)
+        .ok_or(FyersError::InvalidOrderId)
+}
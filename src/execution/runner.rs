@@ -0,0 +1,327 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use super::ExecutionPlan;
+use crate::{Broker, FyersError, slicing};
+
+/// Where an execution is in its life.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionState {
+    /// Placing child orders as they come due
+    Running,
+    /// Waiting for [`ExecutionHandle::resume`]
+    Paused,
+    /// Stopped by [`ExecutionHandle::cancel`]
+    Cancelled,
+    /// Every child order was placed
+    Completed,
+    /// Stopped by an error
+    Failed,
+}
+
+impl ExecutionState {
+    /// Whether the execution has stopped for good.
+    pub fn is_finished(self) -> bool {
+        matches!(
+            self,
+            ExecutionState::Cancelled | ExecutionState::Completed | ExecutionState::Failed
+        )
+    }
+}
+
+/// A child order placed by an execution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChildOrder {
+    /// Order ID
+    pub id: String,
+    /// Quantity of the child
+    pub qty: u32,
+    /// When the child was placed
+    pub time: DateTime<Utc>,
+}
+
+/// Progress of an execution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionProgress {
+    /// Current state
+    pub state: ExecutionState,
+    /// Quantity of the parent order
+    pub target_qty: u32,
+    /// Quantity of all planned child orders
+    pub scheduled_qty: u32,
+    /// Quantity of the child orders placed so far
+    pub placed_qty: u32,
+    /// Quantity filled, as of the last order book check.
+    ///
+    /// The order book is checked after every child order is placed and
+    /// once more when the execution finishes. Child orders that rest may
+    /// fill later, so use [`ExecutionHandle::refresh`] to check again.
+    pub filled_qty: u32,
+    /// Child orders placed so far, oldest first
+    pub children: Vec<ChildOrder>,
+}
+
+/// Controls a running execution.
+///
+/// Returned by [`ExecutionPlan::start`]. Dropping the handle leaves a
+/// running execution running, and cancels a paused one.
+pub struct ExecutionHandle {
+    progress: Arc<Mutex<ExecutionProgress>>,
+    control: watch::Sender<Control>,
+    task: JoinHandle<Result<(), FyersError>>,
+    refresh: Refresh,
+}
+
+// Refreshes the fills through the execution's broker
+type Refresh = Box<dyn Fn() -> RefreshFuture + Send + Sync>;
+type RefreshFuture = Pin<Box<dyn Future<Output = Result<(), FyersError>> + Send>>;
+
+impl std::fmt::Debug for ExecutionHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecutionHandle")
+            .field("progress", &self.progress)
+            .field("control", &self.control)
+            .field("task", &self.task)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    Run,
+    Pause,
+    Cancel,
+}
+
+pub(super) fn start<B>(plan: ExecutionPlan, broker: B) -> ExecutionHandle
+where
+    B: Broker + Send + Sync + 'static,
+{
+    let progress = Arc::new(Mutex::new(ExecutionProgress {
+        state: ExecutionState::Running,
+        target_qty: plan.target_qty,
+        scheduled_qty: plan.scheduled_qty(),
+        placed_qty: 0,
+        filled_qty: 0,
+        children: Vec::new(),
+    }));
+
+    let (control, mut receiver) = watch::channel(Control::Run);
+    let broker = Arc::new(broker);
+
+    let refresh: Refresh = {
+        let broker = Arc::clone(&broker);
+        let progress = Arc::clone(&progress);
+        Box::new(move || {
+            let broker = Arc::clone(&broker);
+            let progress = Arc::clone(&progress);
+            Box::pin(async move { check_fills(&*broker, &progress).await })
+        })
+    };
+
+    let shared = Arc::clone(&progress);
+    let task = tokio::spawn(async move {
+        let result = run(&*broker, plan, &shared, &mut receiver).await;
+
+        lock(&shared).state = match result {
+            Ok(true) => ExecutionState::Completed,
+            Ok(false) => ExecutionState::Cancelled,
+            Err(_) => ExecutionState::Failed,
+        };
+
+        result.map(|_| ())
+    });
+
+    ExecutionHandle {
+        progress,
+        control,
+        task,
+        refresh,
+    }
+}
+
+impl ExecutionHandle {
+    /// Stop placing child orders until [`resume`](ExecutionHandle::resume)
+    /// is called.
+    ///
+    /// Child orders already placed keep working.
+    pub fn pause(&self) {
+        let mut progress = lock(&self.progress);
+
+        if progress.state == ExecutionState::Running {
+            progress.state = ExecutionState::Paused;
+            self.control.send_replace(Control::Pause);
+        }
+    }
+
+    /// Resume a paused execution.
+    ///
+    /// Child orders that came due while paused are placed straight away.
+    pub fn resume(&self) {
+        let mut progress = lock(&self.progress);
+
+        if progress.state == ExecutionState::Paused {
+            progress.state = ExecutionState::Running;
+            self.control.send_replace(Control::Run);
+        }
+    }
+
+    /// Stop the execution and cancel its child orders that are still
+    /// open.
+    ///
+    /// Use [`wait`](ExecutionHandle::wait) to find out when this is done.
+    pub fn cancel(&self) {
+        if !lock(&self.progress).state.is_finished() {
+            self.control.send_replace(Control::Cancel);
+        }
+    }
+
+    /// The progress so far.
+    ///
+    /// `filled_qty` is as of the last order book check.
+    pub fn progress(&self) -> ExecutionProgress {
+        lock(&self.progress).clone()
+    }
+
+    /// Check the order book for new fills and return the progress.
+    pub async fn refresh(&self) -> Result<ExecutionProgress, FyersError> {
+        (self.refresh)().await?;
+        Ok(self.progress())
+    }
+
+    /// Wait for the execution to finish and return its final progress.
+    ///
+    /// Returns the error that stopped the execution, if any.
+    pub async fn wait(self) -> Result<ExecutionProgress, FyersError> {
+        let ExecutionHandle { progress, task, .. } = self;
+
+        match task.await {
+            Ok(result) => result?,
+            Err(err) => std::panic::resume_unwind(err.into_panic()),
+        }
+
+        Ok(lock(&progress).clone())
+    }
+}
+
+// Place the children as they come due. Returns false if the execution
+// was cancelled.
+async fn run<B: Broker>(
+    broker: &B,
+    plan: ExecutionPlan,
+    progress: &Mutex<ExecutionProgress>,
+    control: &mut watch::Receiver<Control>,
+) -> Result<bool, FyersError> {
+    for child in plan.children {
+        if !wait_until(child.time, control).await {
+            cancel_children(broker, progress).await?;
+            return Ok(false);
+        }
+
+        let placed = slicing::place_slice(broker, &child.order).await?;
+
+        {
+            let mut progress = lock(progress);
+            progress.placed_qty += child.order.qty;
+            progress.children.push(ChildOrder {
+                id: placed.id,
+                qty: child.order.qty,
+                time: Utc::now(),
+            });
+        }
+
+        update_fills(broker, progress).await;
+    }
+
+    // Children placed last may have filled since
+    update_fills(broker, progress).await;
+    Ok(true)
+}
+
+// Wait for `time` while the execution is running. Returns false if it
+// is cancelled first.
+async fn wait_until(time: DateTime<Utc>, control: &mut watch::Receiver<Control>) -> bool {
+    loop {
+        let state = *control.borrow_and_update();
+
+        match state {
+            Control::Cancel => return false,
+            Control::Pause => {
+                // Nobody is left to resume the execution
+                if control.changed().await.is_err() {
+                    return false;
+                }
+                continue;
+            }
+            Control::Run => {}
+        }
+
+        let wait = (time - Utc::now()).to_std().unwrap_or(Duration::ZERO);
+        if wait.is_zero() {
+            return true;
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(wait) => return true,
+            changed = control.changed() => {
+                // The handle is gone, so nothing can change any more
+                if changed.is_err() {
+                    tokio::time::sleep(wait).await;
+                    return true;
+                }
+            }
+        }
+    }
+}
+
+// Refresh the filled quantity from the order book
+async fn update_fills<B: Broker>(broker: &B, progress: &Mutex<ExecutionProgress>) {
+    // A failed refresh only leaves the fills stale
+    let _ = check_fills(broker, progress).await;
+}
+
+async fn check_fills<B: Broker>(
+    broker: &B,
+    progress: &Mutex<ExecutionProgress>,
+) -> Result<(), FyersError> {
+    let book = broker.orders().await?;
+
+    let mut progress = lock(progress);
+    progress.filled_qty = progress
+        .children
+        .iter()
+        .filter_map(|child| book.iter().find(|o| o.id == child.id))
+        .map(|o| o.filled_qty)
+        .sum();
+    Ok(())
+}
+
+async fn cancel_children<B: Broker>(
+    broker: &B,
+    progress: &Mutex<ExecutionProgress>,
+) -> Result<(), FyersError> {
+    let ids: Vec<String> = lock(progress)
+        .children
+        .iter()
+        .map(|c| c.id.clone())
+        .collect();
+
+    for order in broker.orders().await? {
+        if order.status.is_open() && ids.contains(&order.id) {
+            broker.cancel_order(&order.id).await?;
+        }
+    }
+
+    update_fills(broker, progress).await;
+    Ok(())
+}
+
+fn lock(progress: &Mutex<ExecutionProgress>) -> MutexGuard<'_, ExecutionProgress> {
+    progress.lock().unwrap_or_else(|e| e.into_inner())
+}
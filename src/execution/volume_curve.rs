@@ -0,0 +1,72 @@
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::NaiveTime;
+
+use crate::datetime::IST;
+use crate::{Candle, Fyers, FyersError, HistoryRequest};
+
+/// Average traded volume by time of day.
+///
+/// Built from intraday candles over one or more days. Each candle's
+/// volume is added to the bucket for its IST start time, and every bucket
+/// is averaged over the number of days seen.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VolumeCurve {
+    buckets: BTreeMap<NaiveTime, f64>,
+}
+
+impl VolumeCurve {
+    /// Build a curve from intraday candles.
+    pub fn from_candles<'a>(candles: impl IntoIterator<Item = &'a Candle>) -> Self {
+        let mut totals: BTreeMap<NaiveTime, u64> = BTreeMap::new();
+        let mut days = HashSet::new();
+
+        for candle in candles {
+            let time = candle.time.with_timezone(&IST);
+            days.insert(time.date_naive());
+            *totals.entry(time.time()).or_default() += candle.volume;
+        }
+
+        let days = days.len().max(1) as f64;
+        let buckets = totals
+            .into_iter()
+            .map(|(time, volume)| (time, volume as f64 / days))
+            .collect();
+
+        Self { buckets }
+    }
+
+    /// Download intraday candles with [`Fyers::history`] and build a curve
+    /// from them.
+    ///
+    /// Request a few recent days at the resolution the execution will
+    /// trade at.
+    pub async fn from_history(fyers: &Fyers, request: &HistoryRequest) -> Result<Self, FyersError> {
+        let candles = fyers.history(request).await?;
+        Ok(Self::from_candles(&candles))
+    }
+
+    /// Average volume traded in buckets starting from `from` (inclusive)
+    /// to `to` (exclusive), in IST.
+    ///
+    /// A `to` earlier than `from` wraps around midnight.
+    pub fn volume(&self, from: NaiveTime, to: NaiveTime) -> f64 {
+        if from <= to {
+            return self.buckets.range(from..to).map(|(_, volume)| volume).sum();
+        }
+
+        let before_midnight: f64 = self.buckets.range(from..).map(|(_, volume)| volume).sum();
+        let after_midnight: f64 = self.buckets.range(..to).map(|(_, volume)| volume).sum();
+        before_midnight + after_midnight
+    }
+
+    // Average volume traded over a whole day
+    pub(crate) fn daily_volume(&self) -> f64 {
+        self.buckets.values().sum()
+    }
+
+    /// Whether the curve has no buckets.
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+}
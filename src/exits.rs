@@ -0,0 +1,57 @@
+//! Client-side exit orders.
+//!
+//! Fyers has no native trailing stop for most product types. A
+//! [`TrailingStop`] keeps a stop order resting at the exchange and moves
+//! it with [`Fyers::modify_order`] as the price moves in favour of the
+//! position, by a fixed number of points or a percentage.
+//!
+//! Prices come from [`TrailingStop::update`], which takes a price from any
+//! feed, or from [`TrailingStop::run`], which polls [`Fyers::quotes`].
+//! The state of a trailing stop can be saved to a file after every change
+//! and [resumed](TrailingStop::resume) after a restart.
+//!
+//! Where GTT OCO orders aren't available, an [`Oco`] places a target and a
+//! stop order for a position, watches them through the order book, and
+//! cancels one with [`Fyers::cancel_order`] once the other has closed the
+//! position.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use fyers::exits::{Trail, TrailingStop};
+//! use fyers::{Fyers, ProductType, Side};
+//!
+//! # async fn run() -> Result<(), fyers::FyersError> {
+//! let fyers = Fyers::new("CLIENT_ID", "ACCESS_TOKEN");
+//!
+//! // Pick up where the last run left off, or protect a new long position
+//! let mut stop = match TrailingStop::resume("sbin-stop.json")? {
+//!     Some(stop) => stop,
+//!     None => TrailingStop::new(
+//!         "NSE:SBIN-EQ",
+//!         Side::Buy,
+//!         100,
+//!         ProductType::Intraday,
+//!         Trail::Percent(1.0),
+//!         580.0,
+//!     )?
+//!     .tick_size(0.05)
+//!     .persist("sbin-stop.json"),
+//! };
+//!
+//! let exit_id = stop.run(&fyers, Duration::from_secs(1)).await?;
+//! println!("Exited with order {exit_id}");
+//! # Ok(())
+//! # }
+//! ```
+
+mod oco;
+mod trailing_stop;
+
+pub use oco::{Oco, OcoLeg, OcoOutcome, OcoReport};
+pub use trailing_stop::{Trail, TrailingEvent, TrailingStatus, TrailingStop, TrailingStopState};
+
+#[cfg(doc)]
+use crate::Fyers;
@@ -0,0 +1,285 @@
+use std::time::Duration;
+
+use crate::{
+    Broker, FyersError, ModifyOrderRequest, OrderDetails, OrderRequest, OrderStatus, ProductType,
+    Side,
+};
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A target and a stop order where a fill on one cancels the other.
+///
+/// Exits a position with a limit order at the target price and a stop
+/// (SL-M) order at the stop price. The order book is checked every
+/// [`poll_interval`](Oco::poll_interval), and once the position is closed
+/// the other leg is cancelled.
+///
+/// When a leg fills partially, the other leg's quantity is reduced to
+/// what is left of the position, so the two never close more than it
+/// between them.
+///
+/// # Example
+///
+/// ```no_run
+/// use fyers::exits::Oco;
+/// use fyers::{Fyers, ProductType, Side};
+///
+/// # async fn run() -> Result<(), fyers::FyersError> {
+/// let fyers = Fyers::new("CLIENT_ID", "ACCESS_TOKEN");
+///
+/// // Exit a long position of 100 at 600, or at 570 if it falls
+/// let report = Oco::new("NSE:SBIN-EQ", Side::Buy, 100, ProductType::Intraday, 600.0, 570.0)?
+///     .order_tag("exit")
+///     .run(&fyers)
+///     .await?;
+/// println!("Target filled {}, stop filled {}", report.target_filled_qty, report.stop_filled_qty);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Oco {
+    symbol: String,
+    side: Side,
+    qty: u32,
+    product_type: ProductType,
+    target_price: f64,
+    stop_price: f64,
+    order_tag: Option<String>,
+    poll_interval: Duration,
+    target_id: Option<String>,
+    stop_id: Option<String>,
+}
+
+/// One of the two orders of an [`Oco`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OcoLeg {
+    /// The limit order at the target price
+    Target,
+    /// The stop order at the stop price
+    Stop,
+}
+
+/// How an [`Oco`] ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OcoOutcome {
+    /// The position was closed by one or both legs
+    Closed,
+    /// A leg was cancelled or rejected outside the OCO before the position
+    /// was closed. The other leg is left working.
+    LegClosed {
+        /// The leg that closed
+        leg: OcoLeg,
+        /// Its final status
+        status: OrderStatus,
+    },
+}
+
+/// The outcome of an [`Oco`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcoReport {
+    /// ID of the target order
+    pub target_order_id: String,
+    /// ID of the stop order
+    pub stop_order_id: String,
+    /// Quantity filled by the target order
+    pub target_filled_qty: u32,
+    /// Quantity filled by the stop order
+    pub stop_filled_qty: u32,
+    /// Why the OCO stopped
+    pub outcome: OcoOutcome,
+}
+
+impl Oco {
+    /// Exit a position of `qty` on `side` at `target_price` or
+    /// `stop_price`, whichever is reached first.
+    ///
+    /// For a long position the target must be above the stop, and for a
+    /// short one below it.
+    pub fn new(
+        symbol: impl Into<String>,
+        side: Side,
+        qty: u32,
+        product_type: ProductType,
+        target_price: f64,
+        stop_price: f64,
+    ) -> Result<Self, FyersError> {
+        if qty == 0 {
+            return Err(FyersError::InvalidParams(
+                "oco quantity must be positive".into(),
+            ));
+        }
+        if target_price <= 0.0 || stop_price <= 0.0 {
+            return Err(FyersError::InvalidParams(
+                "oco prices must be positive".into(),
+            ));
+        }
+
+        let ordered = match side {
+            Side::Buy => target_price > stop_price,
+            Side::Sell => target_price < stop_price,
+        };
+        if !ordered {
+            return Err(FyersError::InvalidParams(
+                "oco target must be on the profitable side of the stop".into(),
+            ));
+        }
+
+        Ok(Self {
+            symbol: symbol.into(),
+            side,
+            qty,
+            product_type,
+            target_price,
+            stop_price,
+            order_tag: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            target_id: None,
+            stop_id: None,
+        })
+    }
+
+    /// Tag both legs with `tag`.
+    pub fn order_tag(mut self, tag: impl Into<String>) -> Self {
+        self.order_tag = Some(tag.into());
+        self
+    }
+
+    /// How often [`run`](Oco::run) checks the order book.
+    ///
+    /// Defaults to one second.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// IDs of the target and stop orders, once placed.
+    pub fn order_ids(&self) -> Option<(&str, &str)> {
+        Some((self.target_id.as_deref()?, self.stop_id.as_deref()?))
+    }
+
+    /// Place both legs, if they haven't been placed yet.
+    ///
+    /// If the stop order fails, the target order is cancelled again.
+    pub async fn place<B: Broker>(&mut self, broker: &B) -> Result<(), FyersError> {
+        if self.order_ids().is_some() {
+            return Ok(());
+        }
+
+        let exit_side = match self.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+
+        let mut target = OrderRequest::limit(
+            &self.symbol,
+            self.qty,
+            exit_side,
+            self.product_type,
+            self.target_price,
+        );
+        let mut stop = OrderRequest::stop(
+            &self.symbol,
+            self.qty,
+            exit_side,
+            self.product_type,
+            self.stop_price,
+        );
+        if let Some(tag) = &self.order_tag {
+            target = target.order_tag(tag);
+            stop = stop.order_tag(tag);
+        }
+
+        let target_id = broker.place_order(&target.build()).await?.id;
+
+        let stop_id = match broker.place_order(&stop.build()).await {
+            Ok(placed) => placed.id,
+            Err(err) => {
+                // Don't leave half an OCO behind
+                let _ = broker.cancel_order(&target_id).await;
+                return Err(err);
+            }
+        };
+
+        self.target_id = Some(target_id);
+        self.stop_id = Some(stop_id);
+        Ok(())
+    }
+
+    /// Check the order book once.
+    ///
+    /// Resizes a leg after a partial fill of the other, and cancels what
+    /// is left once the position is closed. Returns the report when the
+    /// OCO is done.
+    pub async fn check<B: Broker>(&self, broker: &B) -> Result<Option<OcoReport>, FyersError> {
+        let (target_id, stop_id) = self
+            .order_ids()
+            .ok_or_else(|| FyersError::InvalidParams("oco hasn't been placed".into()))?;
+
+        let book = broker.orders().await?;
+        let target = find_order(&book, target_id)?;
+        let stop = find_order(&book, stop_id)?;
+
+        let filled = target.filled_qty + stop.filled_qty;
+        let remaining = self.qty.saturating_sub(filled);
+
+        let report = |outcome| OcoReport {
+            target_order_id: target_id.to_owned(),
+            stop_order_id: stop_id.to_owned(),
+            target_filled_qty: target.filled_qty,
+            stop_filled_qty: stop.filled_qty,
+            outcome,
+        };
+
+        if remaining == 0 {
+            for leg in [target, stop] {
+                if leg.status.is_open() {
+                    broker.cancel_order(&leg.id).await?;
+                }
+            }
+            return Ok(Some(report(OcoOutcome::Closed)));
+        }
+
+        for (leg, details) in [(OcoLeg::Target, target), (OcoLeg::Stop, stop)] {
+            if !details.status.is_open() {
+                let outcome = OcoOutcome::LegClosed {
+                    leg,
+                    status: details.status,
+                };
+                return Ok(Some(report(outcome)));
+            }
+        }
+
+        // Each leg may only close what the other hasn't
+        for details in [target, stop] {
+            let qty = details.filled_qty + remaining;
+            if details.qty != qty {
+                let modify = ModifyOrderRequest::builder(details.id.as_str())
+                    .qty(qty)
+                    .build();
+                broker.modify_order(&modify).await?;
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Place both legs and check the order book every
+    /// [`poll_interval`](Oco::poll_interval) until the OCO is done.
+    pub async fn run<B: Broker>(&mut self, broker: &B) -> Result<OcoReport, FyersError> {
+        self.place(broker).await?;
+
+        loop {
+            if let Some(report) = self.check(broker).await? {
+                return Ok(report);
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+fn find_order<'a>(book: &'a [OrderDetails], id: &str) -> Result<&'a OrderDetails, FyersError> {
+    book.iter()
+        .find(|o| o.id == id)
+        .ok_or(FyersError::InvalidOrderId)
+}
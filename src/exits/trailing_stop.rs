@@ -0,0 +1,497 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Broker, Fyers, FyersError, ModifyOrderRequest, OrderDetails, OrderRequest, OrderStatus,
+    ProductType, Side, utils,
+};
+
+// Order tags are at most 30 characters, and the stop and exit orders add
+// one to the tag of the trailing stop
+const MAX_TAG_LEN: usize = 29;
+
+/// How far a [`TrailingStop`] follows behind the best price.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trail {
+    /// A fixed distance in price points
+    Points(f64),
+    /// A percentage of the best price
+    Percent(f64),
+}
+
+/// Where a [`TrailingStop`] is in its life.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrailingStatus {
+    /// The stop order hasn't been placed yet
+    Pending,
+    /// The stop order is being placed, and may already be in the order
+    /// book
+    Placing,
+    /// The stop order is resting at the exchange
+    Active,
+    /// The exit order is being placed, and may already be in the order
+    /// book
+    Exiting,
+    /// The position was exited
+    Exited,
+}
+
+/// What a price update did to a [`TrailingStop`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrailingEvent {
+    /// The stop stayed where it was
+    Unchanged,
+    /// The stop order was moved to a new stop price
+    Moved {
+        /// The new stop price
+        stop_price: f64,
+    },
+    /// The stop triggered and the position was exited
+    Exited {
+        /// ID of the order that exited the position
+        order_id: String,
+    },
+}
+
+/// Everything a [`TrailingStop`] needs to carry on after a restart.
+///
+/// Saved as JSON by [`TrailingStop::persist`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrailingStopState {
+    /// Trading symbol
+    pub symbol: String,
+    /// Side of the position being protected
+    pub side: Side,
+    /// Quantity of the position
+    pub qty: u32,
+    /// Product type of the position
+    pub product_type: ProductType,
+    /// Distance of the stop from the best price
+    pub trail: Trail,
+    /// Tick size stop prices are rounded to, or zero for none
+    pub tick_size: f64,
+    /// Tag identifying the stop and exit orders
+    pub tag: String,
+    /// Best price seen so far: the highest for a long position and the
+    /// lowest for a short one
+    pub best_price: f64,
+    /// Current stop price
+    pub stop_price: f64,
+    /// ID of the resting stop order
+    pub stop_order_id: Option<String>,
+    /// ID of the order that exited the position
+    pub exit_order_id: Option<String>,
+    /// Current status
+    pub status: TrailingStatus,
+}
+
+/// A stop order that follows the price of an open position.
+///
+/// The stop starts [`trail`](Trail) away from the entry price, and is only
+/// ever moved in favour of the position: up for a long position and down
+/// for a short one. It is placed as a stop (SL-M) order on the first
+/// [`start`](TrailingStop::start) or [`update`](TrailingStop::update).
+///
+/// When a price reaches the stop, the stop order is looked up in the order
+/// book. If the exchange hasn't filled it, it is cancelled and a market
+/// order exits whatever is left of the position.
+///
+/// The state is saved before each order is sent. If the program stops
+/// while sending one, the order is found in the order book by its tag after a
+/// [restart](TrailingStop::resume) instead of being sent again.
+///
+/// See the [module documentation](super) for an example.
+#[derive(Debug, Clone)]
+pub struct TrailingStop {
+    state: TrailingStopState,
+    path: Option<PathBuf>,
+}
+
+impl TrailingStop {
+    /// Protect a position of `qty` on `side`, entered at `entry_price`.
+    pub fn new(
+        symbol: impl Into<String>,
+        side: Side,
+        qty: u32,
+        product_type: ProductType,
+        trail: Trail,
+        entry_price: f64,
+    ) -> Result<Self, FyersError> {
+        if qty == 0 {
+            return Err(FyersError::InvalidParams(
+                "trailing stop quantity must be positive".into(),
+            ));
+        }
+        if !entry_price.is_finite() || entry_price <= 0.0 {
+            return Err(FyersError::InvalidParams(
+                "trailing stop entry price must be positive".into(),
+            ));
+        }
+
+        let valid_trail = match trail {
+            Trail::Points(points) => points > 0.0 && points < entry_price,
+            Trail::Percent(percent) => percent > 0.0 && percent < 100.0,
+        };
+        if !valid_trail {
+            return Err(FyersError::InvalidParams(
+                "trailing stop distance must be positive and less than the price".into(),
+            ));
+        }
+
+        let mut stop = Self::from_state(TrailingStopState {
+            symbol: symbol.into(),
+            side,
+            qty,
+            product_type,
+            trail,
+            tick_size: 0.0,
+            // Unique enough to tell apart trailing stops on the same day
+            tag: format!("trail{:010}", (utils::random_f64() * 1e10) as u64),
+            best_price: entry_price,
+            stop_price: 0.0,
+            stop_order_id: None,
+            exit_order_id: None,
+            status: TrailingStatus::Pending,
+        });
+        stop.state.stop_price = stop.stop_for(entry_price);
+
+        Ok(stop)
+    }
+
+    /// Carry on from a saved state.
+    pub fn from_state(state: TrailingStopState) -> Self {
+        Self { state, path: None }
+    }
+
+    /// Load a trailing stop saved by [`persist`](TrailingStop::persist).
+    ///
+    /// Returns `None` if there is no file at `path`. The resumed stop keeps
+    /// saving to `path`.
+    pub fn resume(path: impl Into<PathBuf>) -> Result<Option<Self>, FyersError> {
+        let path = path.into();
+
+        let state = match fs::read(&path) {
+            Ok(contents) => serde_json::from_slice(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Some(Self::from_state(state).persist(path)))
+    }
+
+    /// Round stop prices to `tick_size`, away from the price.
+    pub fn tick_size(mut self, tick_size: f64) -> Self {
+        self.state.tick_size = tick_size;
+        if self.state.status == TrailingStatus::Pending {
+            self.state.stop_price = self.stop_for(self.state.best_price);
+        }
+        self
+    }
+
+    /// Tag the stop order `{tag}S` and the exit order `{tag}X`.
+    ///
+    /// Defaults to `trail` and a random number. The tag is how the orders
+    /// are found after a restart, so it must not be shared with other
+    /// orders placed the same day. It can be at most 29 letters or digits.
+    pub fn order_tag(mut self, tag: impl Into<String>) -> Self {
+        self.state.tag = tag.into();
+        self
+    }
+
+    /// Save the state to `path` as JSON whenever it changes.
+    ///
+    /// The file is synced to disk and replaced atomically, so a crash or
+    /// power loss never leaves a half-written state behind. On Unix it is
+    /// only readable by the current user.
+    pub fn persist(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// The current state.
+    pub fn state(&self) -> &TrailingStopState {
+        &self.state
+    }
+
+    /// Place the stop order, if it hasn't been placed yet.
+    pub async fn start<B: Broker>(&mut self, broker: &B) -> Result<(), FyersError> {
+        let tag = format!("{}S", self.state.tag);
+
+        match self.state.status {
+            TrailingStatus::Pending => {
+                check_tag(&self.state.tag)?;
+                self.state.status = TrailingStatus::Placing;
+                self.save()?;
+            }
+            TrailingStatus::Placing => {
+                // An earlier attempt may have got through before failing
+                if let Some(order) = find_tagged(broker, &tag).await? {
+                    return self.stop_placed(order.id);
+                }
+            }
+            _ => return Ok(()),
+        }
+
+        let order = OrderRequest::stop(
+            &self.state.symbol,
+            self.state.qty,
+            self.exit_side(),
+            self.state.product_type,
+            self.state.stop_price,
+        )
+        .order_tag(tag)
+        .build();
+
+        let placed = broker.place_order(&order).await?;
+        self.stop_placed(placed.id)
+    }
+
+    /// Feed a new price to the trailing stop.
+    ///
+    /// Places the stop order first if needed. Does nothing once the
+    /// position has been exited.
+    pub async fn update<B: Broker>(
+        &mut self,
+        broker: &B,
+        price: f64,
+    ) -> Result<TrailingEvent, FyersError> {
+        match self.state.status {
+            TrailingStatus::Exited => return Ok(TrailingEvent::Unchanged),
+            TrailingStatus::Pending | TrailingStatus::Placing => self.start(broker).await?,
+            TrailingStatus::Active => {}
+            // Finish an exit that was interrupted
+            TrailingStatus::Exiting => {
+                let order_id = self.exit(broker).await?;
+                return Ok(TrailingEvent::Exited { order_id });
+            }
+        }
+
+        let triggered = match self.state.side {
+            Side::Buy => price <= self.state.stop_price,
+            Side::Sell => price >= self.state.stop_price,
+        };
+        if triggered {
+            let order_id = self.exit(broker).await?;
+            return Ok(TrailingEvent::Exited { order_id });
+        }
+
+        let better = match self.state.side {
+            Side::Buy => price > self.state.best_price,
+            Side::Sell => price < self.state.best_price,
+        };
+        if !better {
+            return Ok(TrailingEvent::Unchanged);
+        }
+
+        self.state.best_price = price;
+
+        let stop_price = self.stop_for(price);
+        let moved = match self.state.side {
+            Side::Buy => stop_price > self.state.stop_price,
+            Side::Sell => stop_price < self.state.stop_price,
+        };
+        if !moved {
+            self.save()?;
+            return Ok(TrailingEvent::Unchanged);
+        }
+
+        let id = self.stop_order_id()?;
+        let modify = ModifyOrderRequest::builder(id.as_str())
+            .stop_price(stop_price)
+            .build();
+
+        if let Err(err) = broker.modify_order(&modify).await {
+            // The stop order may have been filled or cancelled since the
+            // last update, in which case the position needs exiting
+            if find_order(broker, &id).await?.status.is_open() {
+                return Err(err);
+            }
+
+            let order_id = self.exit(broker).await?;
+            return Ok(TrailingEvent::Exited { order_id });
+        }
+
+        self.state.stop_price = stop_price;
+        self.save()?;
+
+        Ok(TrailingEvent::Moved { stop_price })
+    }
+
+    /// Poll [`Fyers::quotes`] every `poll_interval` and feed the last
+    /// traded price to [`update`](TrailingStop::update) until the position
+    /// is exited.
+    ///
+    /// Returns the ID of the order that exited the position.
+    pub async fn run(
+        &mut self,
+        fyers: &Fyers,
+        poll_interval: Duration,
+    ) -> Result<String, FyersError> {
+        loop {
+            if let Some(id) = &self.state.exit_order_id {
+                return Ok(id.clone());
+            }
+
+            let quote = fyers
+                .quotes(&[&self.state.symbol])
+                .await?
+                .into_iter()
+                .next()
+                .ok_or(FyersError::InvalidSymbol)?;
+
+            if let TrailingEvent::Exited { order_id } = self.update(fyers, quote.ltp).await? {
+                return Ok(order_id);
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    // Make sure the position is closed once the stop has been reached.
+    // Returns the ID of the order that closed it.
+    async fn exit<B: Broker>(&mut self, broker: &B) -> Result<String, FyersError> {
+        let tag = format!("{}X", self.state.tag);
+
+        // An earlier attempt may have got through before failing
+        if self.state.status == TrailingStatus::Exiting
+            && let Some(order) = find_tagged(broker, &tag).await?
+        {
+            return self.exited(order.id);
+        }
+
+        let id = self.stop_order_id()?;
+        let mut stop = find_order(broker, &id).await?;
+
+        if stop.status.is_open() {
+            if let Err(err) = broker.cancel_order(&id).await {
+                // Filling between the lookup and the cancel is fine
+                if find_order(broker, &id).await?.status != OrderStatus::Filled {
+                    return Err(err);
+                }
+            }
+            stop = find_order(broker, &id).await?;
+        }
+
+        let remaining = self.state.qty.saturating_sub(stop.filled_qty);
+
+        if remaining == 0 {
+            return self.exited(id);
+        }
+
+        self.state.status = TrailingStatus::Exiting;
+        self.save()?;
+
+        let order = OrderRequest::market(
+            &self.state.symbol,
+            remaining,
+            self.exit_side(),
+            self.state.product_type,
+        )
+        .order_tag(tag)
+        .build();
+
+        let placed = broker.place_order(&order).await?;
+        self.exited(placed.id)
+    }
+
+    fn stop_placed(&mut self, id: String) -> Result<(), FyersError> {
+        self.state.stop_order_id = Some(id);
+        self.state.status = TrailingStatus::Active;
+        self.save()
+    }
+
+    fn exited(&mut self, id: String) -> Result<String, FyersError> {
+        self.state.exit_order_id = Some(id.clone());
+        self.state.status = TrailingStatus::Exited;
+        self.save()?;
+        Ok(id)
+    }
+
+    // The stop for a best price of `price`, rounded away from it
+    fn stop_for(&self, price: f64) -> f64 {
+        let distance = match self.state.trail {
+            Trail::Points(points) => points,
+            Trail::Percent(percent) => price * percent / 100.0,
+        };
+
+        let tick_size = self.state.tick_size;
+        let stop = match self.state.side {
+            Side::Buy => price - distance,
+            Side::Sell => price + distance,
+        };
+
+        if tick_size <= 0.0 {
+            return stop;
+        }
+
+        // Allow for floating point error before rounding to whole ticks
+        let ticks = stop / tick_size;
+        let ticks = match self.state.side {
+            Side::Buy => (ticks + 1e-9).floor(),
+            Side::Sell => (ticks - 1e-9).ceil(),
+        };
+
+        (ticks * tick_size * 10_000.0).round() / 10_000.0
+    }
+
+    fn exit_side(&self) -> Side {
+        match self.state.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        }
+    }
+
+    fn stop_order_id(&self) -> Result<String, FyersError> {
+        self.state
+            .stop_order_id
+            .clone()
+            .ok_or(FyersError::InvalidOrderId)
+    }
+
+    fn save(&self) -> Result<(), FyersError> {
+        match &self.path {
+            Some(path) => write_state(path, &self.state),
+            None => Ok(()),
+        }
+    }
+}
+
+fn write_state(path: &Path, state: &TrailingStopState) -> Result<(), FyersError> {
+    let contents = serde_json::to_vec_pretty(state)?;
+    utils::write_file_atomic(path, &contents)?;
+
+    Ok(())
+}
+
+fn check_tag(tag: &str) -> Result<(), FyersError> {
+    if tag.is_empty() || tag.len() > MAX_TAG_LEN || !tag.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(FyersError::InvalidParams(format!(
+            "trailing stop tag {tag:?} must be 1 to {MAX_TAG_LEN} letters or digits"
+        )));
+    }
+    Ok(())
+}
+
+async fn find_tagged<B: Broker>(broker: &B, tag: &str) -> Result<Option<OrderDetails>, FyersError> {
+    Ok(broker
+        .orders()
+        .await?
+        .into_iter()
+        .find(|o| o.tag() == Some(tag)))
+}
+
+async fn find_order<B: Broker>(broker: &B, id: &str) -> Result<OrderDetails, FyersError> {
+    broker
+        .orders()
+        .await?
+        .into_iter()
+        .find(|o| o.id == id)
+        .ok_or(FyersError::InvalidOrderId)
+}
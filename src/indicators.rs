@@ -0,0 +1,54 @@
+//! Technical indicators computed over [`Candle`] series.
+//!
+//! Every indicator comes in two flavours:
+//!
+//! - A **batch** function (e.g. [`sma`]) that takes a slice of candles and
+//!   returns one output per candle. Entries are `None` until the indicator
+//!   has seen enough data to produce a value (the warm-up period).
+//! - A **streaming** type (e.g. [`Sma`]) that is fed one value at a time
+//!   through `update`. This is what the batch functions use internally, so
+//!   both always agree.
+//!
+//! Price based indicators (SMA, EMA, RSI, MACD and Bollinger Bands) use the
+//! closing price in their batch form. The streaming variants accept any
+//! price, which makes them suitable for feeding ticks directly.
+//!
+//! # Example
+//!
+//! ```
+//! use fyers::indicators::{self, Ema};
+//! # let candles: Vec<fyers::Candle> = Vec::new();
+//!
+//! // Batch
+//! let ema = indicators::ema(&candles, 20);
+//! assert_eq!(ema.len(), candles.len());
+//!
+//! // Streaming
+//! let mut ema = Ema::new(3);
+//! for price in [10.0, 11.0, 12.0, 13.0] {
+//!     if let Some(value) = ema.update(price) {
+//!         println!("EMA: {value}");
+//!     }
+//! }
+//! ```
+
+mod atr;
+mod bollinger;
+mod ema;
+mod macd;
+mod rsi;
+mod sma;
+mod supertrend;
+mod vwap;
+
+pub use atr::{Atr, atr};
+pub use bollinger::{Bollinger, BollingerBands, bollinger};
+pub use ema::{Ema, ema};
+pub use macd::{Macd, MacdValue, macd};
+pub use rsi::{Rsi, rsi};
+pub use sma::{Sma, sma};
+pub use supertrend::{SuperTrend, SuperTrendValue, Trend, supertrend};
+pub use vwap::{Vwap, vwap};
+
+#[cfg(doc)]
+use crate::Candle;
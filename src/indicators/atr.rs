@@ -0,0 +1,73 @@
+use crate::Candle;
+
+/// Average True Range using Wilder's smoothing.
+///
+/// See [`Atr`] for the streaming variant.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn atr(candles: &[Candle], period: usize) -> Vec<Option<f64>> {
+    let mut atr = Atr::new(period);
+    candles.iter().map(|c| atr.update(c)).collect()
+}
+
+/// Streaming Average True Range.
+///
+/// The first value is the plain average of the first `period` true ranges.
+#[derive(Debug, Clone)]
+pub struct Atr {
+    period: usize,
+    prev_close: Option<f64>,
+    seen: usize,
+    current: f64,
+}
+
+impl Atr {
+    /// Create a new ATR with the given period (14 is the usual choice).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "ATR period must be greater than zero");
+
+        Self {
+            period,
+            prev_close: None,
+            seen: 0,
+            current: 0.0,
+        }
+    }
+
+    /// Feed the next candle.
+    pub fn update(&mut self, candle: &Candle) -> Option<f64> {
+        let true_range = match self.prev_close.replace(candle.close) {
+            Some(prev_close) => (candle.high - candle.low)
+                .max((candle.high - prev_close).abs())
+                .max((candle.low - prev_close).abs()),
+            None => candle.high - candle.low,
+        };
+
+        let period = self.period as f64;
+        self.seen += 1;
+
+        if self.seen <= self.period {
+            self.current += true_range / period;
+        } else {
+            self.current = (self.current * (period - 1.0) + true_range) / period;
+        }
+
+        self.value()
+    }
+
+    /// The current ATR, if the warm-up period is over.
+    pub fn value(&self) -> Option<f64> {
+        (self.seen >= self.period).then_some(self.current)
+    }
+
+    /// Clear all state.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.period);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::Candle;
+
+use super::Sma;
+
+/// Bollinger Bands of closing prices.
+///
+/// The usual parameters are `period = 20` and `multiplier = 2.0`.
+///
+/// See [`Bollinger`] for the streaming variant.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn bollinger(
+    candles: &[Candle],
+    period: usize,
+    multiplier: f64,
+) -> Vec<Option<BollingerBands>> {
+    let mut bollinger = Bollinger::new(period, multiplier);
+    candles.iter().map(|c| bollinger.update(c.close)).collect()
+}
+
+/// A single Bollinger Bands output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BollingerBands {
+    /// Middle band plus `multiplier` standard deviations.
+    pub upper: f64,
+    /// Simple moving average.
+    pub middle: f64,
+    /// Middle band minus `multiplier` standard deviations.
+    pub lower: f64,
+}
+
+/// Streaming Bollinger Bands.
+///
+/// Uses the population standard deviation of the window.
+#[derive(Debug, Clone)]
+pub struct Bollinger {
+    sma: Sma,
+    multiplier: f64,
+}
+
+impl Bollinger {
+    /// Create new Bollinger Bands.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: usize, multiplier: f64) -> Self {
+        Self {
+            sma: Sma::new(period),
+            multiplier,
+        }
+    }
+
+    /// Feed the next price.
+    pub fn update(&mut self, price: f64) -> Option<BollingerBands> {
+        self.sma.update(price);
+        self.value()
+    }
+
+    /// The current bands, if the warm-up period is over.
+    pub fn value(&self) -> Option<BollingerBands> {
+        let middle = self.sma.value()?;
+        let window = self.sma.window();
+
+        let variance =
+            window.iter().map(|v| (v - middle).powi(2)).sum::<f64>() / window.len() as f64;
+        let width = self.multiplier * variance.sqrt();
+
+        Some(BollingerBands {
+            upper: middle + width,
+            middle,
+            lower: middle - width,
+        })
+    }
+
+    /// Clear all state.
+    pub fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
@@ -0,0 +1,64 @@
+use crate::Candle;
+
+use super::Sma;
+
+/// Exponential moving average of closing prices.
+///
+/// See [`Ema`] for the streaming variant.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn ema(candles: &[Candle], period: usize) -> Vec<Option<f64>> {
+    let mut ema = Ema::new(period);
+    candles.iter().map(|c| ema.update(c.close)).collect()
+}
+
+/// Streaming exponential moving average.
+///
+/// Uses a smoothing factor of `2 / (period + 1)` and is seeded with the
+/// simple average of the first `period` values.
+#[derive(Debug, Clone)]
+pub struct Ema {
+    alpha: f64,
+    seed: Sma,
+    current: Option<f64>,
+}
+
+impl Ema {
+    /// Create a new EMA over the given number of values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: usize) -> Self {
+        Self {
+            alpha: 2.0 / (period as f64 + 1.0),
+            seed: Sma::new(period),
+            current: None,
+        }
+    }
+
+    /// Feed the next value.
+    ///
+    /// Returns the average once `period` values have been seen.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        self.current = match self.current {
+            Some(prev) => Some(prev + self.alpha * (value - prev)),
+            None => self.seed.update(value),
+        };
+
+        self.current
+    }
+
+    /// The current average, if the warm-up period is over.
+    pub fn value(&self) -> Option<f64> {
+        self.current
+    }
+
+    /// Clear all state.
+    pub fn reset(&mut self) {
+        self.seed.reset();
+        self.current = None;
+    }
+}
@@ -0,0 +1,86 @@
+use crate::Candle;
+
+use super::Ema;
+
+/// Moving Average Convergence Divergence of closing prices.
+///
+/// The usual parameters are `fast = 12`, `slow = 26` and `signal = 9`.
+///
+/// See [`Macd`] for the streaming variant.
+///
+/// # Panics
+///
+/// Panics if any period is zero.
+pub fn macd(candles: &[Candle], fast: usize, slow: usize, signal: usize) -> Vec<Option<MacdValue>> {
+    let mut macd = Macd::new(fast, slow, signal);
+    candles.iter().map(|c| macd.update(c.close)).collect()
+}
+
+/// A single MACD output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MacdValue {
+    /// Fast EMA minus slow EMA.
+    pub macd: f64,
+    /// EMA of the MACD line.
+    pub signal: f64,
+    /// MACD line minus signal line.
+    pub histogram: f64,
+}
+
+/// Streaming MACD.
+///
+/// A value is produced once the signal line is available, which takes
+/// `slow + signal - 1` prices.
+#[derive(Debug, Clone)]
+pub struct Macd {
+    fast: Ema,
+    slow: Ema,
+    signal: Ema,
+    current: Option<MacdValue>,
+}
+
+impl Macd {
+    /// Create a new MACD.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any period is zero.
+    pub fn new(fast: usize, slow: usize, signal: usize) -> Self {
+        Self {
+            fast: Ema::new(fast),
+            slow: Ema::new(slow),
+            signal: Ema::new(signal),
+            current: None,
+        }
+    }
+
+    /// Feed the next price.
+    pub fn update(&mut self, price: f64) -> Option<MacdValue> {
+        let fast = self.fast.update(price);
+        let slow = self.slow.update(price);
+
+        if let (Some(fast), Some(slow)) = (fast, slow) {
+            let macd = fast - slow;
+            self.current = self.signal.update(macd).map(|signal| MacdValue {
+                macd,
+                signal,
+                histogram: macd - signal,
+            });
+        }
+
+        self.current
+    }
+
+    /// The current MACD value, if the warm-up period is over.
+    pub fn value(&self) -> Option<MacdValue> {
+        self.current
+    }
+
+    /// Clear all state.
+    pub fn reset(&mut self) {
+        self.fast.reset();
+        self.slow.reset();
+        self.signal.reset();
+        self.current = None;
+    }
+}
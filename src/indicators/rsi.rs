@@ -0,0 +1,89 @@
+use crate::Candle;
+
+/// Relative Strength Index of closing prices using Wilder's smoothing.
+///
+/// See [`Rsi`] for the streaming variant.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn rsi(candles: &[Candle], period: usize) -> Vec<Option<f64>> {
+    let mut rsi = Rsi::new(period);
+    candles.iter().map(|c| rsi.update(c.close)).collect()
+}
+
+/// Streaming Relative Strength Index.
+///
+/// The first value is produced after `period + 1` prices, i.e. once
+/// `period` price changes are available.
+#[derive(Debug, Clone)]
+pub struct Rsi {
+    period: usize,
+    prev: Option<f64>,
+    seen: usize,
+    avg_gain: f64,
+    avg_loss: f64,
+}
+
+impl Rsi {
+    /// Create a new RSI with the given period (14 is the usual choice).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "RSI period must be greater than zero");
+
+        Self {
+            period,
+            prev: None,
+            seen: 0,
+            avg_gain: 0.0,
+            avg_loss: 0.0,
+        }
+    }
+
+    /// Feed the next price.
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        let prev = self.prev.replace(price)?;
+
+        let change = price - prev;
+        let gain = change.max(0.0);
+        let loss = (-change).max(0.0);
+        let period = self.period as f64;
+
+        self.seen += 1;
+
+        if self.seen <= self.period {
+            // Plain average over the first `period` changes
+            self.avg_gain += gain / period;
+            self.avg_loss += loss / period;
+        } else {
+            self.avg_gain = (self.avg_gain * (period - 1.0) + gain) / period;
+            self.avg_loss = (self.avg_loss * (period - 1.0) + loss) / period;
+        }
+
+        self.value()
+    }
+
+    /// The current RSI (0 to 100), if the warm-up period is over.
+    pub fn value(&self) -> Option<f64> {
+        if self.seen < self.period {
+            return None;
+        }
+
+        let value = if self.avg_loss == 0.0 {
+            if self.avg_gain == 0.0 { 50.0 } else { 100.0 }
+        } else {
+            let rs = self.avg_gain / self.avg_loss;
+            100.0 - 100.0 / (1.0 + rs)
+        };
+
+        Some(value)
+    }
+
+    /// Clear all state.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.period);
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::VecDeque;
+
+use crate::Candle;
+
+/// Simple moving average of closing prices.
+///
+/// See [`Sma`] for the streaming variant.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn sma(candles: &[Candle], period: usize) -> Vec<Option<f64>> {
+    let mut sma = Sma::new(period);
+    candles.iter().map(|c| sma.update(c.close)).collect()
+}
+
+/// Streaming simple moving average.
+#[derive(Debug, Clone)]
+pub struct Sma {
+    period: usize,
+    window: VecDeque<f64>,
+    sum: f64,
+}
+
+impl Sma {
+    /// Create a new SMA over the given number of values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "SMA period must be greater than zero");
+
+        Self {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+            sum: 0.0,
+        }
+    }
+
+    /// Feed the next value.
+    ///
+    /// Returns the average once `period` values have been seen.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        self.window.push_back(value);
+        self.sum += value;
+
+        if self.window.len() > self.period
+            && let Some(old) = self.window.pop_front()
+        {
+            self.sum -= old;
+        }
+
+        self.value()
+    }
+
+    /// The current average, if the warm-up period is over.
+    pub fn value(&self) -> Option<f64> {
+        (self.window.len() == self.period).then(|| self.sum / self.period as f64)
+    }
+
+    /// The values currently inside the window, oldest first.
+    pub(crate) fn window(&self) -> &VecDeque<f64> {
+        &self.window
+    }
+
+    /// Clear all state.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+    }
+}
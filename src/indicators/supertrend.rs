@@ -0,0 +1,128 @@
+use crate::Candle;
+
+use super::Atr;
+
+/// SuperTrend indicator.
+///
+/// The usual parameters are `period = 10` and `multiplier = 3.0`.
+///
+/// See [`SuperTrend`] for the streaming variant.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn supertrend(
+    candles: &[Candle],
+    period: usize,
+    multiplier: f64,
+) -> Vec<Option<SuperTrendValue>> {
+    let mut supertrend = SuperTrend::new(period, multiplier);
+    candles.iter().map(|c| supertrend.update(c)).collect()
+}
+
+/// Direction of the trend reported by [`SuperTrend`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trend {
+    /// Price is above the SuperTrend line.
+    Up,
+    /// Price is below the SuperTrend line.
+    Down,
+}
+
+/// A single SuperTrend output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuperTrendValue {
+    /// The SuperTrend line (the lower band in an uptrend, the upper band in
+    /// a downtrend).
+    pub value: f64,
+    /// Current trend direction.
+    pub trend: Trend,
+}
+
+/// Streaming SuperTrend.
+#[derive(Debug, Clone)]
+pub struct SuperTrend {
+    atr: Atr,
+    multiplier: f64,
+    prev_close: Option<f64>,
+    upper: f64,
+    lower: f64,
+    current: Option<SuperTrendValue>,
+}
+
+impl SuperTrend {
+    /// Create a new SuperTrend.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: usize, multiplier: f64) -> Self {
+        Self {
+            atr: Atr::new(period),
+            multiplier,
+            prev_close: None,
+            upper: 0.0,
+            lower: 0.0,
+            current: None,
+        }
+    }
+
+    /// Feed the next candle.
+    pub fn update(&mut self, candle: &Candle) -> Option<SuperTrendValue> {
+        let atr = self.atr.update(candle);
+        let prev_close = self.prev_close.replace(candle.close);
+        let atr = atr?;
+
+        let mid = (candle.high + candle.low) / 2.0;
+        let basic_upper = mid + self.multiplier * atr;
+        let basic_lower = mid - self.multiplier * atr;
+
+        let trend = match (self.current, prev_close) {
+            (Some(prev), Some(prev_close)) => {
+                // Bands only tighten while price stays inside them
+                if basic_upper < self.upper || prev_close > self.upper {
+                    self.upper = basic_upper;
+                }
+                if basic_lower > self.lower || prev_close < self.lower {
+                    self.lower = basic_lower;
+                }
+
+                match prev.trend {
+                    Trend::Down if candle.close > self.upper => Trend::Up,
+                    Trend::Up if candle.close < self.lower => Trend::Down,
+                    trend => trend,
+                }
+            }
+            _ => {
+                self.upper = basic_upper;
+                self.lower = basic_lower;
+
+                if candle.close > mid {
+                    Trend::Up
+                } else {
+                    Trend::Down
+                }
+            }
+        };
+
+        let value = match trend {
+            Trend::Up => self.lower,
+            Trend::Down => self.upper,
+        };
+
+        self.current = Some(SuperTrendValue { value, trend });
+        self.current
+    }
+
+    /// The current SuperTrend value, if the warm-up period is over.
+    pub fn value(&self) -> Option<SuperTrendValue> {
+        self.current
+    }
+
+    /// Clear all state.
+    pub fn reset(&mut self) {
+        self.atr.reset();
+        self.prev_close = None;
+        self.current = None;
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::Candle;
+use crate::datetime::IST;
+
+/// Volume Weighted Average Price, reset at the start of every IST session.
+///
+/// Each candle contributes its typical price `(high + low + close) / 3`.
+///
+/// See [`Vwap`] for the streaming variant.
+pub fn vwap(candles: &[Candle]) -> Vec<Option<f64>> {
+    let mut vwap = Vwap::new();
+    candles.iter().map(|c| vwap.update(c)).collect()
+}
+
+/// Streaming Volume Weighted Average Price.
+///
+/// The running totals are cleared whenever a candle or tick falls on a new
+/// trading date **in IST**. Candle timestamps are in UTC, so grouping them
+/// by their UTC date would incorrectly split or merge Indian sessions.
+///
+/// Returns `None` until some volume has been traded in the current session.
+#[derive(Debug, Clone, Default)]
+pub struct Vwap {
+    session: Option<NaiveDate>,
+    price_volume: f64,
+    volume: f64,
+}
+
+impl Vwap {
+    /// Create a new VWAP.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next candle.
+    pub fn update(&mut self, candle: &Candle) -> Option<f64> {
+        let typical = (candle.high + candle.low + candle.close) / 3.0;
+        self.update_tick(candle.time, typical, candle.volume)
+    }
+
+    /// Feed a single trade (tick).
+    pub fn update_tick(&mut self, time: DateTime<Utc>, price: f64, volume: u64) -> Option<f64> {
+        let session = time.with_timezone(&IST).date_naive();
+
+        if self.session != Some(session) {
+            self.reset();
+            self.session = Some(session);
+        }
+
+        self.price_volume += price * volume as f64;
+        self.volume += volume as f64;
+
+        self.value()
+    }
+
+    /// The VWAP of the current session.
+    pub fn value(&self) -> Option<f64> {
+        (self.volume > 0.0).then(|| self.price_volume / self.volume)
+    }
+
+    /// The IST date of the current session.
+    pub fn session(&self) -> Option<NaiveDate> {
+        self.session
+    }
+
+    /// Clear all state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -27,28 +27,54 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+pub mod amo;
 pub mod auth;
+pub mod backtest;
+pub mod charges;
 pub mod client;
+pub mod execution;
+pub mod exits;
+pub mod indicators;
+pub mod paper;
+pub mod slicing;
+#[cfg(feature = "testing")]
+pub mod testing;
 
+mod broker;
 mod datetime;
 mod endpoints;
 mod error;
 mod models;
+mod order_handle;
+mod rate_limit;
+mod retry;
 mod utils;
 
-pub use client::Fyers;
+pub use broker::Broker;
+pub use client::{Fyers, FyersBuilder};
+pub use endpoints::Endpoint;
 pub use error::FyersError;
+pub use order_handle::OrderHandle;
+pub use rate_limit::{RateLimitPolicy, RateLimiter, RateLimiterBuilder, RateLimits};
+pub use retry::{RetryConfig, RetryPolicy};
 
 // Profile
 pub use models::Profile;
 
+// Quotes
+pub use models::Quote;
+
 // History
 pub use models::{Candle, CandleResolution, HistoryBuilder, HistoryRequest};
 
 // Orders
-pub use models::{Order, OrderBuilder, OrderRequest, OrderType, ProductType, Side, Validity};
+pub use models::{
+    Entry, InstrumentInfo, ModifyOrderBuilder, ModifyOrderRequest, Order, OrderBuilder,
+    OrderDetails, OrderGroup, OrderRequest, OrderStatus, OrderType, OrderValidationError,
+    OrderViolation, ProductType, Side, SymbolMaster, TypedOrderBuilder, Validity, order_kind,
+};
 
 // Positions
-pub use models::ExitPositionResult;
+pub use models::{ExitPositionResult, Position};
 
 pub use datetime::ist_datetime;
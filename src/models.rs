@@ -3,17 +3,25 @@ pub mod history;
 pub mod orders;
 pub mod positions;
 pub mod profile;
+pub mod quotes;
 
 pub use orders::{
-    order::Order, order_type::OrderType, place_order_request::OrderBuilder,
-    place_order_request::OrderRequest, product_type::ProductType, side::Side, validity::Validity,
+    instrument_info::InstrumentInfo, modify_order_request::ModifyOrderBuilder,
+    modify_order_request::ModifyOrderRequest, order::Order, order_details::OrderDetails,
+    order_group::OrderGroup, order_status::OrderStatus, order_type::OrderType,
+    order_validation::OrderValidationError, order_validation::OrderViolation,
+    place_order_request::OrderBuilder, place_order_request::OrderRequest,
+    product_type::ProductType, side::Side, symbol_master::SymbolMaster, typed_order::Entry,
+    typed_order::TypedOrderBuilder, typed_order::kind as order_kind, validity::Validity,
 };
 
 pub use profile::Profile;
 
+pub use quotes::quote::Quote;
+
 pub use history::{
     candle_resolution::CandleResolution, history_request::HistoryBuilder,
     history_request::HistoryRequest, history_response::Candle,
 };
 
-pub use positions::exit_position_result::ExitPositionResult;
+pub use positions::{exit_position_result::ExitPositionResult, position::Position};
@@ -38,7 +38,7 @@ pub struct HistoryBuilder {
 /// rather than instantiated directly.
 #[derive(Serialize)]
 pub struct HistoryRequest {
-    symbol: String,
+    pub(crate) symbol: String,
     resolution: CandleResolution,
     date_format: &'static str,
     range_from: String,
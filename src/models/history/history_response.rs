@@ -11,6 +11,8 @@ use serde::Deserialize;
 /// * The Fyers API returns candles as an array of numeric values.
 ///   This struct provides a strongly-typed representation.
 /// * `open_interest` will be `None` unless the history request enables OI.
+/// * Candles serialize back into the same array form, so they can be
+///   cached to a file and read back.
 ///
 /// # Example
 ///
@@ -80,6 +82,30 @@ impl<'de> serde::Deserialize<'de> for Candle {
     }
 }
 
+// Serialized in the same array form Fyers uses, so saved candles can be
+// read back with `Deserialize`
+impl serde::Serialize for Candle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let len = if self.open_interest.is_some() { 7 } else { 6 };
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        seq.serialize_element(&self.time.timestamp())?;
+        seq.serialize_element(&self.open)?;
+        seq.serialize_element(&self.high)?;
+        seq.serialize_element(&self.low)?;
+        seq.serialize_element(&self.close)?;
+        seq.serialize_element(&self.volume)?;
+        if let Some(oi) = self.open_interest {
+            seq.serialize_element(&oi)?;
+        }
+        seq.end()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct HistoryResponse {
     pub(crate) candles: Vec<Candle>,
@@ -1,6 +1,14 @@
+pub mod instrument_info;
+pub mod modify_order_request;
 pub mod order;
+pub mod order_details;
+pub mod order_group;
+pub mod order_status;
 pub mod order_type;
+pub mod order_validation;
 pub mod place_order_request;
 pub mod product_type;
 pub mod side;
+pub mod symbol_master;
+pub mod typed_order;
 pub mod validity;
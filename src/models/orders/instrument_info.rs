@@ -0,0 +1,32 @@
+/// Trading rules of an instrument, used to validate orders before they
+/// are sent.
+///
+/// Fyers publishes these in its symbol master files, which can be loaded
+/// with [`SymbolMaster`](crate::SymbolMaster).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct InstrumentInfo {
+    /// Quantities must be a multiple of this
+    pub lot_size: u32,
+    /// Prices must be a multiple of this
+    pub tick_size: f64,
+    /// Orders for this quantity or more are rejected by the exchange
+    pub freeze_qty: Option<u32>,
+}
+
+impl InstrumentInfo {
+    /// Create instrument info with the given lot and tick size.
+    pub fn new(lot_size: u32, tick_size: f64) -> Self {
+        Self {
+            lot_size,
+            tick_size,
+            freeze_qty: None,
+        }
+    }
+
+    /// Set the exchange freeze quantity.
+    pub fn with_freeze_qty(mut self, freeze_qty: u32) -> Self {
+        self.freeze_qty = Some(freeze_qty);
+        self
+    }
+}
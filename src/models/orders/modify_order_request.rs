@@ -0,0 +1,111 @@
+use serde::Serialize;
+
+use crate::OrderType;
+
+#[cfg(doc)]
+use crate::Fyers;
+
+/// The request type sent to the Fyers modify order API.
+///
+/// This type is typically constructed using [`ModifyOrderRequest::builder`]
+/// rather than instantiated directly. Only the fields that are set are
+/// changed.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyOrderRequest {
+    pub(crate) id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) qty: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) r#type: Option<OrderType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) limit_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stop_price: Option<f64>,
+}
+
+/// Builder for creating a [`ModifyOrderRequest`] used with
+/// [`Fyers::modify_order`].
+///
+/// Only pending orders can be modified.
+///
+/// # Example
+///
+/// ```
+/// use fyers::ModifyOrderRequest;
+///
+/// let modify = ModifyOrderRequest::builder("808058117761")
+///     .qty(5)
+///     .limit_price(580.5)
+///     .build();
+/// ```
+#[must_use = "builders must be finalized with .build()"]
+#[derive(Debug)]
+pub struct ModifyOrderBuilder {
+    id: String,
+    qty: Option<u32>,
+    r#type: Option<OrderType>,
+    limit_price: Option<f64>,
+    stop_price: Option<f64>,
+}
+
+impl ModifyOrderBuilder {
+    /// Create a new builder for the order with the given ID.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            qty: None,
+            r#type: None,
+            limit_price: None,
+            stop_price: None,
+        }
+    }
+
+    /// Change the quantity.
+    pub fn qty(mut self, qty: u32) -> Self {
+        self.qty = Some(qty);
+        self
+    }
+
+    /// Change the order type.
+    pub fn order_type(mut self, order_type: OrderType) -> Self {
+        self.r#type = Some(order_type);
+        self
+    }
+
+    /// Change the limit price.
+    pub fn limit_price(mut self, price: f64) -> Self {
+        self.limit_price = Some(price);
+        self
+    }
+
+    /// Change the stop (trigger) price.
+    pub fn stop_price(mut self, price: f64) -> Self {
+        self.stop_price = Some(price);
+        self
+    }
+
+    /// Return a [`ModifyOrderRequest`] with the desired changes.
+    pub fn build(self) -> ModifyOrderRequest {
+        ModifyOrderRequest {
+            id: self.id,
+            qty: self.qty,
+            r#type: self.r#type,
+            limit_price: self.limit_price,
+            stop_price: self.stop_price,
+        }
+    }
+}
+
+impl ModifyOrderRequest {
+    /// Creates a [`ModifyOrderBuilder`] to construct a [`ModifyOrderRequest`].
+    /// This is the same as [`ModifyOrderBuilder::new()`].
+    pub fn builder(id: impl Into<String>) -> ModifyOrderBuilder {
+        ModifyOrderBuilder::new(id)
+    }
+
+    /// ID of the order to modify.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
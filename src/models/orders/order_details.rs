@@ -0,0 +1,117 @@
+use serde::Deserialize;
+
+use crate::{OrderStatus, OrderType, ProductType, Side, Validity};
+
+/// A single order from the order book.
+///
+/// Returned by [`Fyers::orders`](crate::Fyers::orders).
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderDetails {
+    /// Order ID
+    pub id: String,
+
+    /// Order ID assigned by the exchange, once the order reaches it.
+    #[serde(default)]
+    pub exch_ord_id: Option<String>,
+
+    /// Trading symbol (e.g. `NSE:SBIN-EQ`)
+    pub symbol: String,
+
+    /// Total quantity of the order
+    pub qty: u32,
+
+    /// Quantity yet to be filled
+    #[serde(default)]
+    pub remaining_quantity: u32,
+
+    /// Quantity filled so far
+    #[serde(default)]
+    pub filled_qty: u32,
+
+    /// Disclosed quantity
+    #[serde(default)]
+    pub disclosed_qty: u32,
+
+    /// Limit price (`0.0` for market orders)
+    #[serde(default)]
+    pub limit_price: f64,
+
+    /// Stop (trigger) price (`0.0` if not applicable)
+    #[serde(default)]
+    pub stop_price: f64,
+
+    /// Average traded price
+    #[serde(default)]
+    pub traded_price: f64,
+
+    /// Order type
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Buy or Sell
+    pub side: Side,
+
+    /// Product type
+    pub product_type: ProductType,
+
+    /// Order validity
+    #[serde(rename = "orderValidity")]
+    pub validity: Validity,
+
+    /// Current status of the order
+    pub status: OrderStatus,
+
+    /// Whether the order is an AMO (After Market Order)
+    #[serde(default)]
+    pub offline_order: bool,
+
+    /// Time the order was placed, as reported by Fyers (IST)
+    #[serde(default)]
+    pub order_date_time: Option<String>,
+
+    /// Message from the exchange or Fyers (e.g. the rejection reason)
+    #[serde(default)]
+    pub message: Option<String>,
+
+    /// The raw order tag as returned by Fyers.
+    ///
+    /// Fyers prefixes the tag given when placing the order (e.g.
+    /// `1:mytag`). Use [`OrderDetails::tag`] to get the original tag.
+    #[serde(default)]
+    pub order_tag: Option<String>,
+
+    /// ID of the entry order, for the stop loss and target legs of
+    /// bracket and cover orders.
+    ///
+    /// See [`OrderGroup`](crate::OrderGroup) for grouping the legs.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub parent_id: Option<String>,
+}
+
+impl OrderDetails {
+    /// The order tag as it was set when placing the order.
+    pub fn tag(&self) -> Option<&str> {
+        let raw = self.order_tag.as_deref()?;
+
+        match raw.split_once(':') {
+            Some((prefix, tag)) if prefix.chars().all(|c| c.is_ascii_digit()) => Some(tag),
+            _ => Some(raw),
+        }
+    }
+}
+
+// Fyers sends an empty parentId for orders without a parent
+fn empty_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let id = Option::<String>::deserialize(deserializer)?;
+    Ok(id.filter(|id| !id.is_empty()))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct OrderBookResponse {
+    #[serde(rename = "orderBook", default)]
+    pub(crate) order_book: Vec<OrderDetails>,
+}
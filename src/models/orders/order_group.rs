@@ -0,0 +1,75 @@
+use crate::{OrderDetails, OrderType, ProductType};
+
+/// A bracket or cover order with its legs.
+///
+/// Fyers lists the entry and each leg as separate rows in the order book.
+/// Once the entry fills, the stop loss leg (and the target leg of a
+/// bracket order) appear with their
+/// [`parent_id`](OrderDetails::parent_id) set to the entry's ID.
+///
+/// Returned by [`Fyers::order_groups`](crate::Fyers::order_groups), or
+/// built from an order book with [`OrderGroup::from_orders`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderGroup {
+    /// The entry order
+    pub entry: OrderDetails,
+
+    /// The stop loss leg, once the entry has filled
+    pub stop_loss: Option<OrderDetails>,
+
+    /// The target leg of a bracket order, once the entry has filled
+    pub target: Option<OrderDetails>,
+}
+
+impl OrderGroup {
+    /// Group the bracket and cover orders in an order book with their
+    /// legs.
+    ///
+    /// Other orders are left out, as are legs whose entry isn't in
+    /// `orders`.
+    pub fn from_orders(orders: &[OrderDetails]) -> Vec<OrderGroup> {
+        let mut groups: Vec<OrderGroup> = orders
+            .iter()
+            .filter(|o| o.parent_id.is_none())
+            .filter(|o| matches!(o.product_type, ProductType::Bo | ProductType::Co))
+            .map(|entry| OrderGroup {
+                entry: entry.clone(),
+                stop_loss: None,
+                target: None,
+            })
+            .collect();
+
+        for leg in orders {
+            let Some(parent_id) = &leg.parent_id else {
+                continue;
+            };
+
+            let Some(group) = groups.iter_mut().find(|g| &g.entry.id == parent_id) else {
+                continue;
+            };
+
+            // The stop loss leg is a stop order and the target a limit order
+            match leg.order_type {
+                OrderType::Stop | OrderType::StopLimit => group.stop_loss = Some(leg.clone()),
+                OrderType::Limit | OrderType::Market => group.target = Some(leg.clone()),
+            }
+        }
+
+        groups
+    }
+
+    /// [`ProductType::Bo`] or [`ProductType::Co`].
+    pub fn product_type(&self) -> ProductType {
+        self.entry.product_type
+    }
+
+    /// The legs placed so far.
+    pub fn legs(&self) -> impl Iterator<Item = &OrderDetails> {
+        self.stop_loss.iter().chain(self.target.iter())
+    }
+
+    /// Whether the entry or any leg is still working.
+    pub fn is_open(&self) -> bool {
+        self.entry.status.is_open() || self.legs().any(|leg| leg.status.is_open())
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Deserializer};
+
+/// Status of an order in the order book.
+///
+/// This maps to the numeric status codes returned by Fyers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrderStatus {
+    /// Cancelled -> 1
+    Cancelled,
+
+    /// Traded / Filled -> 2
+    Filled,
+
+    /// Transit -> 4
+    ///
+    /// The order is on its way to the exchange.
+    Transit,
+
+    /// Rejected -> 5
+    Rejected,
+
+    /// Pending -> 6
+    ///
+    /// The order is open at the exchange and may be partially filled.
+    Pending,
+
+    /// Expired -> 7
+    Expired,
+
+    /// A status code not known to this crate.
+    Unknown(i32),
+}
+
+impl OrderStatus {
+    /// Whether the order can no longer change.
+    ///
+    /// Terminal orders are filled, cancelled, rejected or expired.
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Cancelled
+                | OrderStatus::Filled
+                | OrderStatus::Rejected
+                | OrderStatus::Expired
+        )
+    }
+
+    /// Whether the order is still working (pending or in transit).
+    pub fn is_open(self) -> bool {
+        matches!(self, OrderStatus::Pending | OrderStatus::Transit)
+    }
+}
+
+impl From<i32> for OrderStatus {
+    fn from(code: i32) -> Self {
+        match code {
+            1 => OrderStatus::Cancelled,
+            2 => OrderStatus::Filled,
+            4 => OrderStatus::Transit,
+            5 => OrderStatus::Rejected,
+            6 => OrderStatus::Pending,
+            7 => OrderStatus::Expired,
+            code => OrderStatus::Unknown(code),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(i32::deserialize(deserializer)?.into())
+    }
+}
@@ -0,0 +1,231 @@
+use thiserror::Error;
+
+use crate::{InstrumentInfo, OrderRequest, OrderType, ProductType, Side};
+
+#[cfg(doc)]
+use crate::OrderBuilder;
+
+// Fyers accepts alphanumeric order tags of up to 30 characters
+const MAX_TAG_LEN: usize = 30;
+
+/// A rule broken by an order.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum OrderViolation {
+    /// The quantity is zero.
+    #[error("qty must be positive")]
+    ZeroQty,
+
+    /// A limit or stop-limit order without a limit price.
+    #[error("{0:?} orders need a limit_price")]
+    MissingLimitPrice(OrderType),
+
+    /// A stop or stop-limit order without a stop price.
+    #[error("{0:?} orders need a stop_price")]
+    MissingStopPrice(OrderType),
+
+    /// A stop-limit order whose limit is on the wrong side of the stop,
+    /// so it can never fill once triggered.
+    #[error("limit_price {limit_price} is on the wrong side of stop_price {stop_price}")]
+    StopLimitInverted {
+        /// Stop price of the order
+        stop_price: f64,
+        /// Limit price of the order
+        limit_price: f64,
+    },
+
+    /// A bracket or cover order without a stop loss.
+    #[error("{0:?} orders need a stop_loss")]
+    MissingStopLoss(ProductType),
+
+    /// A bracket order without a take profit.
+    #[error("BO orders need a take_profit")]
+    MissingTakeProfit,
+
+    /// The disclosed quantity is larger than the quantity.
+    #[error("disclosed_qty {disclosed_qty} is larger than qty {qty}")]
+    DisclosedQtyTooLarge {
+        /// Disclosed quantity
+        disclosed_qty: u32,
+        /// Order quantity
+        qty: u32,
+    },
+
+    /// The order tag is empty, too long or not alphanumeric.
+    #[error("order_tag {0:?} must be 1 to 30 letters or digits")]
+    InvalidOrderTag(String),
+
+    /// The quantity isn't a multiple of the lot size.
+    #[error("qty {qty} is not a multiple of the lot size {lot_size}")]
+    LotSize {
+        /// Order quantity
+        qty: u32,
+        /// Lot size of the instrument
+        lot_size: u32,
+    },
+
+    /// The quantity is at or above the exchange freeze quantity, and the
+    /// order isn't a slice order.
+    #[error("qty {qty} is not below the freeze quantity {freeze_qty}")]
+    FreezeQty {
+        /// Order quantity
+        qty: u32,
+        /// Freeze quantity of the instrument
+        freeze_qty: u32,
+    },
+
+    /// A price isn't a multiple of the tick size.
+    #[error("{field} {price} is not a multiple of the tick size {tick_size}")]
+    TickSize {
+        /// Name of the price field
+        field: &'static str,
+        /// The misaligned price
+        price: f64,
+        /// Tick size of the instrument
+        tick_size: f64,
+    },
+}
+
+/// An order failed validation.
+///
+/// Returned by [`OrderBuilder::try_build`] with every rule the order
+/// breaks.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("invalid order: {}", display_violations(.violations))]
+pub struct OrderValidationError {
+    violations: Vec<OrderViolation>,
+}
+
+impl OrderValidationError {
+    /// The rules broken by the order.
+    pub fn violations(&self) -> &[OrderViolation] {
+        &self.violations
+    }
+}
+
+fn display_violations(violations: &[OrderViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+// Check an order against the Fyers order rules and, if given, the
+// instrument's lot and tick size
+pub(crate) fn validate(
+    order: &OrderRequest,
+    instrument: Option<&InstrumentInfo>,
+) -> Result<(), OrderValidationError> {
+    let mut violations = Vec::new();
+    let order_type = order.r#type;
+
+    if order.qty == 0 {
+        violations.push(OrderViolation::ZeroQty);
+    }
+
+    let limit = matches!(order_type, OrderType::Limit | OrderType::StopLimit);
+    let stop = matches!(order_type, OrderType::Stop | OrderType::StopLimit);
+
+    if limit && order.limit_price <= 0.0 {
+        violations.push(OrderViolation::MissingLimitPrice(order_type));
+    }
+
+    if stop && order.stop_price <= 0.0 {
+        violations.push(OrderViolation::MissingStopPrice(order_type));
+    }
+
+    if order_type == OrderType::StopLimit && order.limit_price > 0.0 && order.stop_price > 0.0 {
+        let inverted = match order.side {
+            Side::Buy => order.limit_price < order.stop_price,
+            Side::Sell => order.limit_price > order.stop_price,
+        };
+
+        if inverted {
+            violations.push(OrderViolation::StopLimitInverted {
+                stop_price: order.stop_price,
+                limit_price: order.limit_price,
+            });
+        }
+    }
+
+    if matches!(order.product_type, ProductType::Bo | ProductType::Co) && order.stop_loss <= 0.0 {
+        violations.push(OrderViolation::MissingStopLoss(order.product_type));
+    }
+
+    if order.product_type == ProductType::Bo && order.take_profit <= 0.0 {
+        violations.push(OrderViolation::MissingTakeProfit);
+    }
+
+    if order.disclosed_qty > order.qty {
+        violations.push(OrderViolation::DisclosedQtyTooLarge {
+            disclosed_qty: order.disclosed_qty,
+            qty: order.qty,
+        });
+    }
+
+    if let Some(tag) = &order.order_tag {
+        let valid = !tag.is_empty()
+            && tag.len() <= MAX_TAG_LEN
+            && tag.chars().all(|c| c.is_ascii_alphanumeric());
+
+        if !valid {
+            violations.push(OrderViolation::InvalidOrderTag(tag.clone()));
+        }
+    }
+
+    if let Some(instrument) = instrument {
+        let lot_size = instrument.lot_size;
+        if lot_size > 1 && !order.qty.is_multiple_of(lot_size) {
+            violations.push(OrderViolation::LotSize {
+                qty: order.qty,
+                lot_size,
+            });
+        }
+
+        if let Some(freeze_qty) = instrument.freeze_qty
+            && order.qty >= freeze_qty
+            && !order.is_slice_order
+        {
+            violations.push(OrderViolation::FreezeQty {
+                qty: order.qty,
+                freeze_qty,
+            });
+        }
+
+        // Stop loss and take profit are offsets from the entry, but they
+        // still have to be in ticks
+        let prices = [
+            ("limit_price", order.limit_price),
+            ("stop_price", order.stop_price),
+            ("stop_loss", order.stop_loss),
+            ("take_profit", order.take_profit),
+        ];
+
+        let tick_size = instrument.tick_size;
+        for (field, price) in prices {
+            if price > 0.0 && !is_multiple(price, tick_size) {
+                violations.push(OrderViolation::TickSize {
+                    field,
+                    price,
+                    tick_size,
+                });
+            }
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(OrderValidationError { violations })
+    }
+}
+
+fn is_multiple(price: f64, tick_size: f64) -> bool {
+    if tick_size <= 0.0 {
+        return true;
+    }
+
+    let ticks = price / tick_size;
+    (ticks - ticks.round()).abs() < 1e-6
+}
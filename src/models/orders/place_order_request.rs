@@ -1,6 +1,7 @@
 use serde::Serialize;
 
-use crate::{OrderType, ProductType, Side, Validity};
+use crate::models::orders::order_validation;
+use crate::{InstrumentInfo, OrderType, OrderValidationError, ProductType, Side, Validity};
 
 #[cfg(doc)]
 use crate::Fyers;
@@ -9,23 +10,23 @@ use crate::Fyers;
 ///
 /// This type is typically constructed using [`OrderRequest::builder`]
 /// rather than instantiated directly.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderRequest {
-    symbol: String,
-    qty: u32,
-    r#type: OrderType,
-    side: Side,
-    product_type: ProductType,
-    limit_price: f64,
-    stop_price: f64,
-    disclosed_qty: u32,
-    validity: Validity,
-    offline_order: bool,
-    stop_loss: f64,
-    take_profit: f64,
-    order_tag: Option<String>,
-    is_slice_order: bool,
+    pub(crate) symbol: String,
+    pub(crate) qty: u32,
+    pub(crate) r#type: OrderType,
+    pub(crate) side: Side,
+    pub(crate) product_type: ProductType,
+    pub(crate) limit_price: f64,
+    pub(crate) stop_price: f64,
+    pub(crate) disclosed_qty: u32,
+    pub(crate) validity: Validity,
+    pub(crate) offline_order: bool,
+    pub(crate) stop_loss: f64,
+    pub(crate) take_profit: f64,
+    pub(crate) order_tag: Option<String>,
+    pub(crate) is_slice_order: bool,
 }
 
 /// Builder for creating an [`OrderRequest`] used with [`Fyers::place_order`].
@@ -69,6 +70,7 @@ pub struct OrderBuilder {
     take_profit: f64,
     order_tag: Option<String>,
     is_slice_order: bool,
+    instrument: Option<InstrumentInfo>,
 }
 
 impl OrderBuilder {
@@ -106,6 +108,7 @@ impl OrderBuilder {
             take_profit: 0.0,
             order_tag: None,
             is_slice_order: false,
+            instrument: None,
         }
     }
 
@@ -125,6 +128,14 @@ impl OrderBuilder {
         self
     }
 
+    /// Set the order validity.
+    ///
+    /// Overrides the validity passed to [`OrderBuilder::new`].
+    pub fn validity(mut self, validity: Validity) -> Self {
+        self.validity = validity;
+        self
+    }
+
     /// Set the disclosed quantity.
     ///
     /// This is only applicable for **equity orders**.  
@@ -176,7 +187,59 @@ impl OrderBuilder {
         self
     }
 
+    /// Supply the instrument's lot and tick size, so
+    /// [`try_build`](OrderBuilder::try_build) can check the quantity and
+    /// prices against them.
+    pub fn instrument(mut self, instrument: InstrumentInfo) -> Self {
+        self.instrument = Some(instrument);
+        self
+    }
+
+    /// Return an [`OrderRequest`] if it passes validation.
+    ///
+    /// Checks the prices required by the order type and product, the
+    /// disclosed quantity and the order tag. With an
+    /// [`instrument`](OrderBuilder::instrument), the quantity must also be
+    /// a multiple of the lot size and every price a multiple of the tick
+    /// size. If the instrument has a freeze quantity, the quantity must be
+    /// below it unless [`slice_order`](OrderBuilder::slice_order) is set.
+    ///
+    /// The error lists every rule the order breaks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fyers::{OrderBuilder, OrderType, OrderViolation, ProductType, Side, Validity};
+    ///
+    /// let err = OrderBuilder::new(
+    ///     "NSE:SBIN-EQ",
+    ///     1,
+    ///     OrderType::Limit,
+    ///     Side::Buy,
+    ///     ProductType::Intraday,
+    ///     Validity::Day,
+    /// )
+    /// .order_tag("not valid!")
+    /// .try_build()
+    /// .unwrap_err();
+    ///
+    /// assert_eq!(err.violations().len(), 2);
+    /// assert_eq!(
+    ///     err.violations()[0],
+    ///     OrderViolation::MissingLimitPrice(OrderType::Limit)
+    /// );
+    /// ```
+    pub fn try_build(self) -> Result<OrderRequest, OrderValidationError> {
+        let instrument = self.instrument;
+        let order = self.build();
+        order_validation::validate(&order, instrument.as_ref())?;
+        Ok(order)
+    }
+
     /// Return an [`OrderRequest`] with the desired configuration.
+    ///
+    /// The order isn't checked. Use [`try_build`](OrderBuilder::try_build)
+    /// to catch invalid orders before sending them.
     pub fn build(self) -> OrderRequest {
         OrderRequest {
             symbol: self.symbol,
@@ -210,6 +273,26 @@ impl OrderRequest {
     ) -> OrderBuilder {
         OrderBuilder::new(symbol, qty, order_type, side, product_type, validity)
     }
+
+    /// Trading symbol (e.g. `NSE:SBIN-EQ`)
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Quantity
+    pub fn qty(&self) -> u32 {
+        self.qty
+    }
+
+    /// Buy or Sell
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// Custom order tag, if set
+    pub fn order_tag(&self) -> Option<&str> {
+        self.order_tag.as_deref()
+    }
 }
 
 #[cfg(test)]
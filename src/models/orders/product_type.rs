@@ -1,11 +1,11 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Product type for an order.
 ///
 /// This determines how the position is held and margined by the broker.
 ///
 /// Values map directly to the strings expected by Fyers.
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ProductType {
     /// **CNC (Cash and Carry)**
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{FyersError, InstrumentInfo};
+
+/// The trading rules of every instrument in a Fyers symbol master.
+///
+/// Fyers publishes a JSON symbol master for each exchange segment, such
+/// as `https://public.fyers.in/sym_details/NSE_FO_sym_master.json`.
+///
+/// # Example
+///
+/// ```
+/// use fyers::SymbolMaster;
+///
+/// let json = r#"{
+///     "NSE:NIFTY25JANFUT": {
+///         "symTicker": "NSE:NIFTY25JANFUT",
+///         "minLotSize": 75,
+///         "tickSize": 0.05,
+///         "qtyFreeze": "1801"
+///     }
+/// }"#;
+///
+/// let master = SymbolMaster::from_json(json)?;
+/// let nifty = master.instrument("NSE:NIFTY25JANFUT").unwrap();
+///
+/// assert_eq!(nifty.lot_size, 75);
+/// assert_eq!(nifty.freeze_qty, Some(1801));
+/// # Ok::<(), fyers::FyersError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolMaster {
+    instruments: HashMap<String, InstrumentInfo>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SymbolEntry {
+    sym_ticker: String,
+    min_lot_size: u32,
+    tick_size: f64,
+    #[serde(default)]
+    qty_freeze: serde_json::Value,
+}
+
+impl SymbolMaster {
+    /// Parse a JSON symbol master.
+    pub fn from_json(json: &str) -> Result<Self, FyersError> {
+        let entries: HashMap<String, SymbolEntry> = serde_json::from_str(json)?;
+
+        let instruments = entries
+            .into_values()
+            .map(|entry| {
+                let mut info = InstrumentInfo::new(entry.min_lot_size, entry.tick_size);
+                info.freeze_qty = freeze_qty(&entry.qty_freeze);
+                (entry.sym_ticker, info)
+            })
+            .collect();
+
+        Ok(Self { instruments })
+    }
+
+    /// The trading rules of `symbol` (e.g. `NSE:SBIN-EQ`).
+    pub fn instrument(&self, symbol: &str) -> Option<InstrumentInfo> {
+        self.instruments.get(symbol).copied()
+    }
+
+    /// Number of instruments.
+    pub fn len(&self) -> usize {
+        self.instruments.len()
+    }
+
+    /// Whether there are no instruments.
+    pub fn is_empty(&self) -> bool {
+        self.instruments.is_empty()
+    }
+}
+
+// The freeze quantity is a string, which is empty for instruments
+// without one
+fn freeze_qty(value: &serde_json::Value) -> Option<u32> {
+    let qty = match value {
+        serde_json::Value::Number(n) => n.as_u64()?,
+        serde_json::Value::String(s) => s.trim().parse().ok()?,
+        _ => return None,
+    };
+
+    u32::try_from(qty).ok().filter(|&qty| qty > 0)
+}
@@ -0,0 +1,287 @@
+use std::marker::PhantomData;
+
+use crate::{
+    InstrumentInfo, OrderBuilder, OrderRequest, OrderType, OrderValidationError, ProductType, Side,
+    Validity,
+};
+
+/// Marker types for [`TypedOrderBuilder`].
+///
+/// The marker decides which setters are available, so options that don't
+/// apply to an order can't be set on it.
+pub mod kind {
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    /// Implemented by every order kind.
+    pub trait OrderKind: sealed::Sealed {}
+
+    /// Implemented by the kinds that aren't bracket or cover orders.
+    ///
+    /// Only these can set validity, disclosed quantity, AMO and slicing.
+    pub trait Regular: OrderKind {}
+
+    macro_rules! kinds {
+        ($($(#[$doc:meta])* $name:ident $(: $regular:ident)?;)*) => {
+            $(
+                $(#[$doc])*
+                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                pub struct $name;
+
+                impl sealed::Sealed for $name {}
+                impl OrderKind for $name {}
+                $(impl $regular for $name {})?
+            )*
+        };
+    }
+
+    kinds! {
+        /// A market order
+        Market: Regular;
+        /// A limit order
+        Limit: Regular;
+        /// A stop (SL-M) order
+        Stop: Regular;
+        /// A stop-limit (SL-L) order
+        StopLimit: Regular;
+        /// A bracket order
+        Bracket;
+        /// A cover order
+        Cover;
+    }
+}
+
+use kind::{OrderKind, Regular};
+
+/// How a bracket or cover order enters the position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Entry {
+    /// Enter at the market price.
+    Market,
+    /// Enter at this price or better.
+    Limit(f64),
+}
+
+/// A builder for an [`OrderRequest`] whose prices were fixed by its
+/// constructor.
+///
+/// Created by [`OrderRequest::market`], [`OrderRequest::limit`],
+/// [`OrderRequest::stop`], [`OrderRequest::stop_limit`],
+/// [`OrderRequest::bracket`] and [`OrderRequest::cover`]. Each takes the
+/// prices its order type needs as arguments, so they can't be forgotten.
+///
+/// # Example
+///
+/// ```
+/// use fyers::{OrderRequest, ProductType, Side, Validity};
+///
+/// let order = OrderRequest::limit("NSE:SBIN-EQ", 1, Side::Buy, ProductType::Intraday, 580.5)
+///     .validity(Validity::Ioc)
+///     .order_tag("entry")
+///     .build();
+/// ```
+///
+/// Options that don't apply to an order kind aren't available:
+///
+/// ```compile_fail
+/// use fyers::{Entry, OrderRequest, Side, Validity};
+///
+/// // Bracket orders are always intraday
+/// let order = OrderRequest::bracket("NSE:SBIN-EQ", 1, Side::Buy, Entry::Market, 5.0, 10.0)
+///     .validity(Validity::Ioc)
+///     .build();
+/// ```
+#[must_use = "builders must be finalized with .build()"]
+#[derive(Debug)]
+pub struct TypedOrderBuilder<K: OrderKind> {
+    inner: OrderBuilder,
+    kind: PhantomData<K>,
+}
+
+impl<K: OrderKind> TypedOrderBuilder<K> {
+    fn new(inner: OrderBuilder) -> Self {
+        Self {
+            inner,
+            kind: PhantomData,
+        }
+    }
+
+    /// Attach a custom tag to the order.
+    ///
+    /// This can be used to identify orders created by a strategy.
+    pub fn order_tag(mut self, tag: impl Into<String>) -> Self {
+        self.inner = self.inner.order_tag(tag);
+        self
+    }
+
+    /// Supply the instrument's lot and tick size for
+    /// [`try_build`](TypedOrderBuilder::try_build).
+    pub fn instrument(mut self, instrument: InstrumentInfo) -> Self {
+        self.inner = self.inner.instrument(instrument);
+        self
+    }
+
+    /// Return an [`OrderRequest`] if it passes validation.
+    ///
+    /// See [`OrderBuilder::try_build`] for the rules checked.
+    pub fn try_build(self) -> Result<OrderRequest, OrderValidationError> {
+        self.inner.try_build()
+    }
+
+    /// Return the [`OrderRequest`].
+    pub fn build(self) -> OrderRequest {
+        self.inner.build()
+    }
+}
+
+impl<K: Regular> TypedOrderBuilder<K> {
+    /// Set the order validity.
+    ///
+    /// Defaults to [`Validity::Day`].
+    pub fn validity(mut self, validity: Validity) -> Self {
+        self.inner = self.inner.validity(validity);
+        self
+    }
+
+    /// Set the disclosed quantity.
+    ///
+    /// This is only applicable for **equity orders**.
+    pub fn disclosed_qty(mut self, qty: u32) -> Self {
+        self.inner = self.inner.disclosed_qty(qty);
+        self
+    }
+
+    /// Mark the order as an AMO (After Market Order).
+    pub fn offline_order(mut self, value: bool) -> Self {
+        self.inner = self.inner.offline_order(value);
+        self
+    }
+
+    /// Enable slice orders.
+    pub fn slice_order(mut self, value: bool) -> Self {
+        self.inner = self.inner.slice_order(value);
+        self
+    }
+}
+
+impl OrderRequest {
+    fn typed(
+        symbol: impl Into<String>,
+        qty: u32,
+        order_type: OrderType,
+        side: Side,
+        product_type: ProductType,
+    ) -> OrderBuilder {
+        OrderBuilder::new(symbol, qty, order_type, side, product_type, Validity::Day)
+    }
+
+    /// A market order.
+    pub fn market(
+        symbol: impl Into<String>,
+        qty: u32,
+        side: Side,
+        product_type: ProductType,
+    ) -> TypedOrderBuilder<kind::Market> {
+        TypedOrderBuilder::new(Self::typed(
+            symbol,
+            qty,
+            OrderType::Market,
+            side,
+            product_type,
+        ))
+    }
+
+    /// A limit order at `price`.
+    pub fn limit(
+        symbol: impl Into<String>,
+        qty: u32,
+        side: Side,
+        product_type: ProductType,
+        price: f64,
+    ) -> TypedOrderBuilder<kind::Limit> {
+        TypedOrderBuilder::new(
+            Self::typed(symbol, qty, OrderType::Limit, side, product_type).limit_price(price),
+        )
+    }
+
+    /// A stop (SL-M) order, sent to the market once `trigger` trades.
+    pub fn stop(
+        symbol: impl Into<String>,
+        qty: u32,
+        side: Side,
+        product_type: ProductType,
+        trigger: f64,
+    ) -> TypedOrderBuilder<kind::Stop> {
+        TypedOrderBuilder::new(
+            Self::typed(symbol, qty, OrderType::Stop, side, product_type).stop_price(trigger),
+        )
+    }
+
+    /// A stop-limit (SL-L) order, which becomes a limit order at `price`
+    /// once `trigger` trades.
+    pub fn stop_limit(
+        symbol: impl Into<String>,
+        qty: u32,
+        side: Side,
+        product_type: ProductType,
+        trigger: f64,
+        price: f64,
+    ) -> TypedOrderBuilder<kind::StopLimit> {
+        TypedOrderBuilder::new(
+            Self::typed(symbol, qty, OrderType::StopLimit, side, product_type)
+                .stop_price(trigger)
+                .limit_price(price),
+        )
+    }
+
+    /// A bracket order.
+    ///
+    /// `stop_loss` and `take_profit` are distances from the entry price,
+    /// as Fyers expects them.
+    pub fn bracket(
+        symbol: impl Into<String>,
+        qty: u32,
+        side: Side,
+        entry: Entry,
+        stop_loss: f64,
+        take_profit: f64,
+    ) -> TypedOrderBuilder<kind::Bracket> {
+        TypedOrderBuilder::new(
+            Self::entry(symbol, qty, side, ProductType::Bo, entry)
+                .stop_loss(stop_loss)
+                .take_profit(take_profit),
+        )
+    }
+
+    /// A cover order.
+    ///
+    /// `stop_loss` is a distance from the entry price, as Fyers expects
+    /// it.
+    pub fn cover(
+        symbol: impl Into<String>,
+        qty: u32,
+        side: Side,
+        entry: Entry,
+        stop_loss: f64,
+    ) -> TypedOrderBuilder<kind::Cover> {
+        TypedOrderBuilder::new(
+            Self::entry(symbol, qty, side, ProductType::Co, entry).stop_loss(stop_loss),
+        )
+    }
+
+    fn entry(
+        symbol: impl Into<String>,
+        qty: u32,
+        side: Side,
+        product_type: ProductType,
+        entry: Entry,
+    ) -> OrderBuilder {
+        match entry {
+            Entry::Market => Self::typed(symbol, qty, OrderType::Market, side, product_type),
+            Entry::Limit(price) => {
+                Self::typed(symbol, qty, OrderType::Limit, side, product_type).limit_price(price)
+            }
+        }
+    }
+}
@@ -1 +1,2 @@
 pub mod exit_position_result;
+pub mod position;
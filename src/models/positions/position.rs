@@ -0,0 +1,83 @@
+use serde::Deserialize;
+
+use crate::ProductType;
+
+/// A single net position.
+///
+/// Returned by [`Fyers::positions`](crate::Fyers::positions).
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    /// Position ID (e.g. `NSE:SBIN-EQ-INTRADAY`)
+    pub id: String,
+
+    /// Trading symbol (e.g. `NSE:SBIN-EQ`)
+    pub symbol: String,
+
+    /// Product type
+    pub product_type: ProductType,
+
+    /// Net quantity, negative for short positions
+    pub net_qty: i64,
+
+    /// Average price of the net position
+    #[serde(default)]
+    pub net_avg: f64,
+
+    /// Average price of the open quantity
+    #[serde(default)]
+    pub avg_price: f64,
+
+    /// Total quantity bought
+    #[serde(default)]
+    pub buy_qty: u32,
+
+    /// Average buy price
+    #[serde(default)]
+    pub buy_avg: f64,
+
+    /// Total value bought
+    #[serde(default)]
+    pub buy_val: f64,
+
+    /// Total quantity sold
+    #[serde(default)]
+    pub sell_qty: u32,
+
+    /// Average sell price
+    #[serde(default)]
+    pub sell_avg: f64,
+
+    /// Total value sold
+    #[serde(default)]
+    pub sell_val: f64,
+
+    /// Profit or loss on the closed quantity
+    #[serde(rename = "realized_profit", default)]
+    pub realized_profit: f64,
+
+    /// Profit or loss on the open quantity at the last traded price
+    #[serde(rename = "unrealized_profit", default)]
+    pub unrealized_profit: f64,
+
+    /// Total profit or loss
+    #[serde(default)]
+    pub pl: f64,
+
+    /// Last traded price
+    #[serde(default)]
+    pub ltp: f64,
+}
+
+impl Position {
+    /// Whether any quantity is still open.
+    pub fn is_open(&self) -> bool {
+        self.net_qty != 0
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct PositionsResponse {
+    #[serde(rename = "netPositions", default)]
+    pub(crate) net_positions: Vec<Position>,
+}
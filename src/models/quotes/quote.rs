@@ -0,0 +1,88 @@
+use serde::Deserialize;
+
+/// Latest market data of a symbol.
+///
+/// Returned by [`Fyers::quotes`](crate::Fyers::quotes).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quote {
+    /// Trading symbol (e.g. `NSE:SBIN-EQ`)
+    pub symbol: String,
+    /// Last traded price
+    pub ltp: f64,
+    /// Best bid price
+    pub bid: f64,
+    /// Best ask price
+    pub ask: f64,
+    /// Open price of the day
+    pub open: f64,
+    /// High price of the day
+    pub high: f64,
+    /// Low price of the day
+    pub low: f64,
+    /// Previous close price
+    pub prev_close: f64,
+    /// Change from the previous close
+    pub change: f64,
+    /// Change from the previous close in percent
+    pub change_pct: f64,
+    /// Volume traded during the day
+    pub volume: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct QuotesResponse {
+    #[serde(rename = "d", default)]
+    pub quotes: Vec<QuoteEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct QuoteEntry {
+    #[serde(rename = "n")]
+    symbol: String,
+    #[serde(rename = "v")]
+    values: QuoteValues,
+}
+
+#[derive(Debug, Deserialize)]
+struct QuoteValues {
+    #[serde(default)]
+    lp: f64,
+    #[serde(default)]
+    bid: f64,
+    #[serde(default)]
+    ask: f64,
+    #[serde(default)]
+    open_price: f64,
+    #[serde(default)]
+    high_price: f64,
+    #[serde(default)]
+    low_price: f64,
+    #[serde(default)]
+    prev_close_price: f64,
+    #[serde(default)]
+    ch: f64,
+    #[serde(default)]
+    chp: f64,
+    #[serde(default)]
+    volume: u64,
+}
+
+impl From<QuoteEntry> for Quote {
+    fn from(entry: QuoteEntry) -> Self {
+        let v = entry.values;
+
+        Self {
+            symbol: entry.symbol,
+            ltp: v.lp,
+            bid: v.bid,
+            ask: v.ask,
+            open: v.open_price,
+            high: v.high_price,
+            low: v.low_price,
+            prev_close: v.prev_close_price,
+            change: v.ch,
+            change_pct: v.chp,
+            volume: v.volume,
+        }
+    }
+}
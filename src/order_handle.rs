@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::{
+    Broker, Fyers, FyersError, ModifyOrderBuilder, ModifyOrderRequest, OrderDetails, OrderRequest,
+    OrderStatus,
+};
+
+// Every check downloads the whole order book, which counts against the
+// quota of 200 requests a minute
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Tracks a placed order.
+///
+/// Returned by [`Fyers::place_order_handle`], or created for any order ID
+/// with [`OrderHandle::new`]. The handle owns a clone of the client, so it
+/// can be moved into a spawned task.
+///
+/// The crate has no order stream, so waiting is done by polling: the order
+/// book is fetched every [`poll_interval`](OrderHandle::poll_interval).
+/// Each fetch counts against the order API's rate limit of 200 requests a
+/// minute, and handles don't share fetches. When waiting on many orders at
+/// once, poll [`Fyers::orders`] yourself instead.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use fyers::{Fyers, OrderRequest, OrderStatus, ProductType, Side};
+///
+/// # async fn run() -> Result<(), fyers::FyersError> {
+/// let fyers = Fyers::new("CLIENT_ID", "ACCESS_TOKEN");
+///
+/// let order = OrderRequest::limit("NSE:SBIN-EQ", 10, Side::Buy, ProductType::Intraday, 580.0)
+///     .build();
+/// let handle = fyers.place_order_handle(&order).await?;
+///
+/// // Give it a minute to fill, then give up
+/// let details = handle.wait_until_filled(Duration::from_secs(60)).await?;
+/// if details.status.is_open() {
+///     handle.cancel().await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderHandle<B = Fyers> {
+    broker: B,
+    id: String,
+    poll_interval: Duration,
+}
+
+impl<B: Broker> OrderHandle<B> {
+    /// Track the order with ID `id`, placed through `broker`.
+    pub fn new(broker: B, id: impl Into<String>) -> Self {
+        Self {
+            broker,
+            id: id.into(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Place `order` through `broker` and track it.
+    pub async fn place(broker: B, order: &OrderRequest) -> Result<Self, FyersError> {
+        let placed = broker.place_order(order).await?;
+        Ok(Self::new(broker, placed.id))
+    }
+
+    /// How often to check the order book while waiting.
+    ///
+    /// Defaults to five seconds.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Order ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The order as it is in the order book now.
+    pub async fn details(&self) -> Result<OrderDetails, FyersError> {
+        self.broker
+            .orders()
+            .await?
+            .into_iter()
+            .find(|o| o.id == self.id)
+            .ok_or(FyersError::InvalidOrderId)
+    }
+
+    /// The current status of the order.
+    pub async fn status(&self) -> Result<OrderStatus, FyersError> {
+        Ok(self.details().await?.status)
+    }
+
+    /// Wait for the order to fill, for at most `timeout`.
+    ///
+    /// Returns the order as last seen. It is still open if `timeout`
+    /// passed first, and closed without filling if it was cancelled,
+    /// rejected or expired.
+    pub async fn wait_until_filled(&self, timeout: Duration) -> Result<OrderDetails, FyersError> {
+        self.wait(Some(Instant::now() + timeout)).await
+    }
+
+    /// Wait for the order to be filled, cancelled, rejected or expired.
+    pub async fn wait_until_terminal(&self) -> Result<OrderDetails, FyersError> {
+        self.wait(None).await
+    }
+
+    /// Cancel the order.
+    pub async fn cancel(&self) -> Result<(), FyersError> {
+        self.broker.cancel_order(&self.id).await
+    }
+
+    /// Modify the order.
+    ///
+    /// `changes` sets the fields to change on a builder for this order.
+    ///
+    /// ```no_run
+    /// # async fn run(handle: fyers::OrderHandle) -> Result<(), fyers::FyersError> {
+    /// handle.modify(|m| m.qty(5).limit_price(581.0)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn modify(
+        &self,
+        changes: impl FnOnce(ModifyOrderBuilder) -> ModifyOrderBuilder,
+    ) -> Result<(), FyersError> {
+        let modify = changes(ModifyOrderRequest::builder(self.id.as_str())).build();
+        self.broker.modify_order(&modify).await?;
+        Ok(())
+    }
+
+    // Poll until the order closes or the deadline passes
+    async fn wait(&self, deadline: Option<Instant>) -> Result<OrderDetails, FyersError> {
+        loop {
+            let details = self.details().await?;
+            if !details.status.is_open() {
+                return Ok(details);
+            }
+
+            let mut wait = self.poll_interval;
+            if let Some(deadline) = deadline {
+                let left = deadline.saturating_duration_since(Instant::now());
+                if left.is_zero() {
+                    return Ok(details);
+                }
+                wait = wait.min(left);
+            }
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+impl Fyers {
+    /// Place a single order and return an [`OrderHandle`] for tracking
+    /// it.
+    ///
+    /// Works like [`Fyers::place_order`]. The handle holds a clone of
+    /// this client.
+    pub async fn place_order_handle(
+        &self,
+        order: &OrderRequest,
+    ) -> Result<OrderHandle, FyersError> {
+        OrderHandle::place(self.clone(), order).await
+    }
+}
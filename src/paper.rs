@@ -0,0 +1,254 @@
+//! Paper trading against a supplied price feed.
+//!
+//! [`PaperTrader`] implements [`Broker`] without contacting Fyers. Orders
+//! are filled against the prices passed to
+//! [`update_tick`](PaperTrader::update_tick) and
+//! [`update_candle`](PaperTrader::update_candle), and positions, P&L and
+//! funds are tracked locally.
+//!
+//! # Fills
+//!
+//! Each price update is matched against the open orders for its symbol.
+//! A tick is treated as a candle whose open, high, low and close are all
+//! the tick price.
+//!
+//! - [`OrderType::Market`] fills at the open.
+//! - [`OrderType::Limit`] fills once the limit price is touched, at the
+//!   limit or the open if that is better.
+//! - [`OrderType::Stop`] triggers once the stop price is touched and fills
+//!   at the stop or the open if that is worse.
+//! - [`OrderType::StopLimit`] triggers like a stop order and then works as
+//!   a limit order.
+//!
+//! Orders placed after a symbol has a price are matched against the last
+//! price straight away. [`Validity::Ioc`] orders that don't fill on their
+//! first match are cancelled.
+//!
+//! Orders are filled in full. A buy is rejected when the cash isn't
+//! enough to pay for it, while sells may open short positions.
+//!
+//! Fills are free and exact by default. Use
+//! [`with_slippage`](PaperTrader::with_slippage) and
+//! [`with_charges`](PaperTrader::with_charges) to make them more
+//! realistic.
+//!
+//! # Example
+//!
+//! ```
+//! use fyers::paper::PaperTrader;
+//! use fyers::{Broker, OrderRequest, OrderStatus, OrderType, ProductType, Side, Validity};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), fyers::FyersError> {
+//! let paper = PaperTrader::new(100_000.0);
+//! paper.update_tick("NSE:SBIN-EQ", fyers::ist_datetime(2025, 1, 1, 9, 15), 580.0);
+//!
+//! let order = OrderRequest::builder(
+//!     "NSE:SBIN-EQ",
+//!     10,
+//!     OrderType::Market,
+//!     Side::Buy,
+//!     ProductType::Intraday,
+//!     Validity::Day,
+//! )
+//! .build();
+//! paper.place_order(&order).await?;
+//!
+//! paper.update_tick("NSE:SBIN-EQ", fyers::ist_datetime(2025, 1, 1, 9, 16), 585.0);
+//!
+//! let orders = paper.orders().await?;
+//! assert_eq!(orders[0].status, OrderStatus::Filled);
+//! assert_eq!(paper.funds().unrealized_pnl, 50.0);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::{Future, ready};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+
+use crate::charges::ChargeTable;
+use crate::execution::PriceSource;
+use crate::{
+    Broker, Candle, ExitPositionResult, FyersError, ModifyOrderRequest, Order, OrderDetails,
+    OrderRequest, Position, ProductType, Side,
+};
+
+#[cfg(doc)]
+use crate::charges::Segment;
+#[cfg(doc)]
+use crate::{OrderType, Validity};
+
+mod book;
+mod slippage;
+
+use book::{Bar, Book};
+
+pub use slippage::Slippage;
+
+/// A simulated broker that fills orders against a supplied price feed.
+///
+/// Clones share the same orders, positions and funds, so one clone can
+/// feed prices while another places orders.
+#[derive(Clone)]
+pub struct PaperTrader {
+    book: Arc<Mutex<Book>>,
+}
+
+/// Funds of a [`PaperTrader`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Funds {
+    /// Cash after all fills so far
+    pub cash: f64,
+    /// Profit or loss on closed quantities
+    pub realized_pnl: f64,
+    /// Profit or loss on open quantities at the last price
+    pub unrealized_pnl: f64,
+    /// Total brokerage and statutory charges paid
+    pub charges: f64,
+    /// Cash plus the value of all open positions at the last price
+    pub equity: f64,
+}
+
+/// A single fill of a paper order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trade {
+    /// ID of the filled order
+    pub order_id: String,
+    /// Trading symbol (e.g. `NSE:SBIN-EQ`)
+    pub symbol: String,
+    /// Product type
+    pub product_type: ProductType,
+    /// Buy or Sell
+    pub side: Side,
+    /// Filled quantity
+    pub qty: u32,
+    /// Fill price, including slippage
+    pub price: f64,
+    /// Charges deducted for the fill
+    pub charges: f64,
+    /// Time of the price update that filled the order
+    pub time: DateTime<Utc>,
+}
+
+impl PaperTrader {
+    /// Create a paper trader with the given starting cash.
+    pub fn new(funds: f64) -> Self {
+        Self {
+            book: Arc::new(Mutex::new(Book::new(funds))),
+        }
+    }
+
+    /// Move market and stop order fills against the order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`Slippage::Percent`] isn't from 0 up to 100, or
+    /// [`Slippage::Points`] is negative.
+    pub fn with_slippage(self, slippage: Slippage) -> Self {
+        slippage.check();
+        self.book().slippage = slippage;
+        self
+    }
+
+    /// Deduct brokerage and statutory charges on every fill.
+    ///
+    /// The segment of each fill comes from [`Segment::from_symbol`].
+    /// Symbols it doesn't recognise are charged nothing.
+    pub fn with_charges(self, charges: ChargeTable) -> Self {
+        self.book().charges = charges;
+        self
+    }
+
+    // Hold new orders until the next price update instead of matching
+    // them against the last price
+    pub(crate) fn match_on_update_only(self) -> Self {
+        self.book().match_on_place = false;
+        self
+    }
+
+    /// Feed a traded price for `symbol`.
+    pub fn update_tick(&self, symbol: &str, time: DateTime<Utc>, price: f64) {
+        self.book().update(symbol, Bar::tick(time, price));
+    }
+
+    /// Feed a candle for `symbol`.
+    ///
+    /// Candles should be fed once they are complete, in time order.
+    pub fn update_candle(&self, symbol: &str, candle: &Candle) {
+        self.book().update(symbol, Bar::from(candle));
+    }
+
+    /// The last price fed for `symbol`.
+    pub fn last_price(&self, symbol: &str) -> Option<f64> {
+        self.book().last_price(symbol)
+    }
+
+    /// All fills so far, oldest first.
+    pub fn trades(&self) -> Vec<Trade> {
+        self.book().trades()
+    }
+
+    /// Current funds, valued at the last prices.
+    pub fn funds(&self) -> Funds {
+        self.book().funds()
+    }
+
+    fn book(&self) -> MutexGuard<'_, Book> {
+        self.book.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl std::fmt::Debug for PaperTrader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaperTrader")
+            .field("funds", &self.funds())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Broker for PaperTrader {
+    fn place_order(
+        &self,
+        order: &OrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        ready(self.book().place(order))
+    }
+
+    fn cancel_order(&self, order_id: &str) -> impl Future<Output = Result<(), FyersError>> + Send {
+        ready(self.book().cancel(order_id))
+    }
+
+    fn modify_order(
+        &self,
+        modify: &ModifyOrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        ready(self.book().modify(modify))
+    }
+
+    fn orders(&self) -> impl Future<Output = Result<Vec<OrderDetails>, FyersError>> + Send {
+        ready(Ok(self.book().orders()))
+    }
+
+    fn positions(&self) -> impl Future<Output = Result<Vec<Position>, FyersError>> + Send {
+        ready(Ok(self.book().positions()))
+    }
+
+    fn exit_all_positions(
+        &self,
+    ) -> impl Future<Output = Result<ExitPositionResult, FyersError>> + Send {
+        ready(Ok(self.book().exit_all()))
+    }
+}
+
+impl PriceSource for PaperTrader {
+    // The paper book has no depth, so both sides are at the last price
+    fn best_price(
+        &self,
+        symbol: &str,
+        _side: Side,
+    ) -> impl Future<Output = Result<f64, FyersError>> + Send {
+        ready(self.last_price(symbol).ok_or(FyersError::InvalidSymbol))
+    }
+}
@@ -0,0 +1,574 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::charges::{ChargeTable, Segment};
+use crate::datetime::IST;
+use crate::paper::{Funds, Slippage, Trade};
+use crate::{
+    Candle, ExitPositionResult, FyersError, ModifyOrderRequest, Order, OrderDetails, OrderRequest,
+    OrderStatus, OrderType, Position, ProductType, Side, Validity,
+};
+
+// Fyers' error code for orders rejected by its risk checks
+const REJECTED: i32 = -99;
+
+// A price update, with ticks turned into flat candles
+pub(super) struct Bar {
+    time: DateTime<Utc>,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+}
+
+impl Bar {
+    pub(super) fn tick(time: DateTime<Utc>, price: f64) -> Self {
+        Self {
+            time,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+        }
+    }
+}
+
+impl From<&Candle> for Bar {
+    fn from(candle: &Candle) -> Self {
+        Self {
+            time: candle.time,
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+        }
+    }
+}
+
+struct Quote {
+    time: DateTime<Utc>,
+    price: f64,
+}
+
+struct PaperOrder {
+    details: OrderDetails,
+    // Whether a stop-limit order has hit its stop price
+    triggered: bool,
+}
+
+impl PaperOrder {
+    // The price the order fills at within `bar`, if it fills
+    fn fill_price(&mut self, bar: &Bar) -> Option<f64> {
+        let buy = self.details.side == Side::Buy;
+        let limit = self.details.limit_price;
+        let stop = self.details.stop_price;
+
+        match self.details.order_type {
+            OrderType::Market => Some(bar.open),
+            OrderType::Limit => limit_fill(buy, limit, bar.open, bar),
+            OrderType::Stop => stop_fill(buy, stop, bar),
+            OrderType::StopLimit => {
+                let open = if self.triggered {
+                    bar.open
+                } else {
+                    let trigger = stop_fill(buy, stop, bar)?;
+                    self.triggered = true;
+                    trigger
+                };
+
+                limit_fill(buy, limit, open, bar)
+            }
+        }
+    }
+}
+
+// A limit order fills at its limit or better once the bar touches it
+fn limit_fill(buy: bool, limit: f64, open: f64, bar: &Bar) -> Option<f64> {
+    if buy {
+        (bar.low <= limit).then(|| open.min(limit))
+    } else {
+        (bar.high >= limit).then(|| open.max(limit))
+    }
+}
+
+// A stop order fills at its stop or worse once the bar touches it
+fn stop_fill(buy: bool, stop: f64, bar: &Bar) -> Option<f64> {
+    if buy {
+        (bar.high >= stop).then(|| bar.open.max(stop))
+    } else {
+        (bar.low <= stop).then(|| bar.open.min(stop))
+    }
+}
+
+// The net position in one symbol and product
+struct Holding {
+    symbol: String,
+    product_type: ProductType,
+    net_qty: i64,
+    avg_price: f64,
+    buy_qty: u32,
+    buy_val: f64,
+    sell_qty: u32,
+    sell_val: f64,
+    realized: f64,
+}
+
+impl Holding {
+    fn new(symbol: &str, product_type: ProductType) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            product_type,
+            net_qty: 0,
+            avg_price: 0.0,
+            buy_qty: 0,
+            buy_val: 0.0,
+            sell_qty: 0,
+            sell_val: 0.0,
+            realized: 0.0,
+        }
+    }
+
+    fn apply(&mut self, side: Side, qty: u32, price: f64) {
+        let signed = match side {
+            Side::Buy => {
+                self.buy_qty += qty;
+                self.buy_val += qty as f64 * price;
+                i64::from(qty)
+            }
+            Side::Sell => {
+                self.sell_qty += qty;
+                self.sell_val += qty as f64 * price;
+                -i64::from(qty)
+            }
+        };
+
+        let open = self.net_qty.abs();
+
+        if self.net_qty == 0 || self.net_qty.signum() == signed.signum() {
+            self.avg_price =
+                (self.avg_price * open as f64 + price * qty as f64) / (open + signed.abs()) as f64;
+        } else {
+            let closed = signed.abs().min(open);
+            self.realized +=
+                closed as f64 * (price - self.avg_price) * self.net_qty.signum() as f64;
+
+            if signed.abs() > open {
+                // The position flipped sides
+                self.avg_price = price;
+            } else if signed.abs() == open {
+                self.avg_price = 0.0;
+            }
+        }
+
+        self.net_qty += signed;
+    }
+
+    fn unrealized(&self, ltp: f64) -> f64 {
+        self.net_qty as f64 * (ltp - self.avg_price)
+    }
+}
+
+pub(super) struct Book {
+    cash: f64,
+    next_id: u64,
+    orders: Vec<PaperOrder>,
+    holdings: Vec<Holding>,
+    trades: Vec<Trade>,
+    quotes: HashMap<String, Quote>,
+    // Time of the latest price update of any symbol
+    now: Option<DateTime<Utc>>,
+    charges_paid: f64,
+    pub(super) slippage: Slippage,
+    pub(super) charges: ChargeTable,
+    // Whether new orders are matched against the last price right away,
+    // rather than waiting for the next update
+    pub(super) match_on_place: bool,
+}
+
+impl Book {
+    pub(super) fn new(cash: f64) -> Self {
+        Self {
+            cash,
+            next_id: 1,
+            orders: Vec::new(),
+            holdings: Vec::new(),
+            trades: Vec::new(),
+            quotes: HashMap::new(),
+            now: None,
+            charges_paid: 0.0,
+            slippage: Slippage::None,
+            charges: ChargeTable::empty(),
+            match_on_place: true,
+        }
+    }
+
+    pub(super) fn update(&mut self, symbol: &str, bar: Bar) {
+        self.now = Some(self.now.map_or(bar.time, |now| now.max(bar.time)));
+        self.quotes.insert(
+            symbol.to_owned(),
+            Quote {
+                time: bar.time,
+                price: bar.close,
+            },
+        );
+
+        for i in 0..self.orders.len() {
+            let details = &self.orders[i].details;
+            if details.symbol == symbol && details.status == OrderStatus::Pending {
+                self.execute(i, &bar);
+            }
+        }
+    }
+
+    pub(super) fn last_price(&self, symbol: &str) -> Option<f64> {
+        self.quotes.get(symbol).map(|q| q.price)
+    }
+
+    pub(super) fn place(&mut self, order: &OrderRequest) -> Result<Order, FyersError> {
+        validate(order.qty, order.r#type, order.limit_price, order.stop_price)?;
+
+        if matches!(order.product_type, ProductType::Bo | ProductType::Co) {
+            return Err(FyersError::InvalidParams(
+                "bracket and cover orders are not supported in paper trading".into(),
+            ));
+        }
+
+        let id = self.next_id.to_string();
+        self.next_id += 1;
+
+        let time = self
+            .quotes
+            .get(&order.symbol)
+            .map(|q| q.time)
+            .or(self.now)
+            .map(|time| {
+                time.with_timezone(&IST)
+                    .format("%d-%b-%Y %H:%M:%S")
+                    .to_string()
+            });
+
+        self.orders.push(PaperOrder {
+            details: OrderDetails {
+                id: id.clone(),
+                exch_ord_id: None,
+                symbol: order.symbol.clone(),
+                qty: order.qty,
+                remaining_quantity: order.qty,
+                filled_qty: 0,
+                disclosed_qty: order.disclosed_qty,
+                limit_price: order.limit_price,
+                stop_price: order.stop_price,
+                traded_price: 0.0,
+                order_type: order.r#type,
+                side: order.side,
+                product_type: order.product_type,
+                validity: order.validity,
+                status: OrderStatus::Pending,
+                offline_order: order.offline_order,
+                order_date_time: time,
+                message: None,
+                // Fyers prefixes tags the same way
+                order_tag: order.order_tag.as_ref().map(|tag| format!("1:{tag}")),
+                parent_id: None,
+            },
+            triggered: false,
+        });
+
+        self.execute_at_last_price(self.orders.len() - 1)?;
+        Ok(Order { id })
+    }
+
+    pub(super) fn cancel(&mut self, order_id: &str) -> Result<(), FyersError> {
+        let order = self.pending_order(order_id)?;
+        order.details.status = OrderStatus::Cancelled;
+        order.details.message = Some("Cancelled by user".into());
+        Ok(())
+    }
+
+    pub(super) fn modify(&mut self, modify: &ModifyOrderRequest) -> Result<Order, FyersError> {
+        let i = self.pending_index(&modify.id)?;
+        let order = &mut self.orders[i];
+        let details = &order.details;
+
+        let qty = modify.qty.unwrap_or(details.qty);
+        let order_type = modify.r#type.unwrap_or(details.order_type);
+        let limit_price = modify.limit_price.unwrap_or(details.limit_price);
+        let stop_price = modify.stop_price.unwrap_or(details.stop_price);
+        validate(qty, order_type, limit_price, stop_price)?;
+
+        if order_type != details.order_type || stop_price != details.stop_price {
+            order.triggered = false;
+        }
+
+        let details = &mut order.details;
+        details.qty = qty;
+        details.remaining_quantity = qty;
+        details.order_type = order_type;
+        details.limit_price = limit_price;
+        details.stop_price = stop_price;
+
+        self.execute_at_last_price(i)?;
+        Ok(Order {
+            id: modify.id.clone(),
+        })
+    }
+
+    pub(super) fn trades(&self) -> Vec<Trade> {
+        self.trades.clone()
+    }
+
+    pub(super) fn orders(&self) -> Vec<OrderDetails> {
+        self.orders.iter().map(|o| o.details.clone()).collect()
+    }
+
+    pub(super) fn positions(&self) -> Vec<Position> {
+        self.holdings
+            .iter()
+            .map(|h| {
+                let ltp = self.last_price(&h.symbol).unwrap_or(h.avg_price);
+                let unrealized = h.unrealized(ltp);
+
+                Position {
+                    id: format!("{}-{}", h.symbol, product_code(h.product_type)),
+                    symbol: h.symbol.clone(),
+                    product_type: h.product_type,
+                    net_qty: h.net_qty,
+                    net_avg: h.avg_price,
+                    avg_price: h.avg_price,
+                    buy_qty: h.buy_qty,
+                    buy_avg: average(h.buy_val, h.buy_qty),
+                    buy_val: h.buy_val,
+                    sell_qty: h.sell_qty,
+                    sell_avg: average(h.sell_val, h.sell_qty),
+                    sell_val: h.sell_val,
+                    realized_profit: h.realized,
+                    unrealized_profit: unrealized,
+                    pl: h.realized + unrealized,
+                    ltp,
+                }
+            })
+            .collect()
+    }
+
+    pub(super) fn exit_all(&mut self) -> ExitPositionResult {
+        let open: Vec<_> = self
+            .holdings
+            .iter()
+            .filter(|h| h.net_qty != 0)
+            .map(|h| (h.symbol.clone(), h.product_type, h.net_qty))
+            .collect();
+
+        let mut closed = true;
+
+        for (symbol, product_type, net_qty) in open {
+            let side = if net_qty > 0 { Side::Sell } else { Side::Buy };
+            let qty = u32::try_from(net_qty.unsigned_abs()).unwrap_or(u32::MAX);
+            let order = OrderRequest::builder(
+                symbol,
+                qty,
+                OrderType::Market,
+                side,
+                product_type,
+                Validity::Day,
+            )
+            .build();
+
+            let filled = self
+                .place(&order)
+                .is_ok_and(|_| self.orders.last().unwrap().details.status == OrderStatus::Filled);
+            closed &= filled;
+        }
+
+        if closed {
+            ExitPositionResult::Closed
+        } else {
+            ExitPositionResult::PendingCounterOrder
+        }
+    }
+
+    pub(super) fn funds(&self) -> Funds {
+        let mut funds = Funds {
+            cash: self.cash,
+            realized_pnl: 0.0,
+            unrealized_pnl: 0.0,
+            charges: self.charges_paid,
+            equity: self.cash,
+        };
+
+        for h in &self.holdings {
+            let ltp = self.last_price(&h.symbol).unwrap_or(h.avg_price);
+            funds.realized_pnl += h.realized;
+            funds.unrealized_pnl += h.unrealized(ltp);
+            funds.equity += h.net_qty as f64 * ltp;
+        }
+
+        funds
+    }
+
+    // Match a new or modified order against the last price of its symbol
+    fn execute_at_last_price(&mut self, i: usize) -> Result<(), FyersError> {
+        if !self.match_on_place {
+            return Ok(());
+        }
+
+        let Some(quote) = self.quotes.get(&self.orders[i].details.symbol) else {
+            return Ok(());
+        };
+
+        let bar = Bar::tick(quote.time, quote.price);
+        self.execute(i, &bar);
+
+        let details = &self.orders[i].details;
+        if details.status == OrderStatus::Rejected {
+            return Err(FyersError::Api {
+                code: REJECTED,
+                message: details.message.clone().unwrap_or_default(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn execute(&mut self, i: usize, bar: &Bar) {
+        let order = &mut self.orders[i];
+
+        match order.fill_price(bar) {
+            Some(price) => self.fill(i, price, bar.time),
+            None if order.details.validity == Validity::Ioc => {
+                order.details.status = OrderStatus::Cancelled;
+                order.details.message = Some("IOC order was not filled".into());
+            }
+            None => {}
+        }
+    }
+
+    fn fill(&mut self, i: usize, price: f64, time: DateTime<Utc>) {
+        let details = &mut self.orders[i].details;
+
+        let price = match details.order_type {
+            OrderType::Market | OrderType::Stop => self.slippage.apply(details.side, price),
+            OrderType::Limit | OrderType::StopLimit => price,
+        };
+        let value = details.qty as f64 * price;
+        let charges = Segment::from_symbol(&details.symbol).map_or(0.0, |segment| {
+            self.charges
+                .calculate(
+                    segment,
+                    details.product_type,
+                    details.side,
+                    details.qty,
+                    price,
+                )
+                .total()
+        });
+
+        if details.side == Side::Buy && value + charges > self.cash {
+            details.status = OrderStatus::Rejected;
+            details.message = Some(format!(
+                "Insufficient funds: required {:.2}, available {:.2}",
+                value + charges,
+                self.cash
+            ));
+            return;
+        }
+
+        match details.side {
+            Side::Buy => self.cash -= value + charges,
+            Side::Sell => self.cash += value - charges,
+        }
+        self.charges_paid += charges;
+
+        details.status = OrderStatus::Filled;
+        details.filled_qty = details.qty;
+        details.remaining_quantity = 0;
+        details.traded_price = price;
+
+        let holding = match self
+            .holdings
+            .iter()
+            .position(|h| h.symbol == details.symbol && h.product_type == details.product_type)
+        {
+            Some(h) => &mut self.holdings[h],
+            None => {
+                self.holdings
+                    .push(Holding::new(&details.symbol, details.product_type));
+                self.holdings.last_mut().unwrap()
+            }
+        };
+
+        holding.apply(details.side, details.qty, price);
+
+        self.trades.push(Trade {
+            order_id: details.id.clone(),
+            symbol: details.symbol.clone(),
+            product_type: details.product_type,
+            side: details.side,
+            qty: details.qty,
+            price,
+            charges,
+            time,
+        });
+    }
+
+    fn pending_index(&self, order_id: &str) -> Result<usize, FyersError> {
+        let i = self
+            .orders
+            .iter()
+            .position(|o| o.details.id == order_id)
+            .ok_or(FyersError::InvalidOrderId)?;
+
+        if !self.orders[i].details.status.is_open() {
+            return Err(FyersError::InvalidParams(format!(
+                "order {order_id} is not pending"
+            )));
+        }
+
+        Ok(i)
+    }
+
+    fn pending_order(&mut self, order_id: &str) -> Result<&mut PaperOrder, FyersError> {
+        let i = self.pending_index(order_id)?;
+        Ok(&mut self.orders[i])
+    }
+}
+
+fn validate(
+    qty: u32,
+    order_type: OrderType,
+    limit_price: f64,
+    stop_price: f64,
+) -> Result<(), FyersError> {
+    if qty == 0 {
+        return Err(FyersError::InvalidParams("qty must be positive".into()));
+    }
+
+    if matches!(order_type, OrderType::Limit | OrderType::StopLimit) && limit_price <= 0.0 {
+        return Err(FyersError::InvalidParams(
+            "limit orders need a limit price".into(),
+        ));
+    }
+
+    if matches!(order_type, OrderType::Stop | OrderType::StopLimit) && stop_price <= 0.0 {
+        return Err(FyersError::InvalidParams(
+            "stop orders need a stop price".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn average(value: f64, qty: u32) -> f64 {
+    if qty == 0 { 0.0 } else { value / qty as f64 }
+}
+
+fn product_code(product_type: ProductType) -> &'static str {
+    match product_type {
+        ProductType::Cnc => "CNC",
+        ProductType::Intraday => "INTRADAY",
+        ProductType::Margin => "MARGIN",
+        ProductType::Co => "CO",
+        ProductType::Bo => "BO",
+        ProductType::Mtf => "MTF",
+    }
+}
@@ -0,0 +1,46 @@
+use crate::Side;
+
+/// How far fills are moved against the order, to model the spread and
+/// market impact.
+///
+/// Slippage only applies to market and stop orders. Limit and stop-limit
+/// orders never fill worse than their limit price.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Slippage {
+    /// Fill at the matched price.
+    #[default]
+    None,
+
+    /// A percentage of the price, e.g. `0.05` for 5 basis points.
+    Percent(f64),
+
+    /// A fixed amount of the price, e.g. `0.05` for one tick on most NSE
+    /// equities.
+    Points(f64),
+}
+
+impl Slippage {
+    // Panics unless the slippage is a percentage below 100 or a
+    // non-negative number of points
+    pub(crate) fn check(self) {
+        let valid = match self {
+            Slippage::None => true,
+            Slippage::Percent(percent) => (0.0..100.0).contains(&percent),
+            Slippage::Points(points) => points.is_finite() && points >= 0.0,
+        };
+        assert!(valid, "invalid slippage {self:?}");
+    }
+
+    pub(crate) fn apply(self, side: Side, price: f64) -> f64 {
+        let amount = match self {
+            Slippage::None => 0.0,
+            Slippage::Percent(percent) => price * percent / 100.0,
+            Slippage::Points(points) => points,
+        };
+
+        match side {
+            Side::Buy => price + amount,
+            Side::Sell => price - amount,
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! Client-side rate limiting.
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::time::Instant;
+
+use crate::endpoints::ApiKind;
+use crate::{FyersError, datetime};
+
+#[cfg(doc)]
+use crate::Fyers;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Request quotas for a single API.
+///
+/// The default matches the limits published by Fyers:
+///
+/// - Per second - 10
+/// - Per minute - 200
+/// - Per day - 1,00,000
+///
+/// The daily quota resets at midnight IST, as it does on the Fyers side.
+/// All limits must be greater than zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimits {
+    /// Maximum requests per second.
+    pub per_second: u32,
+    /// Maximum requests per minute.
+    pub per_minute: u32,
+    /// Maximum requests per day.
+    pub per_day: u32,
+}
+
+impl RateLimits {
+    /// Create custom quotas.
+    pub fn new(per_second: u32, per_minute: u32, per_day: u32) -> Self {
+        Self {
+            per_second,
+            per_minute,
+            per_day,
+        }
+    }
+}
+
+impl Default for RateLimits {
+    fn default() -> Self {
+        Self::new(10, 200, 100_000)
+    }
+}
+
+/// What to do when a request would exceed the quota.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitPolicy {
+    /// Wait until the request can be sent within the quota.
+    #[default]
+    Wait,
+
+    /// Return [`FyersError::LocalRateLimit`] immediately without sending
+    /// the request.
+    FailFast,
+}
+
+/// A token-bucket rate limiter for the Fyers API.
+///
+/// The second and minute windows are tracked by token buckets that refill
+/// continuously, while the daily quota is a count that resets at midnight
+/// IST. The data API (e.g. [`Fyers::history`]) and the order API
+/// (everything else) have separate sets of buckets.
+///
+/// The limiter is cheap to clone and all clones share the same buckets.
+/// It is attached to a client with [`Fyers::with_rate_limiter`], so every
+/// clone of that client draws from the same quota.
+///
+/// # Example
+///
+/// ```
+/// use fyers::{Fyers, RateLimitPolicy, RateLimiter, RateLimits};
+///
+/// let limiter = RateLimiter::builder()
+///     .order_limits(RateLimits::new(5, 100, 50_000))
+///     .policy(RateLimitPolicy::FailFast)
+///     .build();
+///
+/// let fyers = Fyers::new("CLIENT_ID", "ACCESS_TOKEN").with_rate_limiter(limiter);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    policy: RateLimitPolicy,
+    data: Arc<Mutex<Buckets>>,
+    orders: Arc<Mutex<Buckets>>,
+}
+
+/// Builder for creating a [`RateLimiter`].
+#[must_use = "builders must be finalized with .build()"]
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiterBuilder {
+    data: RateLimits,
+    orders: RateLimits,
+    policy: RateLimitPolicy,
+}
+
+impl RateLimiterBuilder {
+    /// Creates a builder with the default Fyers quotas for both APIs
+    /// and the [`RateLimitPolicy::Wait`] policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the quotas for the data API.
+    pub fn data_limits(mut self, limits: RateLimits) -> Self {
+        self.data = limits;
+        self
+    }
+
+    /// Set the quotas for the order API.
+    pub fn order_limits(mut self, limits: RateLimits) -> Self {
+        self.orders = limits;
+        self
+    }
+
+    /// Set what happens when the quota is exhausted.
+    ///
+    /// Defaults to [`RateLimitPolicy::Wait`].
+    pub fn policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Return a [`RateLimiter`] with the desired configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the limits is zero.
+    pub fn build(self) -> RateLimiter {
+        RateLimiter {
+            policy: self.policy,
+            data: Arc::new(Mutex::new(Buckets::new(self.data))),
+            orders: Arc::new(Mutex::new(Buckets::new(self.orders))),
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiterBuilder::new().build()
+    }
+}
+
+impl RateLimiter {
+    /// Create a limiter with the default Fyers quotas that waits when
+    /// the quota is exhausted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`RateLimiterBuilder`] to construct a [`RateLimiter`].
+    pub fn builder() -> RateLimiterBuilder {
+        RateLimiterBuilder::new()
+    }
+
+    // Take a permit for a single request, waiting or failing according
+    // to the policy.
+    pub(crate) async fn acquire(&self, api: ApiKind) -> Result<(), FyersError> {
+        let buckets = match api {
+            ApiKind::Data => &self.data,
+            ApiKind::Orders => &self.orders,
+        };
+
+        let wait = {
+            let mut buckets = buckets.lock().unwrap_or_else(|e| e.into_inner());
+            match self.policy {
+                RateLimitPolicy::Wait => buckets.reserve(Instant::now()),
+                RateLimitPolicy::FailFast => {
+                    if !buckets.try_take(Instant::now()) {
+                        return Err(FyersError::LocalRateLimit);
+                    }
+                    Duration::ZERO
+                }
+            }
+        };
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+
+        Ok(())
+    }
+}
+
+// The buckets for the second and minute windows of a single API, and
+// its daily quota.
+#[derive(Debug)]
+struct Buckets {
+    windows: [Bucket; 2],
+    day: DailyQuota,
+}
+
+impl Buckets {
+    fn new(limits: RateLimits) -> Self {
+        let now = Instant::now();
+        let until_midnight = (datetime::next_ist_midnight(Utc::now()) - Utc::now())
+            .to_std()
+            .unwrap_or_default();
+
+        Self {
+            windows: [
+                Bucket::new(limits.per_second, Duration::from_secs(1), now),
+                Bucket::new(limits.per_minute, Duration::from_secs(60), now),
+            ],
+            day: DailyQuota::new(limits.per_day, now + until_midnight),
+        }
+    }
+
+    // Take a token from every bucket, even if that puts it in debt,
+    // and return how long the caller has to wait before its token is
+    // actually available. Later callers queue up behind the debt.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let windows = self.windows.iter_mut().map(|bucket| {
+            bucket.refill(now);
+            bucket.tokens -= 1.0;
+            bucket.time_until_non_negative()
+        });
+
+        windows.max().unwrap_or_default().max(self.day.reserve(now))
+    }
+
+    // Take a token from every bucket only if all of them have one.
+    fn try_take(&mut self, now: Instant) -> bool {
+        for bucket in &mut self.windows {
+            bucket.refill(now);
+        }
+        self.day.reset(now);
+
+        if self.windows.iter().any(|bucket| bucket.tokens < 1.0) || self.day.used >= self.day.limit
+        {
+            return false;
+        }
+
+        for bucket in &mut self.windows {
+            bucket.tokens -= 1.0;
+        }
+        self.day.used += 1;
+
+        true
+    }
+}
+
+// The daily quota, which Fyers resets at midnight IST rather than
+// refilling over the day.
+#[derive(Debug)]
+struct DailyQuota {
+    limit: u64,
+    // Requests counted against the current day, more than `limit` when
+    // waiting callers have reserved requests on later days
+    used: u64,
+    resets_at: Instant,
+}
+
+impl DailyQuota {
+    fn new(limit: u32, resets_at: Instant) -> Self {
+        assert!(limit > 0, "rate limits must be greater than zero");
+
+        Self {
+            limit: u64::from(limit),
+            used: 0,
+            resets_at,
+        }
+    }
+
+    // Start a new day for every midnight that has passed. IST has no
+    // daylight saving, so days are always 24 hours long.
+    fn reset(&mut self, now: Instant) {
+        while now >= self.resets_at {
+            self.used = self.used.saturating_sub(self.limit);
+            self.resets_at += DAY;
+        }
+    }
+
+    // Count a request and return how long until the day it falls on.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        self.reset(now);
+        self.used += 1;
+
+        let days_ahead = (self.used - 1) / self.limit;
+        if days_ahead == 0 {
+            return Duration::ZERO;
+        }
+
+        let starts_at = self.resets_at + DAY * (days_ahead - 1) as u32;
+        starts_at.saturating_duration_since(now)
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    // Tokens added per second
+    rate: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn new(limit: u32, window: Duration, now: Instant) -> Self {
+        assert!(limit > 0, "rate limits must be greater than zero");
+
+        let capacity = f64::from(limit);
+
+        Self {
+            capacity,
+            tokens: capacity,
+            rate: capacity / window.as_secs_f64(),
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.rate).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    fn time_until_non_negative(&self) -> Duration {
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn daily_quota_resets_at_midnight() {
+        let now = Instant::now();
+        let mut day = DailyQuota::new(2, now + Duration::from_secs(60));
+
+        assert_eq!(day.reserve(now), Duration::ZERO);
+        assert_eq!(day.reserve(now), Duration::ZERO);
+
+        // Out of quota, so the next request waits for midnight rather
+        // than for a token to trickle back in
+        assert_eq!(day.reserve(now), Duration::from_secs(60));
+        assert_eq!(day.reserve(now), Duration::from_secs(60));
+        assert_eq!(day.reserve(now), Duration::from_secs(60) + DAY);
+
+        // Requests reserved for later days carry over
+        let midnight = now + Duration::from_secs(60);
+        day.reset(midnight);
+        assert_eq!(day.used, 3);
+        assert_eq!(day.resets_at, midnight + DAY);
+    }
+}
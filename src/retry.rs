@@ -0,0 +1,174 @@
+//! Retrying failed requests.
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::{Endpoint, FyersError, utils};
+
+#[cfg(doc)]
+use crate::{Fyers, OrderBuilder};
+
+/// How often and how fast to retry a failed request.
+///
+/// Only transient failures are retried:
+///
+/// - [`FyersError::Request`] (network errors and timeouts)
+/// - [`FyersError::HttpStatus`] with a 5xx status code
+/// - [`FyersError::RateLimited`] returned by Fyers
+///
+/// The delay before retry `n` (starting at 0) is
+/// `initial_backoff * 2^n`, capped at `max_backoff`. With jitter enabled,
+/// each delay is randomly reduced by up to half to spread out retries
+/// from concurrent workers.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use fyers::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(3)
+///     .initial_backoff(Duration::from_millis(200))
+///     .max_backoff(Duration::from_secs(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Create a policy that retries up to `max_retries` times.
+    ///
+    /// Defaults:
+    ///
+    /// - Initial backoff: **100 ms**
+    /// - Maximum backoff: **5 seconds**
+    /// - Jitter: **enabled**
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self::new(0)
+    }
+
+    /// Set the delay before the first retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the maximum delay between two attempts.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Enable or disable jitter.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Maximum number of retries after the first attempt.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    // Delay before the given retry (starting at 0)
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff);
+
+        if self.jitter {
+            exponential.mul_f64(0.5 + utils::random_f64() / 2.0)
+        } else {
+            exponential
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Retry up to 3 times.
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+/// Retry configuration for a [`Fyers`] client.
+///
+/// A default [`RetryPolicy`] applies to every endpoint unless overridden
+/// with [`RetryConfig::endpoint`].
+///
+/// Requests to endpoints that aren't [idempotent](Endpoint::is_idempotent)
+/// are never retried blindly. For [`Fyers::place_order`], a failed attempt
+/// is only retried after checking the order book for an order with the
+/// same tag (see [`OrderBuilder::order_tag`]). If one is found, it is
+/// returned instead of placing the order again. Orders without a tag are
+/// not retried, except when Fyers rejected them for exceeding the rate
+/// limit.
+///
+/// # Example
+///
+/// ```
+/// use fyers::{Endpoint, Fyers, RetryConfig, RetryPolicy};
+///
+/// let retry = RetryConfig::new(RetryPolicy::new(3))
+///     .endpoint(Endpoint::History, RetryPolicy::new(5));
+///
+/// let fyers = Fyers::new("CLIENT_ID", "ACCESS_TOKEN").with_retry_config(retry);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RetryConfig {
+    default: RetryPolicy,
+    endpoints: HashMap<Endpoint, RetryPolicy>,
+}
+
+impl RetryConfig {
+    /// Use `policy` for every endpoint.
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self {
+            default: policy,
+            endpoints: HashMap::new(),
+        }
+    }
+
+    /// Do not retry anything.
+    pub fn none() -> Self {
+        Self::new(RetryPolicy::none())
+    }
+
+    /// Override the policy for a single endpoint.
+    pub fn endpoint(mut self, endpoint: Endpoint, policy: RetryPolicy) -> Self {
+        self.endpoints.insert(endpoint, policy);
+        self
+    }
+
+    /// The policy that applies to `endpoint`.
+    pub fn policy(&self, endpoint: Endpoint) -> RetryPolicy {
+        self.endpoints
+            .get(&endpoint)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+// Whether the error is worth retrying
+pub(crate) fn is_transient(error: &FyersError) -> bool {
+    match error {
+        FyersError::Request(e) => !e.is_builder(),
+        FyersError::HttpStatus { status, .. } => status.is_server_error(),
+        FyersError::RateLimited => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,329 @@
+//! Client-side order slicing.
+//!
+//! Exchanges reject orders for an instrument's freeze quantity or more.
+//! [`OrderBuilder::slice_order`] leaves the split to Fyers, while
+//! [`OrderSlicer`] does it on the client: the child orders are known
+//! before anything is sent, get tags sharing a prefix, and can be spaced
+//! out in time.
+//!
+//! Slices are as large as the freeze quantity allows, in whole lots, with
+//! the remainder in the last one. With
+//! [`random_sizes`](OrderSlicer::random_sizes), each slice is between half
+//! and all of that size instead.
+//!
+//! # Example
+//!
+//! ```
+//! use fyers::slicing::OrderSlicer;
+//! use fyers::{InstrumentInfo, OrderRequest, ProductType, Side};
+//!
+//! # fn run() -> Result<(), fyers::FyersError> {
+//! let nifty = InstrumentInfo::new(75, 0.05).with_freeze_qty(1801);
+//! let order = OrderRequest::market("NSE:NIFTY25JANFUT", 3750, Side::Buy, ProductType::Margin)
+//!     .order_tag("rebal")
+//!     .build();
+//!
+//! let slices = OrderSlicer::new(nifty).slice(&order)?;
+//! assert_eq!(slices.len(), 3);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use crate::models::orders::order_validation;
+use crate::{Broker, FyersError, InstrumentInfo, Order, OrderRequest, utils};
+
+#[cfg(doc)]
+use crate::{Fyers, OrderBuilder, RateLimiter};
+
+// Fyers counts its order quota per second, so a rate limited slice is
+// retried after that long
+const RATE_LIMIT_WAIT: Duration = Duration::from_secs(1);
+const RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Splits an [`OrderRequest`] into child orders below the freeze quantity
+/// of its instrument.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct OrderSlicer {
+    instrument: InstrumentInfo,
+    random_sizes: bool,
+    delay: Duration,
+    random_delays: bool,
+    tag_prefix: Option<String>,
+}
+
+/// A child order placed by [`OrderSlicer::place`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliceOrder {
+    /// Order ID
+    pub id: String,
+    /// Quantity of the slice
+    pub qty: u32,
+    /// Tag of the slice
+    pub tag: Option<String>,
+}
+
+/// The outcome of [`OrderSlicer::place`].
+#[derive(Debug)]
+pub struct SliceReport {
+    /// Quantity of the original order
+    pub qty: u32,
+    /// The slices placed, in order
+    pub orders: Vec<SliceOrder>,
+    /// The error that stopped the remaining slices from being placed
+    pub error: Option<FyersError>,
+}
+
+/// Aggregate fill status of the slices of an order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SliceFillStatus {
+    /// Quantity of the original order
+    pub qty: u32,
+    /// Quantity filled across all slices
+    pub filled_qty: u32,
+    /// Quantity still working in open slices
+    pub pending_qty: u32,
+    /// Average fill price across all slices
+    pub avg_price: f64,
+}
+
+impl OrderSlicer {
+    /// Create a slicer for orders of `instrument`.
+    ///
+    /// Without a [`freeze_qty`](InstrumentInfo::freeze_qty), orders
+    /// aren't split.
+    pub fn new(instrument: InstrumentInfo) -> Self {
+        Self {
+            instrument,
+            random_sizes: false,
+            delay: Duration::ZERO,
+            random_delays: false,
+            tag_prefix: None,
+        }
+    }
+
+    /// Make each slice a random number of lots, between half and all of
+    /// the largest allowed slice.
+    pub fn random_sizes(mut self, value: bool) -> Self {
+        self.random_sizes = value;
+        self
+    }
+
+    /// Wait `delay` between placing slices.
+    ///
+    /// Defaults to no delay.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Wait a random time between half and all of the
+    /// [`delay`](OrderSlicer::delay) between slices.
+    pub fn random_delays(mut self, value: bool) -> Self {
+        self.random_delays = value;
+        self
+    }
+
+    /// Tag the slices `{prefix}1`, `{prefix}2` and so on.
+    ///
+    /// Defaults to the tag of the original order, if it has one. Order
+    /// tags are letters and digits only, so the prefix should be too.
+    pub fn tag_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.tag_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Split `order` into child orders.
+    ///
+    /// Each child is checked like [`OrderBuilder::try_build`] against the
+    /// instrument, so a quantity that isn't a whole number of lots or an
+    /// invalid tag is returned as [`FyersError::InvalidOrder`].
+    pub fn slice(&self, order: &OrderRequest) -> Result<Vec<OrderRequest>, FyersError> {
+        let prefix = self.tag_prefix.as_deref().or(order.order_tag.as_deref());
+
+        let mut slices = Vec::new();
+
+        for (n, qty) in self.sizes(order.qty)?.into_iter().enumerate() {
+            let slice = OrderRequest {
+                qty,
+                disclosed_qty: order.disclosed_qty.min(qty),
+                order_tag: prefix.map(|prefix| format!("{prefix}{}", n + 1)),
+                is_slice_order: false,
+                ..order.clone()
+            };
+
+            order_validation::validate(&slice, Some(&self.instrument))?;
+            slices.push(slice);
+        }
+
+        Ok(slices)
+    }
+
+    /// Split `order` and place the slices one after the other.
+    ///
+    /// A slice rejected with [`FyersError::RateLimited`] is retried after
+    /// a second, up to three times. Attach a [`RateLimiter`] to [`Fyers`]
+    /// to stay within the quota in the first place.
+    ///
+    /// Any other error stops the remaining slices from being placed, and
+    /// is returned in [`SliceReport::error`] along with the slices placed
+    /// before it.
+    pub async fn place<B: Broker>(
+        &self,
+        broker: &B,
+        order: &OrderRequest,
+    ) -> Result<SliceReport, FyersError> {
+        let slices = self.slice(order)?;
+
+        let mut report = SliceReport {
+            qty: order.qty,
+            orders: Vec::new(),
+            error: None,
+        };
+
+        for (n, slice) in slices.into_iter().enumerate() {
+            if n > 0 {
+                tokio::time::sleep(self.next_delay()).await;
+            }
+
+            match place_slice(broker, &slice).await {
+                Ok(placed) => report.orders.push(SliceOrder {
+                    id: placed.id,
+                    qty: slice.qty,
+                    tag: slice.order_tag,
+                }),
+                Err(err) => {
+                    report.error = Some(err);
+                    break;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    // Slice quantities adding up to `qty`
+    fn sizes(&self, qty: u32) -> Result<Vec<u32>, FyersError> {
+        let lot_size = self.instrument.lot_size.max(1);
+
+        let max = match self.instrument.freeze_qty {
+            // The largest whole number of lots below the freeze quantity
+            Some(freeze_qty) => freeze_qty.saturating_sub(1) / lot_size * lot_size,
+            None => qty,
+        };
+
+        if max == 0 && qty > 0 {
+            return Err(FyersError::InvalidParams(
+                "freeze quantity is smaller than the lot size".into(),
+            ));
+        }
+
+        let mut sizes = Vec::new();
+        let mut remaining = qty;
+
+        while remaining > max {
+            let size = if self.random_sizes {
+                let lots = max / lot_size;
+                let min_lots = lots.div_ceil(2);
+                let extra = (utils::random_f64() * (lots - min_lots + 1) as f64) as u32;
+                (min_lots + extra).min(lots) * lot_size
+            } else {
+                max
+            };
+
+            sizes.push(size);
+            remaining -= size;
+        }
+
+        sizes.push(remaining);
+        Ok(sizes)
+    }
+
+    fn next_delay(&self) -> Duration {
+        if self.random_delays {
+            self.delay.mul_f64(0.5 + utils::random_f64() / 2.0)
+        } else {
+            self.delay
+        }
+    }
+}
+
+// Place a slice, retrying it while it is rate limited
+pub(crate) async fn place_slice<B: Broker>(
+    broker: &B,
+    slice: &OrderRequest,
+) -> Result<Order, FyersError> {
+    let mut retries = 0;
+
+    loop {
+        match broker.place_order(slice).await {
+            Err(FyersError::RateLimited) if retries < RATE_LIMIT_RETRIES => {
+                tokio::time::sleep(RATE_LIMIT_WAIT).await;
+                retries += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+impl SliceReport {
+    /// Total quantity of the slices placed.
+    pub fn placed_qty(&self) -> u32 {
+        self.orders.iter().map(|o| o.qty).sum()
+    }
+
+    /// Whether every slice was placed.
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none() && self.placed_qty() == self.qty
+    }
+
+    /// Look up the slices in the order book and add up their fills.
+    pub async fn fill_status<B: Broker>(&self, broker: &B) -> Result<SliceFillStatus, FyersError> {
+        let book = broker.orders().await?;
+
+        let mut filled_qty = 0;
+        let mut pending_qty = 0;
+        let mut value = 0.0;
+
+        for slice in &self.orders {
+            let Some(order) = book.iter().find(|o| o.id == slice.id) else {
+                continue;
+            };
+
+            filled_qty += order.filled_qty;
+            value += order.filled_qty as f64 * order.traded_price;
+
+            if order.status.is_open() {
+                pending_qty += order.qty.saturating_sub(order.filled_qty);
+            }
+        }
+
+        let avg_price = if filled_qty > 0 {
+            value / filled_qty as f64
+        } else {
+            0.0
+        };
+
+        Ok(SliceFillStatus {
+            qty: self.qty,
+            filled_qty,
+            pending_qty,
+            avg_price,
+        })
+    }
+}
+
+impl SliceFillStatus {
+    /// Quantity that was cancelled, rejected, expired or never placed.
+    pub fn unfilled_qty(&self) -> u32 {
+        self.qty.saturating_sub(self.filled_qty + self.pending_qty)
+    }
+
+    /// Whether the whole quantity has filled.
+    pub fn is_filled(&self) -> bool {
+        self.filled_qty == self.qty
+    }
+}
@@ -0,0 +1,296 @@
+//! A fake Fyers server for testing code built on this crate.
+//!
+//! [`FakeServer`] starts a local HTTP server that answers every endpoint
+//! implemented by [`Fyers`] with a canned successful response. Errors can
+//! be injected per endpoint to check how your code handles expired tokens,
+//! rate limits and server failures.
+//!
+//! Requires the `testing` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use fyers::Endpoint;
+//! use fyers::testing::{FakeServer, Fault};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let server = FakeServer::start().await;
+//! let fyers = server.client();
+//!
+//! assert!(fyers.profile().await.is_ok());
+//!
+//! server.fail_next(Endpoint::Profile, Fault::TokenExpired);
+//! assert!(fyers.profile().await.is_err());
+//! assert!(fyers.profile().await.is_ok());
+//!
+//! assert_eq!(server.requests(Endpoint::Profile), 3);
+//! # }
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use httpmock::{HttpMockRequest, HttpMockResponse, MockServer};
+use serde_json::json;
+
+use crate::{Endpoint, Fyers, FyersBuilder};
+
+mod responses;
+
+/// Client ID used by clients of a [`FakeServer`].
+pub const CLIENT_ID: &str = "FAKE-100";
+
+/// Access token used by clients of a [`FakeServer`].
+pub const ACCESS_TOKEN: &str = "FAKE_ACCESS_TOKEN";
+
+/// An error the [`FakeServer`] can respond with instead of the canned
+/// response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Fault {
+    /// Reported as [`FyersError::TokenExpired`](crate::FyersError::TokenExpired).
+    TokenExpired,
+
+    /// Reported as [`FyersError::InvalidToken`](crate::FyersError::InvalidToken).
+    InvalidToken,
+
+    /// Reported as [`FyersError::RateLimited`](crate::FyersError::RateLimited).
+    RateLimited,
+
+    /// An HTTP error status without an API error body, like a `503` from
+    /// a load balancer.
+    ServerError(u16),
+
+    /// Any other API error.
+    Api {
+        /// Fyers error code
+        code: i32,
+        /// Error message
+        message: String,
+    },
+}
+
+impl Fault {
+    fn response(&self) -> (u16, String) {
+        let api_error = |code: i32, message: &str| {
+            json!({ "s": "error", "code": code, "message": message }).to_string()
+        };
+
+        match self {
+            Fault::TokenExpired => (
+                401,
+                api_error(-8, "Your token has expired. Please generate a token"),
+            ),
+            Fault::InvalidToken => (401, api_error(-16, "Could not authenticate the user")),
+            Fault::RateLimited => (429, api_error(-429, "request limit reached")),
+            Fault::ServerError(status) => (*status, "Internal Server Error".into()),
+            Fault::Api { code, message } => (400, api_error(*code, message)),
+        }
+    }
+}
+
+/// A local fake Fyers server.
+///
+/// Every endpoint responds with a canned success response until told
+/// otherwise with [`set_response`](FakeServer::set_response) or one of the
+/// fault injection methods. The server doesn't keep any state between
+/// requests, so placing an order doesn't add it to the order book.
+///
+/// The server shuts down when dropped.
+pub struct FakeServer {
+    server: MockServer,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    // Faults used up one request at a time
+    next: HashMap<Endpoint, VecDeque<Fault>>,
+    // Faults returned until cleared
+    always: HashMap<Endpoint, Fault>,
+    // Responses used up one request at a time
+    next_responses: HashMap<Endpoint, VecDeque<(u16, String)>>,
+    responses: HashMap<Endpoint, (u16, String)>,
+    requests: HashMap<Endpoint, usize>,
+}
+
+impl FakeServer {
+    /// Start a fake server on a free local port.
+    pub async fn start() -> Self {
+        let server = MockServer::start_async().await;
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let handler_state = state.clone();
+        server
+            .mock_async(move |when, then| {
+                when.any_request();
+                then.respond_with(move |req| handle(&handler_state, req));
+            })
+            .await;
+
+        Self { server, state }
+    }
+
+    /// A [`Fyers`] client that talks to this server.
+    pub fn client(&self) -> Fyers {
+        self.builder()
+            .build()
+            .expect("fake client configuration is valid")
+    }
+
+    /// A [`FyersBuilder`] pointed at this server, for configuring retries,
+    /// rate limiting or token refresh before building the client.
+    pub fn builder(&self) -> FyersBuilder {
+        Fyers::builder(CLIENT_ID, ACCESS_TOKEN)
+            .api_url(self.api_url())
+            .data_url(self.data_url())
+    }
+
+    /// The base URL of the trading API on this server.
+    pub fn api_url(&self) -> String {
+        self.server.url("/api/v3")
+    }
+
+    /// The base URL of the data API on this server.
+    pub fn data_url(&self) -> String {
+        self.server.url("/data")
+    }
+
+    /// Respond to the next request to `endpoint` with `fault`.
+    ///
+    /// Call this several times to script a sequence of failures. Once
+    /// they are used up, the endpoint responds normally again.
+    pub fn fail_next(&self, endpoint: Endpoint, fault: Fault) {
+        self.state()
+            .next
+            .entry(endpoint)
+            .or_default()
+            .push_back(fault);
+    }
+
+    /// Respond to the next `times` requests to `endpoint` with `fault`.
+    pub fn fail_times(&self, endpoint: Endpoint, fault: Fault, times: usize) {
+        let mut state = self.state();
+        let queue = state.next.entry(endpoint).or_default();
+        queue.extend(std::iter::repeat_n(fault, times));
+    }
+
+    /// Respond to every request to `endpoint` with `fault` until
+    /// [`clear_faults`](FakeServer::clear_faults) is called.
+    pub fn fail_always(&self, endpoint: Endpoint, fault: Fault) {
+        self.state().always.insert(endpoint, fault);
+    }
+
+    /// Remove all injected faults.
+    pub fn clear_faults(&self) {
+        let mut state = self.state();
+        state.next.clear();
+        state.always.clear();
+    }
+
+    /// Replace the canned response of `endpoint`.
+    pub fn set_response(&self, endpoint: Endpoint, status: u16, body: impl Into<String>) {
+        self.state()
+            .responses
+            .insert(endpoint, (status, body.into()));
+    }
+
+    /// Respond to the next request to `endpoint` with `body` instead of
+    /// the canned response.
+    ///
+    /// Call this several times to script a sequence of responses, such as
+    /// an order that is open and then filled. Faults take precedence.
+    pub fn respond_next(&self, endpoint: Endpoint, status: u16, body: impl Into<String>) {
+        self.state()
+            .next_responses
+            .entry(endpoint)
+            .or_default()
+            .push_back((status, body.into()));
+    }
+
+    /// Number of requests received for `endpoint`.
+    pub fn requests(&self, endpoint: Endpoint) -> usize {
+        self.state()
+            .requests
+            .get(&endpoint)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl std::fmt::Debug for FakeServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FakeServer")
+            .field("address", self.server.address())
+            .finish_non_exhaustive()
+    }
+}
+
+fn handle(state: &Mutex<State>, req: &HttpMockRequest) -> HttpMockResponse {
+    let Some(endpoint) = route(req) else {
+        return respond(
+            404,
+            json!({ "s": "error", "code": 404, "message": "not found" }).to_string(),
+        );
+    };
+
+    let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+    *state.requests.entry(endpoint).or_default() += 1;
+
+    let fault = state
+        .next
+        .get_mut(&endpoint)
+        .and_then(VecDeque::pop_front)
+        .or_else(|| state.always.get(&endpoint).cloned());
+
+    let (status, body) = match fault {
+        Some(fault) => fault.response(),
+        None => state
+            .next_responses
+            .get_mut(&endpoint)
+            .and_then(VecDeque::pop_front)
+            .or_else(|| state.responses.get(&endpoint).cloned())
+            .unwrap_or_else(|| (200, responses::canned(endpoint).to_string())),
+    };
+
+    respond(status, body)
+}
+
+// Map a request to the endpoint it belongs to
+//
+// Add a route here for each new endpoint.
+fn route(req: &HttpMockRequest) -> Option<Endpoint> {
+    let path = req.uri().path().to_owned();
+
+    let endpoint = match (req.method_str(), path.as_str()) {
+        ("GET", "/api/v3/profile") => Endpoint::Profile,
+        ("GET", "/data/history") => Endpoint::History,
+        ("GET", "/data/quotes") => Endpoint::Quotes,
+        ("GET", "/api/v3/orders") => Endpoint::Orders,
+        ("POST", "/api/v3/orders/sync") => Endpoint::PlaceOrder,
+        ("PATCH", "/api/v3/orders/sync") => Endpoint::ModifyOrder,
+        ("DELETE", "/api/v3/orders/sync") => Endpoint::CancelOrder,
+        ("GET", "/api/v3/positions") => Endpoint::Positions,
+        ("DELETE", "/api/v3/positions") if req.body_string().contains("exit_all") => {
+            Endpoint::ExitAllPositions
+        }
+        ("DELETE", "/api/v3/positions") => Endpoint::CancelPendingOrders,
+        ("POST", "/api/v3/logout") => Endpoint::Logout,
+        _ => return None,
+    };
+
+    Some(endpoint)
+}
+
+fn respond(status: u16, body: String) -> HttpMockResponse {
+    HttpMockResponse::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(body)
+        .build()
+}
@@ -0,0 +1,180 @@
+use serde_json::{Value, json};
+
+use crate::Endpoint;
+
+// The canned success response of an endpoint
+pub(super) fn canned(endpoint: Endpoint) -> Value {
+    match endpoint {
+        Endpoint::Profile => profile(),
+        Endpoint::History => history(),
+        Endpoint::Quotes => quotes(),
+        Endpoint::Orders => order_book(),
+        Endpoint::PlaceOrder => json!({
+            "s": "ok",
+            "code": 1101,
+            "message": "Order submitted successfully. Your Order Ref. No.808058117761",
+            "id": "808058117761",
+        }),
+        Endpoint::ModifyOrder => json!({
+            "s": "ok",
+            "code": 1102,
+            "message": "Successfully modified order",
+            "id": "808058117761",
+        }),
+        Endpoint::Positions => positions(),
+        Endpoint::CancelOrder => json!({
+            "s": "ok",
+            "code": 1103,
+            "message": "Successfully cancelled order",
+            "id": "808058117761",
+        }),
+        Endpoint::CancelPendingOrders => json!({
+            "s": "ok",
+            "code": 200,
+            "message": "Pending orders cancelled.",
+        }),
+        Endpoint::ExitAllPositions => json!({
+            "s": "ok",
+            "code": 200,
+            "message": "The position is closed.",
+        }),
+        Endpoint::Logout => json!({
+            "s": "ok",
+            "code": 200,
+            "message": "you are successfully logged out",
+        }),
+    }
+}
+
+fn quotes() -> Value {
+    json!({
+        "s": "ok",
+        "code": 200,
+        "d": [{
+            "n": "NSE:SBIN-EQ",
+            "s": "ok",
+            "v": {
+                "ch": 1.2,
+                "chp": 0.21,
+                "lp": 581.2,
+                "spread": 0.1,
+                "ask": 581.25,
+                "bid": 581.15,
+                "open_price": 579.0,
+                "high_price": 583.4,
+                "low_price": 578.1,
+                "prev_close_price": 580.0,
+                "volume": 1520340,
+                "short_name": "SBIN-EQ",
+                "exchange": "NSE",
+                "description": "NSE:SBIN-EQ",
+                "original_name": "NSE:SBIN-EQ",
+                "symbol": "NSE:SBIN-EQ",
+                "fyToken": "10100000003045",
+                "tt": "1735704000"
+            }
+        }]
+    })
+}
+
+fn profile() -> Value {
+    json!({
+        "s": "ok",
+        "code": 200,
+        "message": "",
+        "data": {
+            "name": "FAKE USER",
+            "image": null,
+            "display_name": "FAKE",
+            "email_id": "fake@example.com",
+            "PAN": "FAKEX0000X",
+            "fy_id": "FAKE01",
+            "pin_change_date": "01-01-2025 09:00:00",
+            "mobile_number": "9999999999",
+            "totp": true,
+            "pwd_change_date": "01-01-2025 09:00:00",
+            "pwd_to_expire": 90,
+            "ddpi_enabled": false,
+            "mtf_enabled": false,
+        },
+    })
+}
+
+fn history() -> Value {
+    json!({
+        "s": "ok",
+        "candles": [
+            [1609459200, 274.9, 280.0, 274.4, 279.4, 24531791],
+            [1609718400, 281.85, 283.9, 277.75, 281.05, 31450608],
+            [1609804800, 278.05, 282.45, 277.0, 281.75, 27393072],
+            [1609891200, 283.0, 289.15, 281.4, 285.05, 40765708],
+        ],
+    })
+}
+
+fn order_book() -> Value {
+    json!({
+        "s": "ok",
+        "code": 200,
+        "message": "",
+        "orderBook": [
+            {
+                "id": "808058117761",
+                "exchOrdId": "1100000009596016",
+                "symbol": "NSE:SBIN-EQ",
+                "qty": 1,
+                "remainingQuantity": 0,
+                "filledQty": 1,
+                "disclosedQty": 0,
+                "limitPrice": 0,
+                "stopPrice": 0,
+                "tradedPrice": 580.5,
+                "type": 2,
+                "side": 1,
+                "productType": "INTRADAY",
+                "orderValidity": "DAY",
+                "status": 2,
+                "offlineOrder": false,
+                "orderDateTime": "01-Jan-2025 09:15:00",
+                "message": "",
+                "orderTag": "1:fake",
+            },
+        ],
+    })
+}
+
+fn positions() -> Value {
+    json!({
+        "s": "ok",
+        "code": 200,
+        "message": "",
+        "netPositions": [
+            {
+                "id": "NSE:SBIN-EQ-INTRADAY",
+                "symbol": "NSE:SBIN-EQ",
+                "productType": "INTRADAY",
+                "netQty": 1,
+                "netAvg": 580.5,
+                "avgPrice": 580.5,
+                "side": 1,
+                "buyQty": 1,
+                "buyAvg": 580.5,
+                "buyVal": 580.5,
+                "sellQty": 0,
+                "sellAvg": 0,
+                "sellVal": 0,
+                "realized_profit": 0,
+                "unrealized_profit": 2.5,
+                "pl": 2.5,
+                "ltp": 583.0,
+            },
+        ],
+        "overall": {
+            "count_total": 1,
+            "count_open": 1,
+            "pl_total": 2.5,
+            "pl_realized": 0,
+            "pl_unrealized": 2.5,
+        },
+    })
+}
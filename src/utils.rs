@@ -1,3 +1,7 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
 use serde::de::DeserializeOwned;
 
 use crate::FyersError;
@@ -14,3 +18,51 @@ where
 
     Ok(T::deserialize(value)?)
 }
+
+// Random number in [0, 1).
+//
+// Not suitable for anything security related, but good enough for
+// jittering delays without pulling in a dependency.
+pub(crate) fn random_f64() -> f64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// Replace the file at `path` with `contents`, readable only by the
+// current user on Unix.
+//
+// The contents are written to a temporary file and synced before it is
+// renamed over `path`, so a crash never leaves a half-written or empty
+// file behind.
+pub(crate) fn write_file_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut tmp = path.to_path_buf().into_os_string();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+
+    write_private(&tmp, contents)?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(unix)]
+fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+#[cfg(not(unix))]
+fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut file = fs::File::create(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
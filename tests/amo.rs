@@ -0,0 +1,179 @@
+mod common;
+
+use chrono::{NaiveDate, NaiveTime};
+use fyers::amo::{MarketCalendar, MarketPhase, Session};
+use fyers::charges::Segment;
+use fyers::{Entry, OrderRequest, ProductType, Side, ist_datetime};
+use httpmock::prelude::*;
+
+const ORDER_SUCCESS: &str = include_str!("fixtures/order_success.json");
+const CANCEL_SUCCESS: &str = include_str!("fixtures/order_cancel_success.json");
+const ORDER_BOOK: &str = include_str!("fixtures/orders/order_book_amo.json");
+
+// A calendar in which NSE equity only ever accepts AMOs
+fn always_amo() -> MarketCalendar {
+    let midnight = NaiveTime::MIN;
+    let mut calendar = MarketCalendar::empty();
+    calendar.set_session(
+        Segment::NseEquity,
+        Session {
+            open: midnight,
+            close: midnight,
+            amo_start: midnight,
+            amo_end: midnight,
+        },
+    );
+    calendar
+}
+
+#[test]
+fn phase_follows_session_times() {
+    let calendar = MarketCalendar::fyers();
+    let phase = |h, m| calendar.phase(Segment::NseEquity, ist_datetime(2025, 1, 2, h, m));
+
+    assert_eq!(phase(8, 30), Some(MarketPhase::Amo));
+    assert_eq!(phase(8, 58), Some(MarketPhase::Closed));
+    assert_eq!(phase(9, 0), Some(MarketPhase::Open));
+    assert_eq!(phase(15, 29), Some(MarketPhase::Open));
+    assert_eq!(phase(15, 30), Some(MarketPhase::Closed));
+    assert_eq!(phase(15, 45), Some(MarketPhase::Amo));
+
+    // Commodities trade into the evening
+    let time = ist_datetime(2025, 1, 2, 20, 0);
+    assert_eq!(
+        calendar.phase(Segment::CommodityFutures, time),
+        Some(MarketPhase::Open)
+    );
+
+    assert_eq!(
+        MarketCalendar::empty().phase(Segment::NseEquity, time),
+        None
+    );
+}
+
+#[test]
+fn weekends_and_holidays_accept_amos_all_day() {
+    let holiday = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
+    let calendar = MarketCalendar::fyers().with_holidays([holiday]);
+
+    assert!(!calendar.is_trading_day(holiday));
+    assert_eq!(
+        calendar.phase(Segment::NseEquity, ist_datetime(2025, 1, 2, 11, 0)),
+        Some(MarketPhase::Amo)
+    );
+
+    // Saturday
+    assert_eq!(
+        calendar.phase(Segment::NseFutures, ist_datetime(2025, 1, 4, 11, 0)),
+        Some(MarketPhase::Amo)
+    );
+}
+
+#[test]
+fn next_accepting_skips_closed_periods() {
+    let calendar = MarketCalendar::fyers();
+
+    let evening = ist_datetime(2025, 1, 2, 15, 35);
+    assert_eq!(
+        calendar.next_accepting(Segment::NseEquity, evening),
+        Some(ist_datetime(2025, 1, 2, 15, 45))
+    );
+
+    let morning = ist_datetime(2025, 1, 2, 9, 0);
+    assert_eq!(
+        calendar.next_accepting(Segment::NseOptions, morning),
+        Some(ist_datetime(2025, 1, 2, 9, 15))
+    );
+
+    let open = ist_datetime(2025, 1, 2, 10, 0);
+    assert_eq!(
+        calendar.next_accepting(Segment::NseEquity, open),
+        Some(open)
+    );
+}
+
+#[tokio::test]
+async fn auto_amo_marks_orders_placed_in_the_amo_window() {
+    let ctx = common::setup().await;
+    let fyers = ctx.fyers.with_auto_amo(always_amo());
+
+    let order = OrderRequest::market("NSE:SBIN-EQ", 1, Side::Buy, ProductType::Cnc).build();
+    let expected = OrderRequest::market("NSE:SBIN-EQ", 1, Side::Buy, ProductType::Cnc)
+        .offline_order(true)
+        .build();
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/orders/sync")
+                .json_body_obj(&expected);
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(ORDER_SUCCESS);
+        })
+        .await;
+
+    fyers.place_order(&order).await.unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn auto_amo_leaves_bracket_orders_alone() {
+    let ctx = common::setup().await;
+    let fyers = ctx.fyers.with_auto_amo(always_amo());
+
+    let order =
+        OrderRequest::bracket("NSE:SBIN-EQ", 1, Side::Buy, Entry::Market, 5.0, 10.0).build();
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(POST).path("/orders/sync").json_body_obj(&order);
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(ORDER_SUCCESS);
+        })
+        .await;
+
+    fyers.place_order(&order).await.unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn cancel_amo_orders_cancels_only_pending_amos() {
+    let ctx = common::setup().await;
+
+    ctx.server
+        .mock_async(|when, then| {
+            when.method(GET).path("/orders");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(ORDER_BOOK);
+        })
+        .await;
+
+    let cancel = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(DELETE)
+                .path("/orders/sync")
+                .json_body_obj(&serde_json::json!({ "id": "23080400089360" }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(CANCEL_SUCCESS);
+        })
+        .await;
+
+    let amos = ctx.fyers.amo_orders().await.unwrap();
+    assert_eq!(amos.len(), 1);
+    assert_eq!(amos[0].id, "23080400089360");
+
+    let cancelled = ctx.fyers.cancel_amo_orders().await.unwrap();
+    assert_eq!(cancelled, ["23080400089360"]);
+    cancel.assert();
+}
@@ -0,0 +1,173 @@
+use fyers::auth::{AuthClient, AuthError, RedirectListener, Tokens};
+use httpmock::prelude::*;
+use serde_json::json;
+
+const VALIDATE_AUTHCODE_SUCCESS: &str =
+    include_str!("fixtures/auth/validate_authcode_success.json");
+const INVALID_AUTH_CODE: &str = include_str!("fixtures/auth/invalid_auth_code.json");
+const REFRESH_TOKEN_SUCCESS: &str = include_str!("fixtures/auth/refresh_token_success.json");
+const LOGOUT_SUCCESS: &str = include_str!("fixtures/logout_success.json");
+
+// SHA-256 of "APPID-100:SECRET_KEY"
+const APP_ID_HASH: &str = "bb984a342d0e115172aa144be74b87831c4c71eae00c595e5a6d85044c898325";
+
+fn auth_client(server: &MockServer) -> AuthClient {
+    AuthClient::new("APPID-100", "SECRET_KEY").base_url(server.base_url())
+}
+
+#[tokio::test]
+async fn generate_url_uses_base_url() {
+    let server = MockServer::start_async().await;
+
+    let url = auth_client(&server)
+        .generate_url("https://example.com/redirect", "STATE")
+        .unwrap();
+
+    assert!(url.as_str().starts_with(&server.url("/generate-authcode")));
+    assert!(
+        url.query_pairs()
+            .any(|(k, v)| k == "client_id" && v == "APPID-100")
+    );
+    assert!(url.query_pairs().any(|(k, v)| k == "state" && v == "STATE"));
+}
+
+#[tokio::test]
+async fn generate_tokens_success() {
+    let server = MockServer::start_async().await;
+
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/validate-authcode")
+                .json_body(json!({
+                    "grant_type": "authorization_code",
+                    "appIdHash": APP_ID_HASH,
+                    "code": "AUTH_CODE",
+                }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(VALIDATE_AUTHCODE_SUCCESS);
+        })
+        .await;
+
+    let tokens = auth_client(&server)
+        .generate_tokens("https://example.com/redirect?s=ok&auth_code=AUTH_CODE")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        tokens,
+        Tokens {
+            access_token: "ACCESS_TOKEN".into(),
+            refresh_token: "REFRESH_TOKEN".into(),
+        }
+    );
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn generate_tokens_api_error() {
+    let server = MockServer::start_async().await;
+
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/validate-authcode");
+
+            then.status(400)
+                .header("content-type", "application/json")
+                .body(INVALID_AUTH_CODE);
+        })
+        .await;
+
+    let err = auth_client(&server)
+        .generate_tokens("https://example.com/redirect?auth_code=EXPIRED")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, AuthError::Api { code: -413, .. }));
+}
+
+#[tokio::test]
+async fn refresh_tokens_keeps_refresh_token() {
+    let server = MockServer::start_async().await;
+
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/validate-refresh-token")
+                .json_body(json!({
+                    "grant_type": "refresh_token",
+                    "appIdHash": APP_ID_HASH,
+                    "refresh_token": "REFRESH_TOKEN",
+                    "pin": "1234",
+                }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(REFRESH_TOKEN_SUCCESS);
+        })
+        .await;
+
+    let tokens = auth_client(&server)
+        .refresh_tokens("REFRESH_TOKEN", "1234")
+        .await
+        .unwrap();
+
+    assert_eq!(tokens.access_token, "NEW_ACCESS_TOKEN");
+    assert_eq!(tokens.refresh_token, "REFRESH_TOKEN");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn logout_sends_access_token() {
+    let server = MockServer::start_async().await;
+
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/logout")
+                .header("Authorization", "APPID-100:ACCESS_TOKEN");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(LOGOUT_SUCCESS);
+        })
+        .await;
+
+    auth_client(&server).logout("ACCESS_TOKEN").await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn redirect_listener_exchanges_auth_code() {
+    let server = MockServer::start_async().await;
+
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/validate-authcode")
+                .json_body_includes(json!({ "code": "AUTH_CODE" }).to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(VALIDATE_AUTHCODE_SUCCESS);
+        })
+        .await;
+
+    let auth = auth_client(&server);
+    let listener = RedirectListener::bind("http://127.0.0.1:0/callback")
+        .await
+        .unwrap();
+
+    let browser = reqwest::get(format!(
+        "{}?auth_code=AUTH_CODE&state=STATE",
+        listener.redirect_uri()
+    ));
+
+    let (tokens, _) = tokio::join!(listener.wait_for_tokens(&auth, "STATE"), browser);
+
+    assert_eq!(tokens.unwrap().access_token, "ACCESS_TOKEN");
+    mock.assert_async().await;
+}
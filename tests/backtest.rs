@@ -0,0 +1,169 @@
+mod common;
+
+use common::{SBIN, time};
+use fyers::backtest::{Backtest, Strategy};
+use fyers::charges::ChargeTable;
+use fyers::paper::Slippage;
+use fyers::{
+    Broker, Candle, CandleResolution, FyersError, HistoryRequest, OrderRequest, OrderType,
+    ProductType, Side, Validity,
+};
+use httpmock::prelude::*;
+
+const HISTORY_SUCCESS: &str = include_str!("fixtures/history_success.json");
+
+// Buys below `buy_below` and sells above `sell_above`, one lot at a time
+struct Threshold {
+    buy_below: f64,
+    sell_above: f64,
+}
+
+impl Strategy for Threshold {
+    async fn on_candle<B: Broker>(
+        &mut self,
+        broker: &B,
+        symbol: &str,
+        candle: &Candle,
+    ) -> Result<(), FyersError> {
+        let holding = broker
+            .positions()
+            .await?
+            .iter()
+            .any(|p| p.symbol == symbol && p.is_open());
+        let pending = broker.orders().await?.iter().any(|o| o.status.is_open());
+
+        let side = match holding {
+            _ if pending => return Ok(()),
+            false if candle.close < self.buy_below => Side::Buy,
+            true if candle.close > self.sell_above => Side::Sell,
+            _ => return Ok(()),
+        };
+
+        let order = OrderRequest::builder(
+            symbol,
+            10,
+            OrderType::Market,
+            side,
+            ProductType::Intraday,
+            Validity::Day,
+        )
+        .build();
+        broker.place_order(&order).await?;
+
+        Ok(())
+    }
+}
+
+fn candles(closes: &[f64]) -> Vec<Candle> {
+    let mut open = closes[0];
+
+    closes
+        .iter()
+        .zip(0..)
+        .map(|(&close, minute)| {
+            let candle = Candle {
+                time: time(minute),
+                open,
+                high: open.max(close),
+                low: open.min(close),
+                close,
+                volume: 1000,
+                open_interest: None,
+            };
+            open = close;
+            candle
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn orders_fill_on_the_next_candle() {
+    let mut strategy = Threshold {
+        buy_below: 100.0,
+        sell_above: 105.0,
+    };
+
+    let report = Backtest::new(10_000.0)
+        .run(
+            &mut strategy,
+            SBIN,
+            candles(&[101.0, 99.0, 98.0, 103.0, 106.0, 104.0, 102.0]),
+        )
+        .await
+        .unwrap();
+
+    // Signalled on the close of 99 and 106, filled at the next opens
+    assert_eq!(report.trades.len(), 2);
+    assert_eq!(report.trades[0].side, Side::Buy);
+    assert_eq!(report.trades[0].price, 99.0);
+    assert_eq!(report.trades[0].time, time(2));
+    assert_eq!(report.trades[1].side, Side::Sell);
+    assert_eq!(report.trades[1].price, 106.0);
+
+    assert_eq!(report.equity_curve.len(), 7);
+    assert_eq!(report.equity_curve[2].equity, 9_990.0);
+    assert_eq!(report.net_pnl(), 70.0);
+    assert_eq!(report.max_drawdown(), 0.001);
+}
+
+#[tokio::test]
+async fn slippage_and_charges_reduce_pnl() {
+    let mut strategy = Threshold {
+        buy_below: 100.0,
+        sell_above: 105.0,
+    };
+
+    let report = Backtest::new(10_000.0)
+        .slippage(Slippage::Points(0.5))
+        .charges(ChargeTable::fyers())
+        .run(&mut strategy, SBIN, candles(&[99.0, 98.0, 106.0, 107.0]))
+        .await
+        .unwrap();
+
+    assert_eq!(report.trades[0].price, 99.5);
+    assert_eq!(report.trades[1].price, 105.5);
+
+    let charges: f64 = report.trades.iter().map(|t| t.charges).sum();
+    assert!(charges > 0.0);
+    assert_eq!(report.funds.charges, charges);
+    assert!((report.net_pnl() - (60.0 - charges)).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn run_history_downloads_candles() {
+    let ctx = common::setup().await;
+
+    let request = HistoryRequest::builder(
+        SBIN,
+        fyers::ist_datetime(2021, 1, 1, 9, 15),
+        fyers::ist_datetime(2021, 1, 6, 3, 30),
+    )
+    .resolution(CandleResolution::Day)
+    .build();
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/history");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(HISTORY_SUCCESS);
+        })
+        .await;
+
+    let mut strategy = Threshold {
+        buy_below: 280.0,
+        sell_above: 284.0,
+    };
+
+    let report = Backtest::new(100_000.0)
+        .run_history(&mut strategy, &ctx.fyers, &request)
+        .await
+        .unwrap();
+
+    assert_eq!(report.equity_curve.len(), 4);
+    assert_eq!(report.trades.len(), 1);
+    assert_eq!(report.trades[0].symbol, SBIN);
+    mock.assert();
+}
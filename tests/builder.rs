@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use fyers::{Fyers, FyersError};
+use httpmock::prelude::*;
+
+const PROFILE_SUCCESS: &str = include_str!("fixtures/profile_success.json");
+
+#[tokio::test]
+async fn builder_configures_client() {
+    let server = MockServer::start_async().await;
+
+    let fyers = Fyers::builder("TEST_CLIENT_ID", "TEST_ACCESS_TOKEN")
+        .api_url(server.base_url())
+        .data_url(server.base_url())
+        .connect_timeout(Duration::from_secs(5))
+        .read_timeout(Duration::from_secs(5))
+        .user_agent_suffix("my-bot/1.0")
+        .build()
+        .unwrap();
+
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/profile")
+                .header("Authorization", "TEST_CLIENT_ID:TEST_ACCESS_TOKEN")
+                .header_prefix("user-agent", "fyers/")
+                .header_suffix("user-agent", " my-bot/1.0");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PROFILE_SUCCESS);
+        })
+        .await;
+
+    fyers.profile().await.unwrap();
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn builder_uses_custom_http_client() {
+    let server = MockServer::start_async().await;
+
+    let http = reqwest::Client::builder()
+        .user_agent("custom-agent")
+        .build()
+        .unwrap();
+
+    let fyers = Fyers::builder("TEST_CLIENT_ID", "TEST_ACCESS_TOKEN")
+        .http_client(http)
+        .api_url(server.base_url())
+        .build()
+        .unwrap();
+
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/profile")
+                .header("user-agent", "custom-agent");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PROFILE_SUCCESS);
+        })
+        .await;
+
+    fyers.profile().await.unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn invalid_credentials_are_an_error() {
+    let result = Fyers::builder("CLIENT_ID", "ACCESS\nTOKEN").build();
+
+    assert!(matches!(result, Err(FyersError::InvalidConfig(_))));
+}
+
+#[test]
+fn custom_http_client_conflicts_with_timeouts() {
+    let result = Fyers::builder("CLIENT_ID", "ACCESS_TOKEN")
+        .http_client(reqwest::Client::new())
+        .read_timeout(Duration::from_secs(1))
+        .build();
+
+    assert!(matches!(result, Err(FyersError::InvalidConfig(_))));
+}
@@ -0,0 +1,157 @@
+use fyers::charges::{Brokerage, ChargeRates, ChargeTable, Segment};
+use fyers::{OrderRequest, OrderType, ProductType, Side, Validity};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn equity_intraday_breakdown() {
+    let table = ChargeTable::fyers();
+
+    let buy = table.calculate(
+        Segment::NseEquity,
+        ProductType::Intraday,
+        Side::Buy,
+        100,
+        100.0,
+    );
+    assert!(close(buy.brokerage, 3.0));
+    assert!(close(buy.stt, 0.0));
+    assert!(close(buy.exchange, 0.297));
+    assert!(close(buy.sebi, 0.01));
+    assert!(close(buy.gst, 0.59526));
+    assert!(close(buy.stamp_duty, 0.3));
+    assert!(close(buy.total(), 4.20226));
+
+    let sell = table.calculate(
+        Segment::NseEquity,
+        ProductType::Intraday,
+        Side::Sell,
+        100,
+        100.0,
+    );
+    assert!(close(sell.stt, 2.5));
+    assert!(close(sell.stamp_duty, 0.0));
+}
+
+#[test]
+fn delivery_and_options_use_their_own_rates() {
+    let table = ChargeTable::fyers();
+
+    let delivery = table.calculate(Segment::NseEquity, ProductType::Cnc, Side::Buy, 100, 100.0);
+    assert!(close(delivery.stt, 10.0));
+    assert!(close(delivery.stamp_duty, 1.5));
+
+    // Options pay a flat brokerage, even on a tiny premium
+    let option = table.calculate(
+        Segment::NseOptions,
+        ProductType::Margin,
+        Side::Sell,
+        75,
+        2.0,
+    );
+    assert!(close(option.brokerage, 20.0));
+    assert!(close(option.stt, 0.15));
+
+    // Percentage brokerage is capped
+    let future = table.calculate(
+        Segment::NseFutures,
+        ProductType::Margin,
+        Side::Buy,
+        75,
+        24_000.0,
+    );
+    assert!(close(future.brokerage, 20.0));
+}
+
+#[test]
+fn rates_can_be_overridden() {
+    let mut table = ChargeTable::fyers();
+    table.set(
+        Segment::NseEquity,
+        ProductType::Intraday,
+        ChargeRates {
+            brokerage: Brokerage::Flat(10.0),
+            ..ChargeRates::FREE
+        },
+    );
+
+    let order = OrderRequest::builder(
+        "NSE:SBIN-EQ",
+        10,
+        OrderType::Market,
+        Side::Buy,
+        ProductType::Intraday,
+        Validity::Day,
+    )
+    .build();
+
+    let charges = table.for_order(&order, Segment::NseEquity, 500.0);
+    assert_eq!(charges.total(), 10.0);
+
+    // Missing rates are charged nothing
+    let empty = ChargeTable::empty().for_order(&order, Segment::NseEquity, 500.0);
+    assert_eq!(empty.total(), 0.0);
+}
+
+#[test]
+fn uniform_rates_apply_everywhere() {
+    let table = ChargeTable::uniform(ChargeRates {
+        brokerage: Brokerage::Percent {
+            rate: 0.0003,
+            max: 20.0,
+        },
+        ..ChargeRates::FREE
+    });
+
+    let equity = table.calculate(Segment::NseEquity, ProductType::Cnc, Side::Buy, 100, 100.0);
+    assert!(close(equity.total(), 3.0));
+
+    // Brokerage is capped per fill
+    let commodity = table.calculate(
+        Segment::CommodityFutures,
+        ProductType::Margin,
+        Side::Sell,
+        100,
+        10_000.0,
+    );
+    assert_eq!(commodity.total(), 20.0);
+}
+
+#[test]
+fn breakdowns_add_up() {
+    let table = ChargeTable::fyers();
+    let fills = [(Side::Buy, 100.0), (Side::Sell, 101.0)];
+
+    let total: fyers::charges::ChargeBreakdown = fills
+        .iter()
+        .map(|&(side, price)| {
+            table.calculate(Segment::NseEquity, ProductType::Intraday, side, 100, price)
+        })
+        .sum();
+
+    assert!(close(total.stt, 101.0 * 100.0 * 0.00025));
+    assert!(total.total() > total.brokerage);
+}
+
+#[test]
+fn segment_from_symbol() {
+    let cases = [
+        ("NSE:SBIN-EQ", Some(Segment::NseEquity)),
+        ("BSE:SBIN-A", Some(Segment::BseEquity)),
+        ("NSE:NIFTY25JANFUT", Some(Segment::NseFutures)),
+        ("NSE:NIFTY2510923500CE", Some(Segment::NseOptions)),
+        ("BSE:SENSEX25JAN80000PE", Some(Segment::BseOptions)),
+        ("NSE:USDINR25JANFUT", Some(Segment::CurrencyFutures)),
+        ("NSE:USDINR25JAN86CE", Some(Segment::CurrencyOptions)),
+        ("MCX:CRUDEOIL25JANFUT", Some(Segment::CommodityFutures)),
+        ("MCX:GOLDM25FEB78000CE", Some(Segment::CommodityOptions)),
+        ("NYSE:AAPL", None),
+        ("SBIN", None),
+    ];
+
+    for (symbol, segment) in cases {
+        assert_eq!(Segment::from_symbol(symbol), segment, "{symbol}");
+    }
+}
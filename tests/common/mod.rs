@@ -1,6 +1,12 @@
-use fyers::Fyers;
+// Each test crate only uses some of these helpers
+#![allow(dead_code)]
+
+use chrono::{DateTime, Utc};
+use fyers::{Broker, Fyers, OrderDetails};
 use httpmock::MockServer;
 
+pub const SBIN: &str = "NSE:SBIN-EQ";
+
 pub struct TestContext {
     pub server: MockServer,
     pub fyers: Fyers,
@@ -8,12 +14,22 @@ pub struct TestContext {
 
 pub async fn setup() -> TestContext {
     let server = MockServer::start_async().await;
-    let fyers = Fyers::with_base_urls(
-        "TEST_CLIENT_ID",
-        "TEST_ACCESS_TOKEN",
-        server.base_url(),
-        server.base_url(),
-    );
+    let fyers = Fyers::builder("TEST_CLIENT_ID", "TEST_ACCESS_TOKEN")
+        .api_url(server.base_url())
+        .data_url(server.base_url())
+        .build()
+        .unwrap();
 
     TestContext { server, fyers }
 }
+
+/// `minute` minutes after the open on 1 January 2025.
+pub fn time(minute: u32) -> DateTime<Utc> {
+    fyers::ist_datetime(2025, 1, 1, 9, 15 + minute)
+}
+
+/// The order with ID `id` in the broker's order book.
+pub async fn find<B: Broker>(broker: &B, id: &str) -> OrderDetails {
+    let orders = broker.orders().await.unwrap();
+    orders.into_iter().find(|o| o.id == id).unwrap()
+}
@@ -0,0 +1,257 @@
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use fyers::execution::{Execution, ExecutionState, VolumeCurve};
+use fyers::paper::PaperTrader;
+use fyers::{
+    Broker, Candle, FyersError, InstrumentInfo, OrderRequest, OrderStatus, ProductType, Side,
+    ist_datetime,
+};
+
+const SBIN: &str = "NSE:SBIN-EQ";
+
+fn order(qty: u32) -> OrderRequest {
+    OrderRequest::market(SBIN, qty, Side::Buy, ProductType::Cnc)
+        .order_tag("algo")
+        .build()
+}
+
+fn candle(day: u32, hour: u32, minute: u32, volume: u64) -> Candle {
+    Candle {
+        time: ist_datetime(2025, 1, day, hour, minute),
+        open: 100.0,
+        high: 100.0,
+        low: 100.0,
+        close: 100.0,
+        volume,
+        open_interest: None,
+    }
+}
+
+fn minutes(n: u64) -> Duration {
+    Duration::from_secs(n * 60)
+}
+
+#[test]
+fn twap_splits_evenly() {
+    let plan = Execution::twap(
+        &order(1000),
+        ist_datetime(2025, 1, 6, 10, 0),
+        ist_datetime(2025, 1, 6, 11, 0),
+    )
+    .interval(minutes(15))
+    .build()
+    .unwrap();
+
+    let qty: Vec<_> = plan.children.iter().map(|c| c.order.qty()).collect();
+    assert_eq!(qty, [250, 250, 250, 250]);
+    assert_eq!(plan.children[1].time, ist_datetime(2025, 1, 6, 10, 15));
+    assert_eq!(plan.children[3].order.order_tag(), Some("algo4"));
+    assert_eq!(plan.unscheduled_qty, 0);
+}
+
+#[test]
+fn vwap_follows_the_volume_curve() {
+    // Two days with the same profile
+    let candles: Vec<_> = [2, 3]
+        .into_iter()
+        .flat_map(|day| {
+            [
+                candle(day, 10, 0, 100),
+                candle(day, 10, 15, 300),
+                candle(day, 10, 30, 400),
+                candle(day, 10, 45, 200),
+            ]
+        })
+        .collect();
+    let curve = VolumeCurve::from_candles(&candles);
+
+    let execution = Execution::vwap(
+        &order(1000),
+        ist_datetime(2025, 1, 6, 10, 0),
+        ist_datetime(2025, 1, 6, 11, 0),
+        curve,
+    )
+    .interval(minutes(15));
+
+    let plan = execution.clone().build().unwrap();
+    let qty: Vec<_> = plan.children.iter().map(|c| c.order.qty()).collect();
+    assert_eq!(qty, [100, 300, 400, 200]);
+
+    // At most half of each interval's volume, carrying the rest forward
+    let plan = execution.max_participation(0.5).build().unwrap();
+    let qty: Vec<_> = plan.children.iter().map(|c| c.order.qty()).collect();
+    assert_eq!(qty, [50, 150, 200, 100]);
+    assert_eq!(plan.scheduled_qty(), 500);
+    assert_eq!(plan.unscheduled_qty, 500);
+}
+
+#[test]
+fn vwap_window_can_cross_midnight() {
+    let curve = VolumeCurve::from_candles(&[
+        candle(2, 23, 50, 100),
+        candle(2, 0, 0, 100),
+        candle(2, 0, 10, 200),
+    ]);
+
+    let plan = Execution::vwap(
+        &order(400),
+        ist_datetime(2025, 1, 6, 23, 50),
+        ist_datetime(2025, 1, 7, 0, 20),
+        curve.clone(),
+    )
+    .interval(minutes(10))
+    .build()
+    .unwrap();
+
+    let qty: Vec<_> = plan.children.iter().map(|c| c.order.qty()).collect();
+    assert_eq!(qty, [100, 100, 200]);
+
+    // An interval that wraps around midnight
+    let from = chrono::NaiveTime::from_hms_opt(23, 50, 0).unwrap();
+    let to = chrono::NaiveTime::from_hms_opt(0, 10, 0).unwrap();
+    assert_eq!(curve.volume(from, to), 200.0);
+}
+
+#[test]
+fn children_are_whole_lots_below_the_freeze_qty() {
+    let nifty = InstrumentInfo::new(75, 0.05).with_freeze_qty(1801);
+    let order =
+        OrderRequest::market("NSE:NIFTY25JANFUT", 3000, Side::Buy, ProductType::Margin).build();
+
+    let plan = Execution::twap(
+        &order,
+        ist_datetime(2025, 1, 6, 10, 0),
+        ist_datetime(2025, 1, 6, 10, 30),
+    )
+    .interval(minutes(10))
+    .max_child_qty(1200)
+    .instrument(nifty)
+    .build()
+    .unwrap();
+
+    // 40 lots over three intervals, capped at 16 lots each
+    let qty: Vec<_> = plan.children.iter().map(|c| c.order.qty()).collect();
+    assert_eq!(qty, [1050, 975, 975]);
+}
+
+#[test]
+fn invalid_executions_are_rejected() {
+    let start = ist_datetime(2025, 1, 6, 10, 0);
+
+    let err = Execution::twap(&order(100), start, start).build();
+    assert!(matches!(err, Err(FyersError::InvalidParams(_))));
+
+    // Participation needs a volume curve
+    let err = Execution::twap(&order(100), start, start + TimeDelta::hours(1))
+        .max_participation(0.1)
+        .build();
+    assert!(matches!(err, Err(FyersError::InvalidParams(_))));
+}
+
+fn paper() -> PaperTrader {
+    let paper = PaperTrader::new(1_000_000.0);
+    paper.update_tick(SBIN, Utc::now(), 100.0);
+    paper
+}
+
+#[tokio::test]
+async fn execution_places_children_over_time() {
+    let paper = paper();
+    let start = Utc::now();
+
+    let handle = Execution::twap(&order(300), start, start + TimeDelta::milliseconds(150))
+        .interval(Duration::from_millis(50))
+        .build()
+        .unwrap()
+        .start(paper.clone());
+
+    let progress = handle.wait().await.unwrap();
+
+    assert_eq!(progress.state, ExecutionState::Completed);
+    assert_eq!(progress.children.len(), 3);
+    assert_eq!(progress.placed_qty, 300);
+    assert_eq!(progress.filled_qty, 300);
+    assert_eq!(paper.trades().len(), 3);
+}
+
+#[tokio::test]
+async fn execution_can_be_paused_and_resumed() {
+    let paper = paper();
+    let start = Utc::now() + TimeDelta::milliseconds(100);
+
+    let handle = Execution::twap(&order(200), start, start + TimeDelta::milliseconds(100))
+        .interval(Duration::from_millis(50))
+        .build()
+        .unwrap()
+        .start(paper.clone());
+
+    handle.pause();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let progress = handle.progress();
+    assert_eq!(progress.state, ExecutionState::Paused);
+    assert_eq!(progress.placed_qty, 0);
+
+    // Both children are overdue by now
+    handle.resume();
+    let progress = handle.wait().await.unwrap();
+
+    assert_eq!(progress.state, ExecutionState::Completed);
+    assert_eq!(progress.placed_qty, 200);
+}
+
+#[tokio::test]
+async fn cancel_stops_the_execution_and_cancels_open_children() {
+    let paper = paper();
+    let start = Utc::now();
+
+    // Limit orders below the market stay open
+    let order = OrderRequest::limit(SBIN, 200, Side::Buy, ProductType::Cnc, 90.0).build();
+
+    let handle = Execution::twap(&order, start, start + TimeDelta::hours(2))
+        .interval(minutes(60))
+        .build()
+        .unwrap()
+        .start(paper.clone());
+
+    while handle.progress().children.is_empty() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    handle.cancel();
+    let progress = handle.wait().await.unwrap();
+
+    assert_eq!(progress.state, ExecutionState::Cancelled);
+    assert_eq!(progress.placed_qty, 100);
+
+    let orders = paper.orders().await.unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].status, OrderStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn refresh_picks_up_fills_after_the_last_child() {
+    let paper = paper();
+    let start = Utc::now();
+
+    // Resting limit children, which fill after the execution completes
+    let order = OrderRequest::limit(SBIN, 200, Side::Buy, ProductType::Cnc, 90.0).build();
+
+    let handle = Execution::twap(&order, start, start + TimeDelta::milliseconds(100))
+        .interval(Duration::from_millis(50))
+        .build()
+        .unwrap()
+        .start(paper.clone());
+
+    while !handle.progress().state.is_finished() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(handle.progress().filled_qty, 0);
+
+    paper.update_tick(SBIN, Utc::now(), 89.0);
+
+    let progress = handle.refresh().await.unwrap();
+    assert_eq!(progress.state, ExecutionState::Completed);
+    assert_eq!(progress.filled_qty, 200);
+}
@@ -0,0 +1,192 @@
+#![cfg(feature = "totp-login")]
+
+use fyers::auth::{AuthError, HeadlessLogin, Tokens};
+use httpmock::prelude::*;
+use serde_json::json;
+
+const SEND_LOGIN_OTP_SUCCESS: &str = include_str!("fixtures/auth/send_login_otp_success.json");
+const VERIFY_OTP_SUCCESS: &str = include_str!("fixtures/auth/verify_otp_success.json");
+const VERIFY_OTP_INVALID: &str = include_str!("fixtures/auth/verify_otp_invalid.json");
+const VERIFY_PIN_SUCCESS: &str = include_str!("fixtures/auth/verify_pin_success.json");
+const TOKEN_SUCCESS: &str = include_str!("fixtures/auth/token_success.json");
+const VALIDATE_AUTHCODE_SUCCESS: &str =
+    include_str!("fixtures/auth/validate_authcode_success.json");
+
+// "12345678901234567890" in base32
+const TOTP_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+fn login(server: &MockServer, totp_secret: &str) -> HeadlessLogin {
+    HeadlessLogin::new(
+        "APPID-100",
+        "SECRET_KEY",
+        "https://example.com/redirect",
+        "XY12345",
+        totp_secret,
+        "1234",
+    )
+    .login_url(server.url("/vagator/v2"))
+    .api_url(server.url("/api/v3"))
+}
+
+async fn mock_step<'a>(server: &'a MockServer, path: &str, body: &str) -> httpmock::Mock<'a> {
+    let path = path.to_owned();
+    let body = body.to_owned();
+
+    server
+        .mock_async(move |when, then| {
+            when.method(POST).path(path);
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(body);
+        })
+        .await
+}
+
+#[tokio::test]
+async fn login_runs_all_steps() {
+    let server = MockServer::start_async().await;
+
+    let send_otp = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/vagator/v2/send_login_otp_v2")
+                // base64 encoded fy_id
+                .json_body(json!({ "fy_id": "WFkxMjM0NQ==", "app_id": "2" }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(SEND_LOGIN_OTP_SUCCESS);
+        })
+        .await;
+
+    let verify_otp = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/vagator/v2/verify_otp")
+                .body_matches(r#""request_key":"REQUEST_KEY_1""#)
+                .body_matches(r#""otp":"\d{6}""#);
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(VERIFY_OTP_SUCCESS);
+        })
+        .await;
+
+    let verify_pin = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/vagator/v2/verify_pin_v2")
+                .json_body(json!({
+                    "request_key": "REQUEST_KEY_2",
+                    "identity_type": "pin",
+                    // base64 encoded pin
+                    "identifier": "MTIzNA==",
+                }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(VERIFY_PIN_SUCCESS);
+        })
+        .await;
+
+    let token = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/api/v3/token")
+                .header("Authorization", "Bearer LOGIN_ACCESS_TOKEN")
+                .json_body_includes(
+                    json!({
+                        "fyers_id": "XY12345",
+                        "app_id": "APPID",
+                        "appType": "100",
+                        "redirect_uri": "https://example.com/redirect",
+                        "state": "headless",
+                        "response_type": "code",
+                    })
+                    .to_string(),
+                );
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(TOKEN_SUCCESS);
+        })
+        .await;
+
+    let validate = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/api/v3/validate-authcode")
+                .json_body_includes(
+                    json!({ "grant_type": "authorization_code", "code": "AUTH_CODE" }).to_string(),
+                );
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(VALIDATE_AUTHCODE_SUCCESS);
+        })
+        .await;
+
+    let tokens = login(&server, TOTP_SECRET).login().await.unwrap();
+
+    assert_eq!(
+        tokens,
+        Tokens {
+            access_token: "ACCESS_TOKEN".into(),
+            refresh_token: "REFRESH_TOKEN".into(),
+        }
+    );
+
+    send_otp.assert_async().await;
+    verify_otp.assert_async().await;
+    verify_pin.assert_async().await;
+    token.assert_async().await;
+    validate.assert_async().await;
+}
+
+#[tokio::test]
+async fn login_stops_at_failed_step() {
+    let server = MockServer::start_async().await;
+
+    mock_step(
+        &server,
+        "/vagator/v2/send_login_otp_v2",
+        SEND_LOGIN_OTP_SUCCESS,
+    )
+    .await;
+
+    let verify_otp = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/vagator/v2/verify_otp");
+
+            then.status(400)
+                .header("content-type", "application/json")
+                .body(VERIFY_OTP_INVALID);
+        })
+        .await;
+
+    let verify_pin = mock_step(&server, "/vagator/v2/verify_pin_v2", VERIFY_PIN_SUCCESS).await;
+
+    let err = login(&server, TOTP_SECRET).login().await.unwrap_err();
+
+    assert!(matches!(err, AuthError::Api { code: -1106, .. }));
+    verify_otp.assert_async().await;
+    verify_pin.assert_calls_async(0).await;
+}
+
+#[tokio::test]
+async fn invalid_totp_secret_is_rejected_before_login() {
+    let server = MockServer::start_async().await;
+
+    let send_otp = mock_step(
+        &server,
+        "/vagator/v2/send_login_otp_v2",
+        SEND_LOGIN_OTP_SUCCESS,
+    )
+    .await;
+
+    let err = login(&server, "not base32!").login().await.unwrap_err();
+
+    assert!(matches!(err, AuthError::InvalidTotpSecret));
+    send_otp.assert_calls_async(0).await;
+}
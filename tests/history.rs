@@ -1,6 +1,6 @@
 mod common;
 use chrono::{TimeZone, Utc};
-use fyers::{CandleResolution, HistoryRequest};
+use fyers::{Candle, CandleResolution, HistoryRequest};
 use httpmock::prelude::*;
 
 const HISTORY_SUCCESS: &str = include_str!("fixtures/history_success.json");
@@ -77,3 +77,15 @@ pub fn match_query<T: serde::Serialize>(mut when: httpmock::When, value: &T) ->
 
     when
 }
+
+#[test]
+fn candles_round_trip_through_json() {
+    let candles: Vec<Candle> = serde_json::from_str::<serde_json::Value>(HISTORY_SUCCESS)
+        .map(|v| serde_json::from_value(v["candles"].clone()).unwrap())
+        .unwrap();
+
+    let saved = serde_json::to_string(&candles).unwrap();
+    let loaded: Vec<Candle> = serde_json::from_str(&saved).unwrap();
+
+    assert_eq!(loaded, candles);
+}
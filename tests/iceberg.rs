@@ -0,0 +1,202 @@
+#![cfg(feature = "testing")]
+
+use std::time::Duration;
+
+use chrono::Utc;
+use fyers::execution::{Iceberg, IcebergOutcome};
+use fyers::paper::PaperTrader;
+use fyers::testing::{FakeServer, Fault};
+use fyers::{Endpoint, FyersError, OrderRequest, ProductType, Side};
+use serde_json::json;
+
+// An order book with the fake server's order ID in the given state
+fn order_book(status: u8, filled_qty: u32, limit_price: f64) -> String {
+    json!({
+        "s": "ok",
+        "code": 200,
+        "message": "",
+        "orderBook": [{
+            "id": "808058117761",
+            "symbol": "NSE:SBIN-EQ",
+            "qty": 100,
+            "remainingQuantity": 100 - filled_qty,
+            "filledQty": filled_qty,
+            "limitPrice": limit_price,
+            "stopPrice": 0,
+            "tradedPrice": if filled_qty > 0 { 581.15 } else { 0.0 },
+            "type": 1,
+            "side": 1,
+            "productType": "CNC",
+            "orderValidity": "DAY",
+            "status": status,
+        }]
+    })
+    .to_string()
+}
+
+fn order(qty: u32) -> OrderRequest {
+    OrderRequest::limit("NSE:SBIN-EQ", qty, Side::Buy, ProductType::Cnc, 582.0)
+        .order_tag("ice")
+        .build()
+}
+
+#[tokio::test]
+async fn iceberg_places_slices_until_filled() {
+    let server = FakeServer::start().await;
+    let fyers = server.client();
+    server.set_response(Endpoint::Orders, 200, order_book(2, 100, 581.15));
+
+    let report = Iceberg::new(&order(300), 100)
+        .unwrap()
+        .poll_interval(Duration::from_millis(1))
+        .run(&fyers, &fyers)
+        .await
+        .unwrap();
+
+    assert_eq!(report.outcome, IcebergOutcome::Filled);
+    assert_eq!(report.filled_qty, 300);
+    assert_eq!(report.avg_price, 581.15);
+    assert_eq!(report.orders.len(), 3);
+    assert_eq!(server.requests(Endpoint::PlaceOrder), 3);
+    assert_eq!(server.requests(Endpoint::ModifyOrder), 0);
+}
+
+#[tokio::test]
+async fn iceberg_chases_then_gives_up() {
+    let server = FakeServer::start().await;
+    let fyers = server.client();
+
+    // The slice rests below the best bid of 581.15 and never fills
+    server.set_response(Endpoint::Orders, 200, order_book(6, 0, 580.0));
+
+    let report = Iceberg::new(&order(300), 100)
+        .unwrap()
+        .poll_interval(Duration::from_millis(1))
+        .max_chase_steps(2)
+        .run(&fyers, &fyers)
+        .await
+        .unwrap();
+
+    assert_eq!(report.outcome, IcebergOutcome::ChaseLimitReached);
+    assert_eq!(report.filled_qty, 0);
+    assert_eq!(server.requests(Endpoint::ModifyOrder), 2);
+    assert_eq!(server.requests(Endpoint::CancelOrder), 1);
+}
+
+#[tokio::test]
+async fn slice_filling_during_a_chase_is_not_an_error() {
+    let server = FakeServer::start().await;
+    let fyers = server.client();
+
+    // The slice rests below the best bid, then fills before it can be
+    // moved
+    server.respond_next(Endpoint::Orders, 200, order_book(6, 0, 580.0));
+    server.set_response(Endpoint::Orders, 200, order_book(2, 100, 580.0));
+    server.fail_next(
+        Endpoint::ModifyOrder,
+        Fault::Api {
+            code: -99,
+            message: "order is already filled".into(),
+        },
+    );
+
+    let report = Iceberg::new(&order(100), 100)
+        .unwrap()
+        .poll_interval(Duration::from_millis(1))
+        .run(&fyers, &fyers)
+        .await
+        .unwrap();
+
+    assert_eq!(report.outcome, IcebergOutcome::Filled);
+    assert_eq!(report.filled_qty, 100);
+    assert_eq!(report.orders, ["808058117761"]);
+    assert_eq!(server.requests(Endpoint::ModifyOrder), 1);
+
+    // Same when the chase limit is reached and the cancel comes too late
+    server.respond_next(Endpoint::Orders, 200, order_book(6, 0, 580.0));
+    server.fail_next(
+        Endpoint::CancelOrder,
+        Fault::Api {
+            code: -99,
+            message: "order is already filled".into(),
+        },
+    );
+
+    let report = Iceberg::new(&order(100), 100)
+        .unwrap()
+        .poll_interval(Duration::from_millis(1))
+        .max_chase_steps(0)
+        .run(&fyers, &fyers)
+        .await
+        .unwrap();
+
+    assert_eq!(report.outcome, IcebergOutcome::Filled);
+    assert_eq!(report.filled_qty, 100);
+    assert_eq!(server.requests(Endpoint::CancelOrder), 1);
+}
+
+#[tokio::test]
+async fn iceberg_stops_when_a_slice_is_closed() {
+    let server = FakeServer::start().await;
+    let fyers = server.client();
+    server.set_response(Endpoint::Orders, 200, order_book(5, 0, 581.15));
+
+    let report = Iceberg::new(&order(300), 100)
+        .unwrap()
+        .poll_interval(Duration::from_millis(1))
+        .run(&fyers, &fyers)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        report.outcome,
+        IcebergOutcome::SliceClosed(fyers::OrderStatus::Rejected)
+    );
+    assert_eq!(server.requests(Endpoint::PlaceOrder), 1);
+}
+
+#[tokio::test]
+async fn rate_limited_slices_are_retried() {
+    let server = FakeServer::start().await;
+    let fyers = server.client();
+    server.set_response(Endpoint::Orders, 200, order_book(2, 100, 581.15));
+    server.fail_next(Endpoint::PlaceOrder, Fault::RateLimited);
+
+    let report = Iceberg::new(&order(100), 100)
+        .unwrap()
+        .poll_interval(Duration::from_millis(1))
+        .run(&fyers, &fyers)
+        .await
+        .unwrap();
+
+    assert_eq!(report.outcome, IcebergOutcome::Filled);
+    assert_eq!(report.orders.len(), 1);
+    assert_eq!(server.requests(Endpoint::PlaceOrder), 2);
+}
+
+#[tokio::test]
+async fn iceberg_runs_against_a_paper_trader() {
+    let paper = PaperTrader::new(1_000_000.0);
+    paper.update_tick("NSE:SBIN-EQ", Utc::now(), 580.0);
+
+    let report = Iceberg::new(&order(300), 100)
+        .unwrap()
+        .poll_interval(Duration::from_millis(1))
+        .run(&paper, &paper)
+        .await
+        .unwrap();
+
+    assert_eq!(report.outcome, IcebergOutcome::Filled);
+    assert_eq!(report.filled_qty, 300);
+    assert_eq!(report.avg_price, 580.0);
+    assert_eq!(paper.trades().len(), 3);
+}
+
+#[test]
+fn iceberg_needs_a_limit_order() {
+    let order = OrderRequest::market("NSE:SBIN-EQ", 300, Side::Buy, ProductType::Cnc).build();
+    assert!(matches!(
+        Iceberg::new(&order, 100),
+        Err(FyersError::InvalidParams(_))
+    ));
+}
@@ -0,0 +1,169 @@
+use chrono::Duration;
+use fyers::Candle;
+use fyers::indicators::{self, Ema, Rsi, Trend, Vwap};
+
+fn candle(minute: i64, high: f64, low: f64, close: f64, volume: u64) -> Candle {
+    Candle {
+        time: fyers::ist_datetime(2026, 2, 5, 9, 15) + Duration::minutes(minute),
+        open: close,
+        high,
+        low,
+        close,
+        volume,
+        open_interest: None,
+    }
+}
+
+fn closes(prices: &[f64]) -> Vec<Candle> {
+    prices
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| candle(i as i64, p, p, p, 100))
+        .collect()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn sma_warms_up_and_rolls() {
+    let candles = closes(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+    let sma = indicators::sma(&candles, 3);
+
+    assert_eq!(sma[..2], [None, None]);
+    assert_close(sma[2].unwrap(), 2.0);
+    assert_close(sma[3].unwrap(), 3.0);
+    assert_close(sma[4].unwrap(), 4.0);
+}
+
+#[test]
+fn ema_seeds_with_sma() {
+    let candles = closes(&[2.0, 4.0, 6.0, 8.0]);
+    let ema = indicators::ema(&candles, 3);
+
+    assert_eq!(ema[1], None);
+    assert_close(ema[2].unwrap(), 4.0);
+    // alpha = 0.5
+    assert_close(ema[3].unwrap(), 6.0);
+}
+
+#[test]
+fn streaming_matches_batch() {
+    let prices = [44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42];
+    let candles = closes(&prices);
+
+    let batch = indicators::ema(&candles, 4);
+    let mut ema = Ema::new(4);
+    let streamed: Vec<_> = prices.iter().map(|&p| ema.update(p)).collect();
+
+    assert_eq!(batch, streamed);
+}
+
+#[test]
+fn rsi_extremes() {
+    let mut rsi = Rsi::new(3);
+    let rising: Vec<_> = [1.0, 2.0, 3.0, 4.0, 5.0]
+        .iter()
+        .map(|&p| rsi.update(p))
+        .collect();
+
+    assert_eq!(rising[..3], [None, None, None]);
+    assert_close(rising[3].unwrap(), 100.0);
+
+    let mut rsi = Rsi::new(2);
+    rsi.update(10.0);
+    rsi.update(11.0);
+    // One gain of 1.0 and one loss of 1.0
+    assert_close(rsi.update(10.0).unwrap(), 50.0);
+}
+
+#[test]
+fn bollinger_bands_are_symmetric() {
+    let candles = closes(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+    let bands = indicators::bollinger(&candles, 8, 2.0);
+
+    let last = bands.last().unwrap().unwrap();
+    // mean 5, population standard deviation 2
+    assert_close(last.middle, 5.0);
+    assert_close(last.upper, 9.0);
+    assert_close(last.lower, 1.0);
+}
+
+#[test]
+fn macd_signal_needs_slow_plus_signal_values() {
+    let candles = closes(&(1..=10).map(f64::from).collect::<Vec<_>>());
+    let macd = indicators::macd(&candles, 2, 4, 3);
+
+    // First value once slow (4) + signal (3) - 1 prices are available
+    assert!(macd[..5].iter().all(Option::is_none));
+    let value = macd[5].unwrap();
+    assert_close(value.histogram, value.macd - value.signal);
+}
+
+#[test]
+fn atr_uses_true_range() {
+    let candles = vec![
+        candle(0, 10.0, 8.0, 9.0, 100),
+        // Gap up: true range is high - previous close
+        candle(1, 14.0, 12.0, 13.0, 100),
+        candle(2, 13.0, 12.0, 12.5, 100),
+    ];
+    let atr = indicators::atr(&candles, 2);
+
+    assert_eq!(atr[0], None);
+    assert_close(atr[1].unwrap(), (2.0 + 5.0) / 2.0);
+    assert_close(atr[2].unwrap(), (3.5 + 1.0) / 2.0);
+}
+
+#[test]
+fn vwap_resets_on_new_ist_session() {
+    let day1 = fyers::ist_datetime(2026, 2, 5, 15, 29);
+    // 00:10 IST on the next day is still the previous UTC date
+    let day2 = fyers::ist_datetime(2026, 2, 6, 0, 10);
+    assert_eq!(day1.date_naive(), day2.date_naive());
+
+    let mut vwap = Vwap::new();
+    vwap.update_tick(day1, 100.0, 10);
+    assert_close(vwap.update_tick(day1, 110.0, 30).unwrap(), 107.5);
+
+    assert_close(vwap.update_tick(day2, 200.0, 5).unwrap(), 200.0);
+    assert_eq!(
+        vwap.session(),
+        Some(chrono::NaiveDate::from_ymd_opt(2026, 2, 6).unwrap())
+    );
+}
+
+#[test]
+fn vwap_uses_typical_price() {
+    let candles = vec![
+        candle(0, 12.0, 6.0, 9.0, 10),
+        candle(1, 12.0, 12.0, 12.0, 20),
+    ];
+    let vwap = indicators::vwap(&candles);
+
+    assert_close(vwap[0].unwrap(), 9.0);
+    assert_close(vwap[1].unwrap(), (90.0 + 240.0) / 30.0);
+}
+
+#[test]
+fn supertrend_flips_on_breakdown() {
+    let mut candles: Vec<_> = (0..6)
+        .map(|i| {
+            let p = 100.0 + i as f64;
+            candle(i, p + 1.0, p - 1.0, p + 0.5, 100)
+        })
+        .collect();
+    candles.push(candle(6, 90.0, 80.0, 81.0, 100));
+
+    let supertrend = indicators::supertrend(&candles, 3, 1.0);
+
+    assert!(supertrend[..2].iter().all(Option::is_none));
+    assert_eq!(supertrend[5].unwrap().trend, Trend::Up);
+    assert_eq!(supertrend[6].unwrap().trend, Trend::Down);
+    assert!(supertrend[5].unwrap().value < candles[5].close);
+    assert!(supertrend[6].unwrap().value > candles[6].close);
+}
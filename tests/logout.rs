@@ -0,0 +1,75 @@
+mod common;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fyers::auth::{RefreshFuture, TokenProvider, Tokens};
+use fyers::{Fyers, FyersError};
+use httpmock::prelude::*;
+
+const LOGOUT_SUCCESS: &str = include_str!("fixtures/logout_success.json");
+const INVALID_TOKEN: &str = include_str!("fixtures/error/invalid_token.json");
+
+struct CountingProvider(Arc<AtomicUsize>);
+
+impl TokenProvider for CountingProvider {
+    fn refresh(&self) -> RefreshFuture<'_> {
+        Box::pin(async move {
+            self.0.fetch_add(1, Ordering::SeqCst);
+
+            Ok(Tokens {
+                access_token: "NEW_TOKEN".into(),
+                refresh_token: "REFRESH_TOKEN".into(),
+            })
+        })
+    }
+}
+
+#[tokio::test]
+async fn logout_success() {
+    let ctx = common::setup().await;
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/logout")
+                .header("Authorization", "TEST_CLIENT_ID:TEST_ACCESS_TOKEN");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(LOGOUT_SUCCESS);
+        })
+        .await;
+
+    ctx.fyers.logout().await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn logout_does_not_refresh_invalid_token() {
+    let server = MockServer::start_async().await;
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let fyers = Fyers::builder("TEST_CLIENT_ID", "OLD_TOKEN")
+        .api_url(server.base_url())
+        .token_provider(CountingProvider(calls.clone()))
+        .build()
+        .unwrap();
+
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/logout");
+
+            then.status(401)
+                .header("content-type", "application/json")
+                .body(INVALID_TOKEN);
+        })
+        .await;
+
+    let err = fyers.logout().await.unwrap_err();
+
+    assert!(matches!(err, FyersError::InvalidToken));
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    mock.assert_calls_async(1).await;
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use fyers::{ModifyOrderRequest, OrderType};
+use httpmock::prelude::*;
+
+const SUCCESS: &str = include_str!("fixtures/order_modify_success.json");
+
+#[tokio::test]
+async fn modify_order_sends_only_changed_fields() {
+    let ctx = common::setup().await;
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(PATCH)
+                .path("/orders/sync")
+                .json_body_obj(&serde_json::json!({
+                    "id": "808058117761",
+                    "type": 1,
+                    "limitPrice": 580.5,
+                }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(SUCCESS);
+        })
+        .await;
+
+    let modify = ModifyOrderRequest::builder("808058117761")
+        .order_type(OrderType::Limit)
+        .limit_price(580.5)
+        .build();
+
+    let order = ctx.fyers.modify_order(&modify).await.unwrap();
+
+    assert_eq!(order.id, "808058117761");
+    mock.assert();
+}
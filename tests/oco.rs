@@ -0,0 +1,214 @@
+mod common;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use common::{SBIN, find, time};
+use fyers::exits::{Oco, OcoLeg, OcoOutcome};
+use fyers::paper::PaperTrader;
+use fyers::{
+    Broker, ExitPositionResult, FyersError, ModifyOrderRequest, Order, OrderDetails, OrderRequest,
+    OrderStatus, OrderType, Position, ProductType, Side,
+};
+
+fn long_exit() -> Oco {
+    Oco::new(SBIN, Side::Buy, 100, ProductType::Intraday, 600.0, 570.0).unwrap()
+}
+
+#[tokio::test]
+async fn target_fill_cancels_the_stop() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 590.0);
+
+    let mut oco = long_exit().order_tag("exit");
+    oco.place(&paper).await.unwrap();
+
+    let (target_id, stop_id) = oco.order_ids().unwrap();
+    let target = find(&paper, target_id).await;
+    assert_eq!(target.order_type, OrderType::Limit);
+    assert_eq!(target.side, Side::Sell);
+    assert_eq!(target.limit_price, 600.0);
+    assert_eq!(target.tag(), Some("exit"));
+
+    let stop = find(&paper, stop_id).await;
+    assert_eq!(stop.order_type, OrderType::Stop);
+    assert_eq!(stop.stop_price, 570.0);
+
+    // Nothing has filled yet
+    assert_eq!(oco.check(&paper).await.unwrap(), None);
+
+    paper.update_tick(SBIN, time(1), 601.0);
+
+    let report = oco.check(&paper).await.unwrap().unwrap();
+    assert_eq!(report.outcome, OcoOutcome::Closed);
+    assert_eq!(report.target_filled_qty, 100);
+    assert_eq!(report.stop_filled_qty, 0);
+    assert_eq!(find(&paper, stop_id).await.status, OrderStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn stop_fill_cancels_the_target() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 500.0);
+
+    let mut oco = Oco::new(SBIN, Side::Sell, 50, ProductType::Intraday, 480.0, 510.0).unwrap();
+    oco.place(&paper).await.unwrap();
+    let (target_id, stop_id) = oco.order_ids().unwrap();
+
+    paper.update_tick(SBIN, time(1), 512.0);
+
+    let report = oco.check(&paper).await.unwrap().unwrap();
+    assert_eq!(report.outcome, OcoOutcome::Closed);
+    assert_eq!(report.stop_filled_qty, 50);
+    assert_eq!(find(&paper, stop_id).await.side, Side::Buy);
+    assert_eq!(find(&paper, target_id).await.status, OrderStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn run_places_and_watches_both_legs() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 605.0);
+
+    // The target fills as soon as it is placed
+    let report = long_exit()
+        .poll_interval(Duration::from_millis(10))
+        .run(&paper)
+        .await
+        .unwrap();
+
+    assert_eq!(report.outcome, OcoOutcome::Closed);
+    assert_eq!(report.target_filled_qty, 100);
+    assert_eq!(
+        find(&paper, &report.stop_order_id).await.status,
+        OrderStatus::Cancelled
+    );
+}
+
+#[tokio::test]
+async fn partial_fill_resizes_the_sibling() {
+    let broker = PartialFills::default();
+    broker.paper.update_tick(SBIN, time(0), 590.0);
+
+    let mut oco = long_exit();
+    oco.place(&broker).await.unwrap();
+    let (target_id, stop_id) = oco.order_ids().unwrap();
+
+    broker.fill(target_id, 40);
+    assert_eq!(oco.check(&broker).await.unwrap(), None);
+
+    // The stop only covers what the target hasn't sold
+    assert_eq!(find(&broker, stop_id).await.qty, 60);
+    assert_eq!(find(&broker, target_id).await.qty, 100);
+
+    broker.fill(stop_id, 60);
+
+    let report = oco.check(&broker).await.unwrap().unwrap();
+    assert_eq!(report.outcome, OcoOutcome::Closed);
+    assert_eq!(report.target_filled_qty, 40);
+    assert_eq!(report.stop_filled_qty, 60);
+    assert_eq!(
+        find(&broker, target_id).await.status,
+        OrderStatus::Cancelled
+    );
+}
+
+#[tokio::test]
+async fn leg_closed_elsewhere_stops_the_oco() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 590.0);
+
+    let mut oco = long_exit();
+    oco.place(&paper).await.unwrap();
+    let (target_id, stop_id) = oco.order_ids().unwrap();
+
+    paper.cancel_order(stop_id).await.unwrap();
+
+    let report = oco.check(&paper).await.unwrap().unwrap();
+    assert_eq!(
+        report.outcome,
+        OcoOutcome::LegClosed {
+            leg: OcoLeg::Stop,
+            status: OrderStatus::Cancelled,
+        }
+    );
+
+    // The target is left working
+    assert_eq!(find(&paper, target_id).await.status, OrderStatus::Pending);
+}
+
+#[test]
+fn target_must_be_beyond_the_stop() {
+    let err = Oco::new(SBIN, Side::Buy, 100, ProductType::Intraday, 570.0, 600.0).unwrap_err();
+    assert!(matches!(err, FyersError::InvalidParams(_)));
+
+    let err = Oco::new(SBIN, Side::Sell, 100, ProductType::Intraday, 600.0, 570.0).unwrap_err();
+    assert!(matches!(err, FyersError::InvalidParams(_)));
+}
+
+// A paper trader whose orders can be given partial fills
+struct PartialFills {
+    paper: PaperTrader,
+    fills: Mutex<HashMap<String, u32>>,
+}
+
+impl Default for PartialFills {
+    fn default() -> Self {
+        Self {
+            paper: PaperTrader::new(100_000.0),
+            fills: Mutex::default(),
+        }
+    }
+}
+
+impl PartialFills {
+    fn fill(&self, id: &str, qty: u32) {
+        self.fills.lock().unwrap().insert(id.to_owned(), qty);
+    }
+}
+
+impl Broker for PartialFills {
+    fn place_order(
+        &self,
+        order: &OrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        self.paper.place_order(order)
+    }
+
+    fn cancel_order(&self, order_id: &str) -> impl Future<Output = Result<(), FyersError>> + Send {
+        self.paper.cancel_order(order_id)
+    }
+
+    fn modify_order(
+        &self,
+        modify: &ModifyOrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        self.paper.modify_order(modify)
+    }
+
+    fn orders(&self) -> impl Future<Output = Result<Vec<OrderDetails>, FyersError>> + Send {
+        let orders = self.paper.orders();
+        let fills = self.fills.lock().unwrap().clone();
+
+        async move {
+            let mut orders = orders.await?;
+            for order in &mut orders {
+                if let Some(&filled) = fills.get(&order.id) {
+                    order.filled_qty = filled;
+                }
+            }
+            Ok(orders)
+        }
+    }
+
+    fn positions(&self) -> impl Future<Output = Result<Vec<Position>, FyersError>> + Send {
+        self.paper.positions()
+    }
+
+    fn exit_all_positions(
+        &self,
+    ) -> impl Future<Output = Result<ExitPositionResult, FyersError>> + Send {
+        self.paper.exit_all_positions()
+    }
+}
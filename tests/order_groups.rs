@@ -0,0 +1,143 @@
+mod common;
+
+use fyers::{FyersError, OrderStatus, ProductType};
+use httpmock::prelude::*;
+
+const ORDER_BOOK: &str = include_str!("fixtures/orders/order_book_bracket.json");
+const MODIFY_SUCCESS: &str = include_str!("fixtures/order_modify_success.json");
+const CANCEL_SUCCESS: &str = include_str!("fixtures/order_cancel_success.json");
+
+async fn mock_order_book(server: &MockServer) {
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/orders");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(ORDER_BOOK);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn order_groups_link_legs_to_entry() {
+    let ctx = common::setup().await;
+    mock_order_book(&ctx.server).await;
+
+    let groups = ctx.fyers.order_groups().await.unwrap();
+
+    assert_eq!(groups.len(), 2);
+
+    let bracket = &groups[0];
+    assert_eq!(bracket.product_type(), ProductType::Bo);
+    assert_eq!(bracket.entry.id, "23080400089350");
+    assert_eq!(bracket.entry.parent_id, None);
+    assert_eq!(bracket.entry.status, OrderStatus::Filled);
+
+    let stop_loss = bracket.stop_loss.as_ref().unwrap();
+    assert_eq!(stop_loss.id, "23080400089351");
+    assert_eq!(stop_loss.parent_id.as_deref(), Some("23080400089350"));
+    assert_eq!(stop_loss.stop_price, 575.0);
+
+    let target = bracket.target.as_ref().unwrap();
+    assert_eq!(target.id, "23080400089352");
+    assert_eq!(target.limit_price, 590.0);
+    assert!(bracket.is_open());
+
+    let cover = &groups[1];
+    assert_eq!(cover.product_type(), ProductType::Co);
+    assert!(cover.stop_loss.is_none());
+    assert!(cover.target.is_none());
+}
+
+#[tokio::test]
+async fn modify_stop_loss_and_target_modify_the_legs() {
+    let ctx = common::setup().await;
+    mock_order_book(&ctx.server).await;
+
+    let stop_loss = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(PATCH)
+                .path("/orders/sync")
+                .json_body_obj(&serde_json::json!({
+                    "id": "23080400089351",
+                    "stopPrice": 578.0,
+                }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(MODIFY_SUCCESS);
+        })
+        .await;
+
+    let target = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(PATCH)
+                .path("/orders/sync")
+                .json_body_obj(&serde_json::json!({
+                    "id": "23080400089352",
+                    "limitPrice": 595.0,
+                }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(MODIFY_SUCCESS);
+        })
+        .await;
+
+    let groups = ctx.fyers.order_groups().await.unwrap();
+
+    ctx.fyers.modify_stop_loss(&groups[0], 578.0).await.unwrap();
+    ctx.fyers.modify_target(&groups[0], 595.0).await.unwrap();
+
+    stop_loss.assert();
+    target.assert();
+
+    // The cover order hasn't filled, so it has no legs yet
+    let err = ctx.fyers.modify_stop_loss(&groups[1], 590.0).await;
+    assert!(matches!(err, Err(FyersError::InvalidParams(_))));
+}
+
+#[tokio::test]
+async fn exit_order_group_cancels_one_order() {
+    let ctx = common::setup().await;
+    mock_order_book(&ctx.server).await;
+
+    let stop_loss = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(DELETE)
+                .path("/orders/sync")
+                .json_body_obj(&serde_json::json!({ "id": "23080400089351" }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(CANCEL_SUCCESS);
+        })
+        .await;
+
+    let entry = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(DELETE)
+                .path("/orders/sync")
+                .json_body_obj(&serde_json::json!({ "id": "23080400089353" }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(CANCEL_SUCCESS);
+        })
+        .await;
+
+    let groups = ctx.fyers.order_groups().await.unwrap();
+
+    // Filled entry: exit through the stop loss leg
+    ctx.fyers.exit_order_group(&groups[0]).await.unwrap();
+    // Pending entry: cancel it
+    ctx.fyers.exit_order_group(&groups[1]).await.unwrap();
+
+    stop_loss.assert();
+    entry.assert();
+}
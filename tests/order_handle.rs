@@ -0,0 +1,119 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{SBIN, time};
+use fyers::paper::PaperTrader;
+use fyers::{Broker, FyersError, OrderHandle, OrderRequest, OrderStatus, ProductType, Side};
+
+fn limit_buy(price: f64) -> OrderRequest {
+    OrderRequest::limit(SBIN, 10, Side::Buy, ProductType::Intraday, price).build()
+}
+
+#[tokio::test]
+async fn waits_for_a_fill() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 585.0);
+
+    let handle = OrderHandle::place(paper.clone(), &limit_buy(580.0))
+        .await
+        .unwrap()
+        .poll_interval(Duration::from_millis(5));
+    assert_eq!(handle.status().await.unwrap(), OrderStatus::Pending);
+
+    let feed = paper.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        feed.update_tick(SBIN, time(1), 579.0);
+    });
+
+    let details = handle
+        .wait_until_filled(Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert_eq!(details.status, OrderStatus::Filled);
+    assert_eq!(details.traded_price, 579.0);
+}
+
+#[tokio::test]
+async fn timeout_leaves_the_order_open() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 585.0);
+
+    let handle = OrderHandle::place(paper.clone(), &limit_buy(580.0))
+        .await
+        .unwrap()
+        .poll_interval(Duration::from_millis(5));
+
+    let details = handle
+        .wait_until_filled(Duration::from_millis(20))
+        .await
+        .unwrap();
+    assert_eq!(details.status, OrderStatus::Pending);
+
+    // Place, wait, then cancel
+    handle.cancel().await.unwrap();
+    let details = handle.wait_until_terminal().await.unwrap();
+    assert_eq!(details.status, OrderStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn modify_changes_the_order() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 585.0);
+
+    let handle = OrderHandle::place(paper.clone(), &limit_buy(580.0))
+        .await
+        .unwrap();
+    handle
+        .modify(|m| m.qty(5).limit_price(582.0))
+        .await
+        .unwrap();
+
+    let details = handle.details().await.unwrap();
+    assert_eq!(details.qty, 5);
+    assert_eq!(details.limit_price, 582.0);
+
+    // Moving the limit through the market fills the order
+    handle.modify(|m| m.limit_price(586.0)).await.unwrap();
+    assert_eq!(handle.status().await.unwrap(), OrderStatus::Filled);
+}
+
+#[tokio::test]
+async fn missing_and_closed_orders() {
+    let paper = PaperTrader::new(100_000.0);
+    let handle = OrderHandle::new(paper.clone(), "missing");
+
+    assert_eq!(handle.id(), "missing");
+    assert!(matches!(
+        handle.status().await,
+        Err(FyersError::InvalidOrderId)
+    ));
+
+    // Closed orders can't be changed
+    paper.update_tick(SBIN, time(0), 585.0);
+    let placed = paper.place_order(&limit_buy(590.0)).await.unwrap();
+    let handle = OrderHandle::new(paper.clone(), placed.id);
+    assert!(handle.modify(|m| m.qty(5)).await.is_err());
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn fyers_place_order_handle() {
+    use fyers::Endpoint;
+    use fyers::testing::FakeServer;
+
+    let server = FakeServer::start().await;
+    let fyers = server.client();
+
+    let handle = fyers.place_order_handle(&limit_buy(580.0)).await.unwrap();
+    assert_eq!(handle.id(), "808058117761");
+
+    // The handle can be waited on in another task
+    let waiting =
+        tokio::spawn(async move { handle.wait_until_filled(Duration::from_millis(10)).await });
+    waiting.await.unwrap().unwrap();
+
+    assert_eq!(server.requests(Endpoint::PlaceOrder), 1);
+    assert!(server.requests(Endpoint::Orders) >= 1);
+}
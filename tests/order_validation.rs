@@ -0,0 +1,176 @@
+use fyers::{
+    FyersError, InstrumentInfo, OrderBuilder, OrderRequest, OrderType, OrderViolation, ProductType,
+    Side, Validity,
+};
+
+fn builder(order_type: OrderType, product_type: ProductType) -> OrderBuilder {
+    OrderRequest::builder(
+        "NSE:SBIN-EQ",
+        10,
+        order_type,
+        Side::Buy,
+        product_type,
+        Validity::Day,
+    )
+}
+
+#[test]
+fn valid_order_builds() {
+    let order = builder(OrderType::Limit, ProductType::Intraday)
+        .limit_price(580.5)
+        .order_tag("strategy1")
+        .try_build();
+
+    assert!(order.is_ok());
+}
+
+#[test]
+fn every_violation_is_listed() {
+    let err = OrderRequest::builder(
+        "NSE:SBIN-EQ",
+        0,
+        OrderType::StopLimit,
+        Side::Buy,
+        ProductType::Bo,
+        Validity::Day,
+    )
+    .disclosed_qty(5)
+    .order_tag("my tag!")
+    .try_build()
+    .unwrap_err();
+
+    assert_eq!(
+        err.violations(),
+        [
+            OrderViolation::ZeroQty,
+            OrderViolation::MissingLimitPrice(OrderType::StopLimit),
+            OrderViolation::MissingStopPrice(OrderType::StopLimit),
+            OrderViolation::MissingStopLoss(ProductType::Bo),
+            OrderViolation::MissingTakeProfit,
+            OrderViolation::DisclosedQtyTooLarge {
+                disclosed_qty: 5,
+                qty: 0
+            },
+            OrderViolation::InvalidOrderTag("my tag!".into()),
+        ]
+    );
+
+    let message = err.to_string();
+    assert!(message.starts_with("invalid order: qty must be positive; "));
+}
+
+#[test]
+fn cover_orders_need_only_a_stop_loss() {
+    let err = builder(OrderType::Market, ProductType::Co)
+        .try_build()
+        .unwrap_err();
+    assert_eq!(
+        err.violations(),
+        [OrderViolation::MissingStopLoss(ProductType::Co)]
+    );
+
+    let order = builder(OrderType::Market, ProductType::Co)
+        .stop_loss(575.0)
+        .try_build();
+    assert!(order.is_ok());
+}
+
+#[test]
+fn stop_limit_must_be_fillable() {
+    let err = OrderRequest::builder(
+        "NSE:SBIN-EQ",
+        1,
+        OrderType::StopLimit,
+        Side::Sell,
+        ProductType::Intraday,
+        Validity::Day,
+    )
+    .stop_price(570.0)
+    .limit_price(575.0)
+    .try_build()
+    .unwrap_err();
+
+    assert_eq!(
+        err.violations(),
+        [OrderViolation::StopLimitInverted {
+            stop_price: 570.0,
+            limit_price: 575.0
+        }]
+    );
+}
+
+#[test]
+fn lot_and_tick_size_are_checked() {
+    let nifty = InstrumentInfo::new(75, 0.05);
+
+    let err = OrderRequest::builder(
+        "NSE:NIFTY25JANFUT",
+        100,
+        OrderType::StopLimit,
+        Side::Buy,
+        ProductType::Margin,
+        Validity::Day,
+    )
+    .stop_price(23_500.05)
+    .limit_price(23_510.03)
+    .instrument(nifty)
+    .try_build()
+    .unwrap_err();
+
+    assert_eq!(
+        err.violations(),
+        [
+            OrderViolation::LotSize {
+                qty: 100,
+                lot_size: 75
+            },
+            OrderViolation::TickSize {
+                field: "limit_price",
+                price: 23_510.03,
+                tick_size: 0.05
+            },
+        ]
+    );
+
+    let order = OrderRequest::builder(
+        "NSE:NIFTY25JANFUT",
+        150,
+        OrderType::Limit,
+        Side::Buy,
+        ProductType::Margin,
+        Validity::Day,
+    )
+    .limit_price(23_510.35)
+    .instrument(nifty)
+    .try_build();
+    assert!(order.is_ok());
+}
+
+#[test]
+fn checks_freeze_qty_unless_sliced() {
+    let nifty = InstrumentInfo::new(75, 0.05).with_freeze_qty(1801);
+    let order = |slice| {
+        OrderRequest::market("NSE:NIFTY25JANFUT", 1875, Side::Buy, ProductType::Margin)
+            .slice_order(slice)
+            .instrument(nifty)
+            .try_build()
+    };
+
+    assert_eq!(
+        order(false).unwrap_err().violations(),
+        [OrderViolation::FreezeQty {
+            qty: 1875,
+            freeze_qty: 1801
+        }]
+    );
+    assert!(order(true).is_ok());
+}
+
+#[test]
+fn converts_into_fyers_error() {
+    fn place() -> Result<OrderRequest, FyersError> {
+        Ok(builder(OrderType::Limit, ProductType::Intraday).try_build()?)
+    }
+
+    assert!(matches!(place(), Err(FyersError::InvalidOrder(_))));
+}
@@ -0,0 +1,46 @@
+mod common;
+use fyers::{OrderStatus, OrderType, ProductType, Side, Validity};
+use httpmock::prelude::*;
+
+const ORDER_BOOK: &str = include_str!("fixtures/orders/order_book_success.json");
+
+#[tokio::test]
+async fn order_book_success() {
+    let ctx = common::setup().await;
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/orders")
+                .header("Authorization", "TEST_CLIENT_ID:TEST_ACCESS_TOKEN");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(ORDER_BOOK);
+        })
+        .await;
+
+    let orders = ctx.fyers.orders().await.unwrap();
+
+    assert_eq!(orders.len(), 2);
+
+    let filled = &orders[0];
+    assert_eq!(filled.id, "23080400089344");
+    assert_eq!(filled.status, OrderStatus::Filled);
+    assert_eq!(filled.order_type, OrderType::Market);
+    assert_eq!(filled.side, Side::Sell);
+    assert_eq!(filled.product_type, ProductType::Cnc);
+    assert_eq!(filled.validity, Validity::Day);
+    assert_eq!(filled.traded_price, 6.95);
+    assert_eq!(filled.tag(), Some("strategy1"));
+    assert!(filled.status.is_terminal());
+
+    let pending = &orders[1];
+    assert_eq!(pending.status, OrderStatus::Pending);
+    assert_eq!(pending.remaining_quantity, 10);
+    assert_eq!(pending.limit_price, 580.5);
+    assert!(pending.status.is_open());
+
+    mock.assert();
+}
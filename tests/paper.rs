@@ -0,0 +1,298 @@
+mod common;
+
+use common::{SBIN, time};
+use fyers::paper::{PaperTrader, Slippage};
+use fyers::{
+    Broker, Candle, ExitPositionResult, FyersError, ModifyOrderRequest, OrderRequest, OrderStatus,
+    OrderType, ProductType, Side, Validity,
+};
+
+fn candle(minute: u32, open: f64, high: f64, low: f64, close: f64) -> Candle {
+    Candle {
+        time: time(minute),
+        open,
+        high,
+        low,
+        close,
+        volume: 1000,
+        open_interest: None,
+    }
+}
+
+fn order(qty: u32, order_type: OrderType, side: Side) -> fyers::OrderBuilder {
+    OrderRequest::builder(
+        SBIN,
+        qty,
+        order_type,
+        side,
+        ProductType::Intraday,
+        Validity::Day,
+    )
+}
+
+async fn status(paper: &PaperTrader, id: &str) -> OrderStatus {
+    let orders = paper.orders().await.unwrap();
+    orders.iter().find(|o| o.id == id).unwrap().status
+}
+
+#[tokio::test]
+async fn market_order_fills_at_next_open() {
+    let paper = PaperTrader::new(10_000.0);
+
+    let placed = paper
+        .place_order(
+            &order(10, OrderType::Market, Side::Buy)
+                .order_tag("s1")
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status(&paper, &placed.id).await, OrderStatus::Pending);
+
+    paper.update_candle(SBIN, &candle(0, 500.0, 510.0, 495.0, 505.0));
+
+    let orders = paper.orders().await.unwrap();
+    assert_eq!(orders[0].status, OrderStatus::Filled);
+    assert_eq!(orders[0].traded_price, 500.0);
+    assert_eq!(orders[0].tag(), Some("s1"));
+
+    let positions = paper.positions().await.unwrap();
+    assert_eq!(positions[0].id, "NSE:SBIN-EQ-INTRADAY");
+    assert_eq!(positions[0].net_qty, 10);
+    assert_eq!(positions[0].ltp, 505.0);
+    assert_eq!(positions[0].unrealized_profit, 50.0);
+
+    let funds = paper.funds();
+    assert_eq!(funds.cash, 5_000.0);
+    assert_eq!(funds.equity, 10_050.0);
+}
+
+#[tokio::test]
+async fn limit_orders_fill_at_limit_or_better() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 500.0);
+
+    let buy = paper
+        .place_order(
+            &order(1, OrderType::Limit, Side::Buy)
+                .limit_price(490.0)
+                .build(),
+        )
+        .await
+        .unwrap();
+    let sell = paper
+        .place_order(
+            &order(1, OrderType::Limit, Side::Sell)
+                .limit_price(505.0)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    // Gaps down through the buy limit
+    paper.update_candle(SBIN, &candle(1, 485.0, 492.0, 480.0, 490.0));
+    // Rallies through the sell limit
+    paper.update_candle(SBIN, &candle(2, 495.0, 510.0, 494.0, 508.0));
+
+    let orders = paper.orders().await.unwrap();
+    assert_eq!(orders[0].id, buy.id);
+    assert_eq!(orders[0].traded_price, 485.0);
+    assert_eq!(orders[1].id, sell.id);
+    assert_eq!(orders[1].traded_price, 505.0);
+
+    let funds = paper.funds();
+    assert_eq!(funds.realized_pnl, 20.0);
+    assert_eq!(funds.unrealized_pnl, 0.0);
+}
+
+#[tokio::test]
+async fn stop_orders_trigger_on_touch() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 500.0);
+
+    let stop = paper
+        .place_order(
+            &order(1, OrderType::Stop, Side::Buy)
+                .stop_price(505.0)
+                .build(),
+        )
+        .await
+        .unwrap();
+    let stop_limit = paper
+        .place_order(
+            &order(1, OrderType::StopLimit, Side::Buy)
+                .stop_price(510.0)
+                .limit_price(511.0)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    // Triggers both, but runs away from the stop-limit's limit
+    paper.update_candle(SBIN, &candle(1, 512.0, 520.0, 512.0, 518.0));
+    assert_eq!(status(&paper, &stop.id).await, OrderStatus::Filled);
+    assert_eq!(status(&paper, &stop_limit.id).await, OrderStatus::Pending);
+
+    // Once triggered, the stop-limit works as a limit order
+    paper.update_candle(SBIN, &candle(2, 515.0, 516.0, 509.0, 510.0));
+
+    let orders = paper.orders().await.unwrap();
+    assert_eq!(orders[0].traded_price, 512.0);
+    assert_eq!(orders[1].status, OrderStatus::Filled);
+    assert_eq!(orders[1].traded_price, 511.0);
+}
+
+#[tokio::test]
+async fn buys_without_enough_cash_are_rejected() {
+    let paper = PaperTrader::new(1_000.0);
+    paper.update_tick(SBIN, time(0), 500.0);
+
+    let err = paper
+        .place_order(&order(3, OrderType::Market, Side::Buy).build())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FyersError::Api { code: -99, .. }));
+
+    let orders = paper.orders().await.unwrap();
+    assert_eq!(orders[0].status, OrderStatus::Rejected);
+    assert_eq!(paper.funds().cash, 1_000.0);
+}
+
+#[tokio::test]
+async fn invalid_orders_are_refused() {
+    let paper = PaperTrader::new(1_000.0);
+
+    let err = paper
+        .place_order(&order(1, OrderType::Limit, Side::Buy).build())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FyersError::InvalidParams(_)));
+
+    let err = paper.cancel_order("404").await.unwrap_err();
+    assert!(matches!(err, FyersError::InvalidOrderId));
+
+    assert!(paper.orders().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn pending_orders_can_be_modified_and_cancelled() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 500.0);
+
+    let first = paper
+        .place_order(
+            &order(1, OrderType::Limit, Side::Buy)
+                .limit_price(450.0)
+                .build(),
+        )
+        .await
+        .unwrap();
+    let second = paper
+        .place_order(
+            &order(1, OrderType::Limit, Side::Buy)
+                .limit_price(450.0)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    // Moving the limit above the last price fills straight away
+    let modify = ModifyOrderRequest::builder(&first.id)
+        .qty(2)
+        .limit_price(501.0)
+        .build();
+    paper.modify_order(&modify).await.unwrap();
+
+    let orders = paper.orders().await.unwrap();
+    assert_eq!(orders[0].status, OrderStatus::Filled);
+    assert_eq!(orders[0].filled_qty, 2);
+    assert_eq!(orders[0].traded_price, 500.0);
+
+    paper.cancel_order(&second.id).await.unwrap();
+    assert_eq!(status(&paper, &second.id).await, OrderStatus::Cancelled);
+
+    // Neither can change any more
+    let err = paper.cancel_order(&first.id).await.unwrap_err();
+    assert!(matches!(err, FyersError::InvalidParams(_)));
+    let err = paper.modify_order(&modify).await.unwrap_err();
+    assert!(matches!(err, FyersError::InvalidParams(_)));
+}
+
+#[tokio::test]
+async fn ioc_orders_are_cancelled_when_not_filled() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 500.0);
+
+    let request = OrderRequest::builder(
+        SBIN,
+        1,
+        OrderType::Limit,
+        Side::Buy,
+        ProductType::Intraday,
+        Validity::Ioc,
+    )
+    .limit_price(490.0)
+    .build();
+    let placed = paper.place_order(&request).await.unwrap();
+
+    assert_eq!(status(&paper, &placed.id).await, OrderStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn exit_all_positions_closes_longs_and_shorts() {
+    let paper = PaperTrader::new(100_000.0);
+    let infy = "NSE:INFY-EQ";
+    paper.update_tick(SBIN, time(0), 500.0);
+    paper.update_tick(infy, time(0), 1500.0);
+
+    paper
+        .place_order(&order(10, OrderType::Market, Side::Buy).build())
+        .await
+        .unwrap();
+    let short = OrderRequest::builder(
+        infy,
+        2,
+        OrderType::Market,
+        Side::Sell,
+        ProductType::Intraday,
+        Validity::Day,
+    )
+    .build();
+    paper.place_order(&short).await.unwrap();
+
+    paper.update_tick(SBIN, time(1), 510.0);
+    paper.update_tick(infy, time(1), 1490.0);
+
+    assert_eq!(
+        paper.exit_all_positions().await.unwrap(),
+        ExitPositionResult::Closed
+    );
+
+    let positions = paper.positions().await.unwrap();
+    assert!(positions.iter().all(|p| !p.is_open()));
+
+    let funds = paper.funds();
+    assert_eq!(funds.realized_pnl, 120.0);
+    assert_eq!(funds.cash, 100_120.0);
+    assert_eq!(funds.equity, funds.cash);
+}
+
+#[tokio::test]
+async fn percent_slippage_is_a_percentage() {
+    let paper = PaperTrader::new(100_000.0).with_slippage(Slippage::Percent(0.5));
+
+    paper
+        .place_order(&order(10, OrderType::Market, Side::Buy).build())
+        .await
+        .unwrap();
+    paper.update_candle(SBIN, &candle(0, 500.0, 510.0, 495.0, 505.0));
+
+    let orders = paper.orders().await.unwrap();
+    assert!((orders[0].traded_price - 502.5).abs() < 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "invalid slippage")]
+fn slippage_of_100_percent_is_refused() {
+    let _ = PaperTrader::new(100_000.0).with_slippage(Slippage::Percent(100.0));
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use fyers::ProductType;
+use httpmock::prelude::*;
+
+const SUCCESS: &str = include_str!("fixtures/positions/positions_success.json");
+
+#[tokio::test]
+async fn positions_success() {
+    let ctx = common::setup().await;
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/positions");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(SUCCESS);
+        })
+        .await;
+
+    let positions = ctx.fyers.positions().await.unwrap();
+
+    assert_eq!(positions.len(), 2);
+
+    let open = &positions[0];
+    assert_eq!(open.id, "MCX:SILVERMIC20NOVFUT-MARGIN");
+    assert_eq!(open.product_type, ProductType::Margin);
+    assert_eq!(open.net_qty, 1);
+    assert_eq!(open.realized_profit, 400.0);
+    assert_eq!(open.unrealized_profit, 461.0);
+    assert!(open.is_open());
+
+    let closed = &positions[1];
+    assert_eq!(closed.net_qty, 0);
+    assert_eq!(closed.pl, -12.5);
+    assert!(!closed.is_open());
+
+    mock.assert();
+}
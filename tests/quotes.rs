@@ -0,0 +1,42 @@
+mod common;
+
+use httpmock::prelude::*;
+
+const QUOTES_SUCCESS: &str = include_str!("fixtures/quotes_success.json");
+
+#[tokio::test]
+async fn quotes_success() {
+    let ctx = common::setup().await;
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/quotes")
+                .query_param("symbols", "NSE:SBIN-EQ,NSE:IDEA-EQ");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(QUOTES_SUCCESS);
+        })
+        .await;
+
+    let quotes = ctx
+        .fyers
+        .quotes(&["NSE:SBIN-EQ", "NSE:IDEA-EQ"])
+        .await
+        .unwrap();
+
+    assert_eq!(quotes.len(), 2);
+
+    let sbin = &quotes[0];
+    assert_eq!(sbin.symbol, "NSE:SBIN-EQ");
+    assert_eq!(sbin.ltp, 581.2);
+    assert_eq!(sbin.bid, 581.15);
+    assert_eq!(sbin.ask, 581.25);
+    assert_eq!(sbin.prev_close, 580.0);
+    assert_eq!(sbin.volume, 1_520_340);
+
+    assert_eq!(quotes[1].symbol, "NSE:IDEA-EQ");
+    mock.assert();
+}
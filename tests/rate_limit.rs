@@ -0,0 +1,176 @@
+mod common;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use fyers::{
+    FyersError, HistoryRequest, OrderRequest, ProductType, RateLimitPolicy, RateLimiter,
+    RateLimits, RetryConfig, RetryPolicy, Side,
+};
+use httpmock::prelude::*;
+
+const PROFILE_SUCCESS: &str = include_str!("fixtures/profile_success.json");
+const HISTORY_SUCCESS: &str = include_str!("fixtures/history_success.json");
+const ORDER_SUCCESS: &str = include_str!("fixtures/order_success.json");
+
+fn limiter(per_second: u32, policy: RateLimitPolicy) -> RateLimiter {
+    RateLimiter::builder()
+        .order_limits(RateLimits::new(per_second, 200, 100_000))
+        .policy(policy)
+        .build()
+}
+
+#[tokio::test]
+async fn fail_fast_does_not_send_request() {
+    let ctx = common::setup().await;
+    let fyers = ctx
+        .fyers
+        .with_rate_limiter(limiter(2, RateLimitPolicy::FailFast));
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/profile");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PROFILE_SUCCESS);
+        })
+        .await;
+
+    fyers.profile().await.unwrap();
+    fyers.profile().await.unwrap();
+
+    match fyers.profile().await {
+        Err(FyersError::LocalRateLimit) => {}
+        other => panic!("Expected LocalRateLimit error, got {other:?}"),
+    }
+
+    mock.assert_calls(2);
+}
+
+#[tokio::test]
+async fn fail_fast_orders_are_not_retried() {
+    let ctx = common::setup().await;
+    let fyers = ctx
+        .fyers
+        .with_rate_limiter(limiter(1, RateLimitPolicy::FailFast))
+        .with_retry_config(RetryConfig::new(
+            RetryPolicy::new(3).initial_backoff(Duration::from_secs(1)),
+        ));
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(POST).path("/orders/sync");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(ORDER_SUCCESS);
+        })
+        .await;
+
+    let order = OrderRequest::market("NSE:SBIN-EQ", 1, Side::Buy, ProductType::Intraday)
+        .order_tag("ff1")
+        .build();
+    fyers.place_order(&order).await.unwrap();
+
+    let start = Instant::now();
+    assert!(matches!(
+        fyers.place_order(&order).await,
+        Err(FyersError::LocalRateLimit)
+    ));
+    assert!(start.elapsed() < Duration::from_millis(500));
+
+    mock.assert_calls(1);
+}
+
+#[tokio::test]
+async fn wait_policy_delays_request() {
+    let ctx = common::setup().await;
+    let fyers = ctx
+        .fyers
+        .with_rate_limiter(limiter(2, RateLimitPolicy::Wait));
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/profile");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PROFILE_SUCCESS);
+        })
+        .await;
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        fyers.profile().await.unwrap();
+    }
+
+    // Two tokens per second: the third request waits for half a second
+    assert!(start.elapsed() >= Duration::from_millis(450));
+
+    mock.assert_calls(3);
+}
+
+#[tokio::test]
+async fn quota_is_shared_across_clones() {
+    let ctx = common::setup().await;
+    let fyers = ctx
+        .fyers
+        .with_rate_limiter(limiter(1, RateLimitPolicy::FailFast));
+    let clone = fyers.clone();
+
+    ctx.server
+        .mock_async(|when, then| {
+            when.method(GET).path("/profile");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PROFILE_SUCCESS);
+        })
+        .await;
+
+    fyers.profile().await.unwrap();
+
+    assert!(matches!(
+        clone.profile().await,
+        Err(FyersError::LocalRateLimit)
+    ));
+}
+
+#[tokio::test]
+async fn data_and_order_apis_have_separate_quotas() {
+    let ctx = common::setup().await;
+    let fyers = ctx
+        .fyers
+        .with_rate_limiter(limiter(1, RateLimitPolicy::FailFast));
+
+    ctx.server
+        .mock_async(|when, then| {
+            when.method(GET).path("/profile");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PROFILE_SUCCESS);
+        })
+        .await;
+
+    let history = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/history");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(HISTORY_SUCCESS);
+        })
+        .await;
+
+    fyers.profile().await.unwrap();
+
+    let request = HistoryRequest::builder("NSE:SBIN-EQ", Utc::now(), Utc::now()).build();
+    fyers.history(&request).await.unwrap();
+
+    history.assert();
+}
@@ -0,0 +1,129 @@
+use fyers::auth::{AuthError, RedirectListener};
+
+#[tokio::test]
+async fn captures_redirect_with_auth_code() {
+    let listener = RedirectListener::bind("http://127.0.0.1:0/callback")
+        .await
+        .unwrap();
+    let redirect_uri = listener.redirect_uri().to_owned();
+
+    assert!(!redirect_uri.contains(":0/"));
+
+    let browser = async {
+        let client = reqwest::Client::new();
+
+        // Unrelated requests are ignored
+        let favicon = client
+            .get(redirect_uri.replace("/callback", "/favicon.ico"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(favicon.status(), 404);
+
+        client
+            .get(format!(
+                "{redirect_uri}?s=ok&code=200&auth_code=AUTH_CODE&state=STATE"
+            ))
+            .send()
+            .await
+            .unwrap()
+    };
+
+    let (result, response) = tokio::join!(listener.wait_for_redirect("STATE"), browser);
+    let url = result.unwrap();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(url.path(), "/callback");
+    assert!(
+        url.query_pairs()
+            .any(|(k, v)| k == "auth_code" && v == "AUTH_CODE")
+    );
+}
+
+#[tokio::test]
+async fn ignores_mismatched_state() {
+    let listener = RedirectListener::bind("http://localhost:0/callback")
+        .await
+        .unwrap();
+    let redirect_uri = listener.redirect_uri().to_owned();
+
+    let browser = async {
+        let forged = reqwest::get(format!("{redirect_uri}?auth_code=FORGED&state=FORGED"))
+            .await
+            .unwrap();
+        assert_eq!(forged.status(), 400);
+
+        reqwest::get(format!("{redirect_uri}?auth_code=AUTH_CODE&state=STATE"))
+            .await
+            .unwrap()
+    };
+
+    let (result, response) = tokio::join!(listener.wait_for_redirect("STATE"), browser);
+
+    assert_eq!(response.status(), 200);
+    assert!(
+        result
+            .unwrap()
+            .query_pairs()
+            .any(|(k, v)| k == "auth_code" && v == "AUTH_CODE")
+    );
+}
+
+#[tokio::test]
+async fn idle_connections_dont_block_the_redirect() {
+    let listener = RedirectListener::bind("http://127.0.0.1:0/callback")
+        .await
+        .unwrap();
+    let redirect_uri = listener.redirect_uri().to_owned();
+    let addr = redirect_uri
+        .trim_start_matches("http://")
+        .trim_end_matches("/callback")
+        .to_owned();
+
+    let browser = async {
+        // A preconnected socket that never sends a request
+        let _idle = tokio::net::TcpStream::connect(&addr).await.unwrap();
+
+        reqwest::get(format!("{redirect_uri}?auth_code=AUTH_CODE&state=STATE"))
+            .await
+            .unwrap()
+    };
+
+    let (result, response) = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        tokio::join!(listener.wait_for_redirect("STATE"), browser)
+    })
+    .await
+    .unwrap();
+
+    assert!(result.is_ok());
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn rejects_missing_auth_code() {
+    let listener = RedirectListener::bind("http://127.0.0.1:0/callback")
+        .await
+        .unwrap();
+    let redirect_uri = listener.redirect_uri().to_owned();
+
+    let browser = async {
+        reqwest::get(format!("{redirect_uri}?s=error&state=STATE"))
+            .await
+            .unwrap()
+    };
+
+    let (result, _) = tokio::join!(listener.wait_for_redirect("STATE"), browser);
+
+    assert!(matches!(result, Err(AuthError::MissingAuthCode)));
+}
+
+#[tokio::test]
+async fn rejects_non_local_redirect_uri() {
+    for uri in [
+        "https://example.com/callback",
+        "https://127.0.0.1:0/callback",
+    ] {
+        let err = RedirectListener::bind(uri).await.unwrap_err();
+        assert!(matches!(err, AuthError::InvalidRedirectUri(_)));
+    }
+}
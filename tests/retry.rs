@@ -0,0 +1,181 @@
+mod common;
+use std::time::Duration;
+
+use fyers::{
+    Endpoint, FyersError, OrderRequest, OrderType, ProductType, RetryConfig, RetryPolicy, Side,
+    Validity,
+};
+use httpmock::prelude::*;
+
+const ORDER_BOOK: &str = include_str!("fixtures/orders/order_book_success.json");
+const ORDER_BOOK_EMPTY: &str = include_str!("fixtures/orders/order_book_empty.json");
+const INVALID_TOKEN: &str = include_str!("fixtures/error/invalid_token.json");
+
+fn policy(max_retries: u32) -> RetryPolicy {
+    RetryPolicy::new(max_retries).initial_backoff(Duration::from_millis(1))
+}
+
+fn order(tag: Option<&str>) -> OrderRequest {
+    let builder = OrderRequest::builder(
+        "NSE:IDEA-EQ",
+        1,
+        OrderType::Market,
+        Side::Sell,
+        ProductType::Cnc,
+        Validity::Day,
+    );
+
+    match tag {
+        Some(tag) => builder.order_tag(tag).build(),
+        None => builder.build(),
+    }
+}
+
+#[tokio::test]
+async fn server_errors_are_retried() {
+    let ctx = common::setup().await;
+    let fyers = ctx.fyers.with_retry_config(RetryConfig::new(policy(2)));
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/profile");
+            then.status(503).body("Service Unavailable");
+        })
+        .await;
+
+    let result = fyers.profile().await;
+
+    assert!(matches!(result, Err(FyersError::HttpStatus { .. })));
+    mock.assert_calls(3);
+}
+
+#[tokio::test]
+async fn api_errors_are_not_retried() {
+    let ctx = common::setup().await;
+    let fyers = ctx.fyers.with_retry_config(RetryConfig::new(policy(2)));
+
+    let mock = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/profile");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(INVALID_TOKEN);
+        })
+        .await;
+
+    let result = fyers.profile().await;
+
+    assert!(matches!(result, Err(FyersError::InvalidToken)));
+    mock.assert_calls(1);
+}
+
+#[tokio::test]
+async fn policy_can_be_set_per_endpoint() {
+    let ctx = common::setup().await;
+    let fyers = ctx
+        .fyers
+        .with_retry_config(RetryConfig::none().endpoint(Endpoint::Profile, policy(1)));
+
+    let profile = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/profile");
+            then.status(500);
+        })
+        .await;
+
+    let orders = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/orders");
+            then.status(500);
+        })
+        .await;
+
+    assert!(fyers.profile().await.is_err());
+    assert!(fyers.orders().await.is_err());
+
+    profile.assert_calls(2);
+    orders.assert_calls(1);
+}
+
+#[tokio::test]
+async fn place_order_returns_existing_order_with_same_tag() {
+    let ctx = common::setup().await;
+    let fyers = ctx.fyers.with_retry_config(RetryConfig::new(policy(3)));
+
+    let place = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(POST).path("/orders/sync");
+            then.status(502).body("Bad Gateway");
+        })
+        .await;
+
+    let order_book = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/orders");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(ORDER_BOOK);
+        })
+        .await;
+
+    let order = fyers.place_order(&order(Some("strategy1"))).await.unwrap();
+
+    assert_eq!(order.id, "23080400089344");
+    place.assert_calls(1);
+    order_book.assert_calls(1);
+}
+
+#[tokio::test]
+async fn place_order_is_retried_when_not_in_order_book() {
+    let ctx = common::setup().await;
+    let fyers = ctx.fyers.with_retry_config(RetryConfig::new(policy(2)));
+
+    let place = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(POST).path("/orders/sync");
+            then.status(500);
+        })
+        .await;
+
+    let order_book = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(GET).path("/orders");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(ORDER_BOOK_EMPTY);
+        })
+        .await;
+
+    let result = fyers.place_order(&order(Some("unique-tag"))).await;
+
+    assert!(matches!(result, Err(FyersError::HttpStatus { .. })));
+    place.assert_calls(3);
+    order_book.assert_calls(2);
+}
+
+#[tokio::test]
+async fn untagged_place_order_is_not_retried() {
+    let ctx = common::setup().await;
+    let fyers = ctx.fyers.with_retry_config(RetryConfig::new(policy(3)));
+
+    let place = ctx
+        .server
+        .mock_async(|when, then| {
+            when.method(POST).path("/orders/sync");
+            then.status(500);
+        })
+        .await;
+
+    let result = fyers.place_order(&order(None)).await;
+
+    assert!(matches!(result, Err(FyersError::HttpStatus { .. })));
+    place.assert_calls(1);
+}
@@ -0,0 +1,166 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use fyers::paper::PaperTrader;
+use fyers::slicing::OrderSlicer;
+use fyers::{
+    Broker, ExitPositionResult, FyersError, InstrumentInfo, ModifyOrderRequest, Order,
+    OrderDetails, OrderRequest, OrderViolation, Position, ProductType, Side,
+};
+
+const NIFTY: &str = "NSE:NIFTY25JANFUT";
+
+fn nifty() -> InstrumentInfo {
+    InstrumentInfo::new(75, 0.05).with_freeze_qty(1801)
+}
+
+fn order(qty: u32) -> OrderRequest {
+    OrderRequest::market(NIFTY, qty, Side::Buy, ProductType::Margin).build()
+}
+
+#[test]
+fn slices_stay_below_freeze_qty() {
+    let slices = OrderSlicer::new(nifty())
+        .tag_prefix("rebal")
+        .slice(&order(3750))
+        .unwrap();
+
+    let qty: Vec<_> = slices.iter().map(|s| s.qty()).collect();
+    assert_eq!(qty, [1800, 1800, 150]);
+
+    let tags: Vec<_> = slices.iter().map(|s| s.order_tag().unwrap()).collect();
+    assert_eq!(tags, ["rebal1", "rebal2", "rebal3"]);
+
+    // Nothing to split
+    let slices = OrderSlicer::new(nifty()).slice(&order(750)).unwrap();
+    assert_eq!(slices.len(), 1);
+    assert_eq!(slices[0].order_tag(), None);
+}
+
+#[test]
+fn random_sizes_are_whole_lots() {
+    let slicer = OrderSlicer::new(nifty()).random_sizes(true);
+
+    for _ in 0..20 {
+        let slices = slicer.slice(&order(7500)).unwrap();
+
+        assert_eq!(slices.iter().map(|s| s.qty()).sum::<u32>(), 7500);
+        for slice in &slices {
+            assert!(slice.qty() < 1801);
+            assert!(slice.qty().is_multiple_of(75));
+        }
+        // Every slice but the last is at least half the largest slice
+        for slice in &slices[..slices.len() - 1] {
+            assert!(slice.qty() >= 900);
+        }
+    }
+}
+
+#[test]
+fn invalid_slices_are_rejected() {
+    let err = OrderSlicer::new(nifty()).slice(&order(3700)).unwrap_err();
+    let FyersError::InvalidOrder(err) = err else {
+        panic!("expected an invalid order, got {err:?}");
+    };
+    assert_eq!(
+        err.violations(),
+        [OrderViolation::LotSize {
+            qty: 100,
+            lot_size: 75
+        }]
+    );
+
+    let tiny = InstrumentInfo::new(75, 0.05).with_freeze_qty(50);
+    let err = OrderSlicer::new(tiny).slice(&order(750)).unwrap_err();
+    assert!(matches!(err, FyersError::InvalidParams(_)));
+}
+
+#[tokio::test]
+async fn place_reports_aggregate_fills() {
+    let paper = PaperTrader::new(1_000_000.0);
+    paper.update_tick(NIFTY, fyers::ist_datetime(2025, 1, 1, 9, 15), 100.0);
+
+    let report = OrderSlicer::new(nifty())
+        .tag_prefix("rebal")
+        .place(&paper, &order(3750))
+        .await
+        .unwrap();
+
+    assert!(report.is_complete());
+    assert_eq!(report.orders.len(), 3);
+    assert_eq!(report.placed_qty(), 3750);
+
+    let status = report.fill_status(&paper).await.unwrap();
+    assert!(status.is_filled());
+    assert_eq!(status.pending_qty, 0);
+    assert_eq!(status.unfilled_qty(), 0);
+    assert_eq!(status.avg_price, 100.0);
+
+    let orders = paper.orders().await.unwrap();
+    assert_eq!(orders[2].tag(), Some("rebal3"));
+}
+
+// Rejects the first order with a rate limit error
+struct RateLimitedOnce {
+    paper: PaperTrader,
+    attempts: AtomicU32,
+}
+
+impl Broker for RateLimitedOnce {
+    fn place_order(
+        &self,
+        order: &OrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        let first = self.attempts.fetch_add(1, Ordering::SeqCst) == 0;
+        let placed = (!first).then(|| self.paper.place_order(order));
+
+        async move {
+            match placed {
+                Some(placed) => placed.await,
+                None => Err(FyersError::RateLimited),
+            }
+        }
+    }
+
+    fn cancel_order(&self, order_id: &str) -> impl Future<Output = Result<(), FyersError>> + Send {
+        self.paper.cancel_order(order_id)
+    }
+
+    fn modify_order(
+        &self,
+        modify: &ModifyOrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        self.paper.modify_order(modify)
+    }
+
+    fn orders(&self) -> impl Future<Output = Result<Vec<OrderDetails>, FyersError>> + Send {
+        self.paper.orders()
+    }
+
+    fn positions(&self) -> impl Future<Output = Result<Vec<Position>, FyersError>> + Send {
+        self.paper.positions()
+    }
+
+    fn exit_all_positions(
+        &self,
+    ) -> impl Future<Output = Result<ExitPositionResult, FyersError>> + Send {
+        self.paper.exit_all_positions()
+    }
+}
+
+#[tokio::test]
+async fn place_retries_rate_limited_slices() {
+    let broker = RateLimitedOnce {
+        paper: PaperTrader::new(1_000_000.0),
+        attempts: AtomicU32::new(0),
+    };
+
+    let report = OrderSlicer::new(nifty())
+        .place(&broker, &order(1875))
+        .await
+        .unwrap();
+
+    assert!(report.is_complete());
+    assert_eq!(broker.attempts.load(Ordering::SeqCst), 3);
+    assert_eq!(broker.paper.orders().await.unwrap().len(), 2);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "testing")]
+
+use std::time::Duration;
+
+use fyers::testing::{FakeServer, Fault};
+use fyers::{
+    CandleResolution, Endpoint, ExitPositionResult, FyersError, HistoryRequest, ModifyOrderRequest,
+    OrderRequest, OrderStatus, OrderType, ProductType, RetryConfig, RetryPolicy, Side, Validity,
+};
+
+#[tokio::test]
+async fn every_endpoint_has_a_canned_response() {
+    let server = FakeServer::start().await;
+    let fyers = server.client();
+
+    fyers.profile().await.unwrap();
+
+    let history = HistoryRequest::builder(
+        "NSE:SBIN-EQ",
+        fyers::ist_datetime(2021, 1, 1, 0, 0),
+        fyers::ist_datetime(2021, 1, 7, 0, 0),
+    )
+    .resolution(CandleResolution::Day)
+    .build();
+    assert_eq!(fyers.history(&history).await.unwrap().len(), 4);
+
+    assert_eq!(fyers.quotes(&["NSE:SBIN-EQ"]).await.unwrap()[0].bid, 581.15);
+
+    let orders = fyers.orders().await.unwrap();
+    assert_eq!(orders[0].status, OrderStatus::Filled);
+
+    let order = OrderRequest::builder(
+        "NSE:SBIN-EQ",
+        1,
+        OrderType::Market,
+        Side::Buy,
+        ProductType::Intraday,
+        Validity::Day,
+    )
+    .build();
+    let placed = fyers.place_order(&order).await.unwrap();
+
+    let modify = ModifyOrderRequest::builder(&placed.id)
+        .limit_price(580.0)
+        .build();
+    fyers.modify_order(&modify).await.unwrap();
+    assert_eq!(fyers.positions().await.unwrap()[0].net_qty, 1);
+
+    fyers.cancel_order(&placed.id).await.unwrap();
+    fyers
+        .cancel_pending_orders("NSE:SBIN-EQ-INTRADAY")
+        .await
+        .unwrap();
+    assert_eq!(
+        fyers.exit_all_positions().await.unwrap(),
+        ExitPositionResult::Closed
+    );
+    fyers.logout().await.unwrap();
+
+    assert_eq!(server.requests(Endpoint::CancelPendingOrders), 1);
+    assert_eq!(server.requests(Endpoint::ExitAllPositions), 1);
+}
+
+#[tokio::test]
+async fn scripted_faults_are_used_up_in_order() {
+    let server = FakeServer::start().await;
+    let fyers = server.client();
+
+    server.fail_next(Endpoint::Profile, Fault::TokenExpired);
+    server.fail_next(Endpoint::Profile, Fault::ServerError(503));
+
+    assert!(matches!(
+        fyers.profile().await,
+        Err(FyersError::TokenExpired)
+    ));
+    assert!(matches!(
+        fyers.profile().await,
+        Err(FyersError::HttpStatus { status, .. }) if status == 503
+    ));
+    assert!(fyers.profile().await.is_ok());
+
+    // Other endpoints are unaffected
+    server.fail_always(Endpoint::Orders, Fault::InvalidToken);
+    assert!(fyers.profile().await.is_ok());
+    assert!(matches!(
+        fyers.orders().await,
+        Err(FyersError::InvalidToken)
+    ));
+    assert!(matches!(
+        fyers.orders().await,
+        Err(FyersError::InvalidToken)
+    ));
+
+    server.clear_faults();
+    assert!(fyers.orders().await.is_ok());
+}
+
+#[tokio::test]
+async fn rate_limited_requests_are_retried() {
+    let server = FakeServer::start().await;
+    let fyers = server
+        .builder()
+        .retry_config(RetryConfig::new(
+            RetryPolicy::new(3).initial_backoff(Duration::from_millis(1)),
+        ))
+        .build()
+        .unwrap();
+
+    server.fail_times(Endpoint::Profile, Fault::RateLimited, 2);
+
+    fyers.profile().await.unwrap();
+    assert_eq!(server.requests(Endpoint::Profile), 3);
+}
+
+#[tokio::test]
+async fn canned_response_can_be_replaced() {
+    let server = FakeServer::start().await;
+    let fyers = server.client();
+
+    server.set_response(
+        Endpoint::ExitAllPositions,
+        200,
+        r#"{"s":"ok","code":201,"message":"Counter order placed."}"#,
+    );
+
+    assert_eq!(
+        fyers.exit_all_positions().await.unwrap(),
+        ExitPositionResult::PendingCounterOrder
+    );
+}
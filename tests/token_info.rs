@@ -0,0 +1,81 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{Duration, Utc};
+use fyers::auth::{AuthError, TokenInfo, Tokens};
+use fyers::ist_datetime;
+use serde_json::json;
+
+// Build an unsigned JWT with the given claims
+fn jwt(claims: serde_json::Value) -> String {
+    let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256","typ":"JWT"}"#);
+    let payload = URL_SAFE_NO_PAD.encode(claims.to_string());
+
+    format!("{header}.{payload}.signature")
+}
+
+fn tokens(access_token: String) -> Tokens {
+    Tokens {
+        access_token,
+        refresh_token: "REFRESH_TOKEN".into(),
+    }
+}
+
+#[test]
+fn decodes_claims() {
+    let issued_at = ist_datetime(2026, 3, 2, 9, 0);
+    let expires_at = ist_datetime(2026, 3, 3, 9, 0);
+
+    let token = jwt(json!({
+        "iss": "api.fyers.in",
+        "iat": issued_at.timestamp(),
+        "exp": expires_at.timestamp(),
+        "aud": ["d:1", "d:2", "x:0"],
+        "sub": "access_token",
+        "fy_id": "XY12345",
+        "appType": 100,
+    }));
+
+    let info = TokenInfo::decode(&token).unwrap();
+
+    assert_eq!(info.issued_at, issued_at);
+    assert_eq!(info.expires_at, expires_at);
+    assert_eq!(info.fy_id, "XY12345");
+    assert_eq!(info.scopes, ["d:1", "d:2", "x:0"]);
+
+    // Capped at the end of the IST day it was issued on
+    assert_eq!(
+        tokens(token).access_expires_at().unwrap(),
+        ist_datetime(2026, 3, 3, 0, 0)
+    );
+}
+
+#[test]
+fn should_refresh_near_expiry() {
+    let now = Utc::now();
+
+    let fresh = tokens(jwt(json!({
+        "iat": now.timestamp(),
+        "exp": (now + Duration::hours(1)).timestamp(),
+        "fy_id": "XY12345",
+    })));
+
+    let expired = tokens(jwt(json!({
+        "iat": (now - Duration::hours(2)).timestamp(),
+        "exp": (now - Duration::hours(1)).timestamp(),
+        "fy_id": "XY12345",
+    })));
+
+    assert!(!fresh.should_refresh(Duration::zero()));
+    assert!(fresh.should_refresh(Duration::hours(2)));
+    assert!(expired.should_refresh(Duration::zero()));
+}
+
+#[test]
+fn malformed_tokens_are_rejected() {
+    for token in ["not-a-jwt", "header.!!!.signature", "header.e30.signature"] {
+        let err = TokenInfo::decode(token).unwrap_err();
+        assert!(matches!(err, AuthError::MalformedToken(_)), "{token}");
+    }
+
+    assert!(tokens("not-a-jwt".into()).should_refresh(Duration::zero()));
+}
@@ -0,0 +1,179 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fyers::auth::{AuthError, RefreshFuture, TokenProvider, Tokens};
+use fyers::{Fyers, FyersError};
+use httpmock::prelude::*;
+
+const PROFILE_SUCCESS: &str = include_str!("fixtures/profile_success.json");
+const TOKEN_EXPIRED: &str = include_str!("fixtures/error/token_expired.json");
+
+// Hands out a fixed access token and counts how often it was asked to
+struct StaticProvider {
+    access_token: &'static str,
+    calls: Arc<AtomicUsize>,
+}
+
+impl TokenProvider for StaticProvider {
+    fn refresh(&self) -> RefreshFuture<'_> {
+        Box::pin(async move {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+
+            Ok(Tokens {
+                access_token: self.access_token.into(),
+                refresh_token: "REFRESH_TOKEN".into(),
+            })
+        })
+    }
+}
+
+struct FailingProvider;
+
+impl TokenProvider for FailingProvider {
+    fn refresh(&self) -> RefreshFuture<'_> {
+        Box::pin(async {
+            Err(AuthError::Api {
+                code: -501,
+                message: "invalid refresh token".into(),
+            })
+        })
+    }
+}
+
+async fn setup(provider: impl TokenProvider + 'static) -> (MockServer, Fyers) {
+    let server = MockServer::start_async().await;
+
+    let fyers = Fyers::builder("TEST_CLIENT_ID", "OLD_TOKEN")
+        .api_url(server.base_url())
+        .data_url(server.base_url())
+        .token_provider(provider)
+        .build()
+        .unwrap();
+
+    server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/profile")
+                .header("Authorization", "TEST_CLIENT_ID:OLD_TOKEN");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(TOKEN_EXPIRED);
+        })
+        .await;
+
+    (server, fyers)
+}
+
+#[tokio::test]
+async fn expired_token_is_refreshed_and_request_retried() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let (server, fyers) = setup(StaticProvider {
+        access_token: "NEW_TOKEN",
+        calls: calls.clone(),
+    })
+    .await;
+
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/profile")
+                .header("Authorization", "TEST_CLIENT_ID:NEW_TOKEN");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PROFILE_SUCCESS);
+        })
+        .await;
+
+    let clone = fyers.clone();
+    fyers.profile().await.unwrap();
+
+    // The clone picks up the new token without refreshing again
+    clone.profile().await.unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    mock.assert_calls(2);
+}
+
+#[tokio::test]
+async fn concurrent_requests_refresh_once() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let (server, fyers) = setup(StaticProvider {
+        access_token: "NEW_TOKEN",
+        calls: calls.clone(),
+    })
+    .await;
+
+    server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/profile")
+                .header("Authorization", "TEST_CLIENT_ID:NEW_TOKEN");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PROFILE_SUCCESS);
+        })
+        .await;
+
+    let clone = fyers.clone();
+    let (a, b) = tokio::join!(fyers.profile(), clone.profile());
+
+    a.unwrap();
+    b.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn request_is_only_retried_once() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let (_server, fyers) = setup(StaticProvider {
+        access_token: "OLD_TOKEN",
+        calls: calls.clone(),
+    })
+    .await;
+
+    let result = fyers.profile().await;
+
+    assert!(matches!(result, Err(FyersError::TokenExpired)));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn refresh_failure_is_reported() {
+    let (_server, fyers) = setup(FailingProvider).await;
+
+    let result = fyers.profile().await;
+
+    assert!(matches!(
+        result,
+        Err(FyersError::TokenRefresh(AuthError::Api { code: -501, .. }))
+    ));
+}
+
+#[tokio::test]
+async fn access_token_can_be_updated_manually() {
+    let server = MockServer::start_async().await;
+    let fyers = Fyers::builder("TEST_CLIENT_ID", "OLD_TOKEN")
+        .api_url(server.base_url())
+        .build()
+        .unwrap();
+
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/profile")
+                .header("Authorization", "TEST_CLIENT_ID:NEW_TOKEN");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PROFILE_SUCCESS);
+        })
+        .await;
+
+    fyers.clone().update_access_token("NEW_TOKEN").unwrap();
+    fyers.profile().await.unwrap();
+
+    mock.assert();
+}
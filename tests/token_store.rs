@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+
+use chrono::Duration;
+use fyers::auth::{FileTokenStore, MemoryTokenStore, StoredTokens, TokenStore, Tokens};
+
+fn tokens(access_token: &str) -> Tokens {
+    Tokens {
+        access_token: access_token.into(),
+        refresh_token: "REFRESH_TOKEN".into(),
+    }
+}
+
+// A unique path in the temp directory that is removed afterwards
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("fyers-{}-{name}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        Self(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn expiry_follows_ist_day() {
+    let issued_at = fyers::ist_datetime(2026, 2, 5, 22, 30);
+    let stored = StoredTokens::issued_at(tokens("ACCESS"), issued_at);
+
+    assert_eq!(
+        stored.access_expires_at,
+        fyers::ist_datetime(2026, 2, 6, 0, 0)
+    );
+    assert_eq!(stored.refresh_expires_at, issued_at + Duration::days(15));
+}
+
+#[test]
+fn refresh_keeps_refresh_token_expiry() {
+    let issued_at = fyers::ist_datetime(2026, 2, 5, 9, 0);
+    let stored = StoredTokens::issued_at(tokens("OLD"), issued_at);
+
+    let refreshed = stored.refreshed(tokens("NEW"));
+
+    assert_eq!(refreshed.tokens.access_token, "NEW");
+    assert_eq!(refreshed.refresh_expires_at, stored.refresh_expires_at);
+    assert!(!refreshed.is_access_expired());
+    assert!(stored.is_access_expired());
+}
+
+#[test]
+fn memory_store_round_trip() {
+    let store = MemoryTokenStore::new();
+    assert_eq!(store.load().unwrap(), None);
+
+    let stored = StoredTokens::new(tokens("ACCESS"));
+    store.save(&stored).unwrap();
+
+    assert_eq!(store.load().unwrap(), Some(stored));
+}
+
+#[test]
+fn file_store_round_trip() {
+    let file = TempFile::new("plain");
+    let store = FileTokenStore::new(&file.0);
+    assert_eq!(store.load().unwrap(), None);
+
+    let stored = StoredTokens::new(tokens("ACCESS"));
+    store.save(&stored).unwrap();
+
+    assert_eq!(store.load().unwrap(), Some(stored.clone()));
+
+    // Saving again replaces the file
+    let refreshed = stored.refreshed(tokens("NEW_ACCESS"));
+    store.save(&refreshed).unwrap();
+    assert_eq!(store.load().unwrap(), Some(refreshed));
+}
+
+#[cfg(feature = "encrypted-store")]
+#[test]
+fn encrypted_file_store_round_trip() {
+    use fyers::auth::TokenStoreError;
+
+    let file = TempFile::new("encrypted");
+    // Keep the test fast, the default is much higher
+    let store = FileTokenStore::encrypted(&file.0, "correct horse").kdf_rounds(1_000);
+
+    let stored = StoredTokens::new(tokens("SECRET_ACCESS_TOKEN"));
+    store.save(&stored).unwrap();
+
+    let contents = std::fs::read_to_string(&file.0).unwrap();
+    assert!(!contents.contains("SECRET_ACCESS_TOKEN"));
+
+    assert_eq!(store.load().unwrap(), Some(stored));
+
+    let wrong = FileTokenStore::encrypted(&file.0, "battery staple");
+    assert!(matches!(wrong.load(), Err(TokenStoreError::Decryption)));
+}
+
+#[cfg(feature = "encrypted-store")]
+#[test]
+fn kdf_rounds_are_bounded() {
+    use fyers::auth::TokenStoreError;
+
+    let file = TempFile::new("kdf-rounds");
+    let stored = StoredTokens::new(tokens("SECRET_ACCESS_TOKEN"));
+
+    for rounds in [0, 999, 10_000_001] {
+        let store = FileTokenStore::encrypted(&file.0, "correct horse").kdf_rounds(rounds);
+        assert!(matches!(
+            store.save(&stored),
+            Err(TokenStoreError::InvalidKdfRounds(r)) if r == rounds
+        ));
+    }
+
+    // A file asking for too many rounds is rejected before deriving a key
+    let store = FileTokenStore::encrypted(&file.0, "correct horse").kdf_rounds(1_000);
+    store.save(&stored).unwrap();
+    let contents = std::fs::read_to_string(&file.0).unwrap();
+    let tampered = contents.replace("\"rounds\": 1000", "\"rounds\": 4000000000");
+    assert_ne!(contents, tampered);
+    std::fs::write(&file.0, tampered).unwrap();
+
+    assert!(matches!(
+        store.load(),
+        Err(TokenStoreError::InvalidKdfRounds(4_000_000_000))
+    ));
+}
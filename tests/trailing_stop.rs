@@ -0,0 +1,319 @@
+mod common;
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use common::{SBIN, find, time};
+use fyers::exits::{Trail, TrailingEvent, TrailingStatus, TrailingStop};
+use fyers::paper::PaperTrader;
+use fyers::{
+    Broker, ExitPositionResult, FyersError, ModifyOrderRequest, Order, OrderDetails, OrderRequest,
+    OrderStatus, OrderType, Position, ProductType, Side,
+};
+
+// A unique path in the temp directory that is removed afterwards
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("fyers-{}-{name}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        Self(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn long_stop(trail: Trail) -> TrailingStop {
+    TrailingStop::new(SBIN, Side::Buy, 100, ProductType::Intraday, trail, 580.0)
+        .unwrap()
+        .tick_size(0.05)
+}
+
+#[tokio::test]
+async fn long_stop_follows_the_high() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 580.0);
+
+    let mut stop = long_stop(Trail::Points(10.0));
+    stop.start(&paper).await.unwrap();
+
+    let id = stop.state().stop_order_id.clone().unwrap();
+    let order = find(&paper, &id).await;
+    assert_eq!(order.order_type, OrderType::Stop);
+    assert_eq!(order.side, Side::Sell);
+    assert_eq!(order.stop_price, 570.0);
+
+    let event = stop.update(&paper, 585.0).await.unwrap();
+    assert_eq!(event, TrailingEvent::Moved { stop_price: 575.0 });
+    assert_eq!(find(&paper, &id).await.stop_price, 575.0);
+
+    // A pullback leaves the stop where it is
+    let event = stop.update(&paper, 582.0).await.unwrap();
+    assert_eq!(event, TrailingEvent::Unchanged);
+    assert_eq!(stop.state().best_price, 585.0);
+    assert_eq!(find(&paper, &id).await.stop_price, 575.0);
+}
+
+#[tokio::test]
+async fn short_stop_trails_by_percent() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 500.0);
+
+    let mut stop = TrailingStop::new(
+        SBIN,
+        Side::Sell,
+        50,
+        ProductType::Intraday,
+        Trail::Percent(1.0),
+        500.0,
+    )
+    .unwrap()
+    .tick_size(0.05);
+
+    assert_eq!(stop.state().stop_price, 505.0);
+
+    // 491.3 * 1.01 = 496.213, rounded up to a whole tick
+    let event = stop.update(&paper, 491.3).await.unwrap();
+    assert_eq!(event, TrailingEvent::Moved { stop_price: 496.25 });
+
+    let id = stop.state().stop_order_id.clone().unwrap();
+    let order = find(&paper, &id).await;
+    assert_eq!(order.side, Side::Buy);
+    assert_eq!(order.stop_price, 496.25);
+}
+
+#[tokio::test]
+async fn filled_stop_order_exits_the_position() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 580.0);
+
+    let mut stop = long_stop(Trail::Points(10.0));
+    stop.update(&paper, 590.0).await.unwrap();
+
+    // The exchange fills the stop order
+    paper.update_tick(SBIN, time(1), 579.0);
+
+    let event = stop.update(&paper, 579.0).await.unwrap();
+    let id = stop.state().stop_order_id.clone().unwrap();
+    assert_eq!(event, TrailingEvent::Exited { order_id: id });
+    assert_eq!(stop.state().status, TrailingStatus::Exited);
+
+    // No other order was needed
+    assert_eq!(paper.orders().await.unwrap().len(), 1);
+
+    // Later prices are ignored
+    let event = stop.update(&paper, 600.0).await.unwrap();
+    assert_eq!(event, TrailingEvent::Unchanged);
+}
+
+#[tokio::test]
+async fn triggered_stop_places_an_exit_order() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 580.0);
+
+    let mut stop = long_stop(Trail::Points(10.0)).order_tag("trail");
+    stop.start(&paper).await.unwrap();
+    let stop_id = stop.state().stop_order_id.clone().unwrap();
+
+    // The feed sees the stop before the exchange triggers it
+    let event = stop.update(&paper, 569.0).await.unwrap();
+    let TrailingEvent::Exited { order_id } = event else {
+        panic!("expected an exit, got {event:?}");
+    };
+
+    assert_ne!(order_id, stop_id);
+    assert_eq!(find(&paper, &stop_id).await.status, OrderStatus::Cancelled);
+
+    let exit = find(&paper, &order_id).await;
+    assert_eq!(exit.order_type, OrderType::Market);
+    assert_eq!(exit.side, Side::Sell);
+    assert_eq!(exit.qty, 100);
+    assert_eq!(exit.tag(), Some("trailX"));
+    assert_eq!(find(&paper, &stop_id).await.tag(), Some("trailS"));
+    assert_eq!(
+        stop.state().exit_order_id.as_deref(),
+        Some(order_id.as_str())
+    );
+}
+
+#[tokio::test]
+async fn cancelled_stop_order_is_replaced_by_an_exit() {
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 580.0);
+
+    let mut stop = long_stop(Trail::Points(10.0));
+    stop.start(&paper).await.unwrap();
+    let stop_id = stop.state().stop_order_id.clone().unwrap();
+
+    paper.cancel_order(&stop_id).await.unwrap();
+
+    // Moving the stop fails, so the position is exited instead
+    let event = stop.update(&paper, 590.0).await.unwrap();
+    let TrailingEvent::Exited { order_id } = event else {
+        panic!("expected an exit, got {event:?}");
+    };
+    assert_eq!(find(&paper, &order_id).await.order_type, OrderType::Market);
+}
+
+#[tokio::test]
+async fn state_survives_a_restart() {
+    let file = TempFile::new("trailing-stop");
+    let paper = PaperTrader::new(100_000.0);
+    paper.update_tick(SBIN, time(0), 580.0);
+
+    assert!(TrailingStop::resume(&file.0).unwrap().is_none());
+
+    let mut stop = long_stop(Trail::Points(10.0)).persist(&file.0);
+    stop.update(&paper, 585.0).await.unwrap();
+    let state = stop.state().clone();
+    drop(stop);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&file.0).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    let mut stop = TrailingStop::resume(&file.0).unwrap().unwrap();
+    assert_eq!(stop.state(), &state);
+
+    // The existing stop order is moved rather than a new one placed
+    let event = stop.update(&paper, 590.0).await.unwrap();
+    assert_eq!(event, TrailingEvent::Moved { stop_price: 580.0 });
+    assert_eq!(paper.orders().await.unwrap().len(), 1);
+
+    let saved = TrailingStop::resume(&file.0).unwrap().unwrap();
+    assert_eq!(saved.state().stop_price, 580.0);
+    assert_eq!(saved.state().best_price, 590.0);
+}
+
+#[tokio::test]
+async fn interrupted_orders_are_not_sent_twice() {
+    let file = TempFile::new("trailing-stop-interrupted");
+    let broker = LostResponses::default();
+    broker.paper.update_tick(SBIN, time(0), 580.0);
+
+    // The stop order reaches the exchange, but the response doesn't
+    // reach us
+    broker.lose_next.store(true, Ordering::SeqCst);
+    let mut stop = long_stop(Trail::Points(10.0)).persist(&file.0);
+    assert!(stop.start(&broker).await.is_err());
+    assert_eq!(stop.state().status, TrailingStatus::Placing);
+    drop(stop);
+
+    // The resumed stop finds the order by its tag
+    let mut stop = TrailingStop::resume(&file.0).unwrap().unwrap();
+    stop.start(&broker).await.unwrap();
+    assert_eq!(stop.state().status, TrailingStatus::Active);
+    assert_eq!(broker.paper.orders().await.unwrap().len(), 1);
+
+    // Same for the exit order
+    broker.lose_next.store(true, Ordering::SeqCst);
+    assert!(stop.update(&broker, 569.0).await.is_err());
+    assert_eq!(stop.state().status, TrailingStatus::Exiting);
+    drop(stop);
+
+    let mut stop = TrailingStop::resume(&file.0).unwrap().unwrap();
+    let event = stop.update(&broker, 569.0).await.unwrap();
+    let TrailingEvent::Exited { order_id } = event else {
+        panic!("expected an exit, got {event:?}");
+    };
+
+    let orders = broker.paper.orders().await.unwrap();
+    assert_eq!(orders.len(), 2);
+    assert_eq!(find(&broker.paper, &order_id).await.qty, 100);
+}
+
+#[test]
+fn invalid_trails_are_rejected() {
+    let new = |trail| TrailingStop::new(SBIN, Side::Buy, 100, ProductType::Intraday, trail, 580.0);
+
+    assert!(matches!(
+        new(Trail::Points(0.0)),
+        Err(FyersError::InvalidParams(_))
+    ));
+    assert!(matches!(
+        new(Trail::Percent(100.0)),
+        Err(FyersError::InvalidParams(_))
+    ));
+    assert!(matches!(
+        TrailingStop::new(
+            SBIN,
+            Side::Buy,
+            0,
+            ProductType::Intraday,
+            Trail::Points(1.0),
+            580.0
+        ),
+        Err(FyersError::InvalidParams(_))
+    ));
+}
+
+// A paper trader that can place an order and then fail as if the response
+// was lost
+struct LostResponses {
+    paper: PaperTrader,
+    lose_next: AtomicBool,
+}
+
+impl Default for LostResponses {
+    fn default() -> Self {
+        Self {
+            paper: PaperTrader::new(100_000.0),
+            lose_next: AtomicBool::new(false),
+        }
+    }
+}
+
+impl Broker for LostResponses {
+    fn place_order(
+        &self,
+        order: &OrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        let placed = self.paper.place_order(order);
+        let lost = self.lose_next.swap(false, Ordering::SeqCst);
+
+        async move {
+            let placed = placed.await?;
+            if lost {
+                return Err(FyersError::Api {
+                    code: -1,
+                    message: "connection reset".into(),
+                });
+            }
+            Ok(placed)
+        }
+    }
+
+    fn cancel_order(&self, order_id: &str) -> impl Future<Output = Result<(), FyersError>> + Send {
+        self.paper.cancel_order(order_id)
+    }
+
+    fn modify_order(
+        &self,
+        modify: &ModifyOrderRequest,
+    ) -> impl Future<Output = Result<Order, FyersError>> + Send {
+        self.paper.modify_order(modify)
+    }
+
+    fn orders(&self) -> impl Future<Output = Result<Vec<OrderDetails>, FyersError>> + Send {
+        self.paper.orders()
+    }
+
+    fn positions(&self) -> impl Future<Output = Result<Vec<Position>, FyersError>> + Send {
+        self.paper.positions()
+    }
+
+    fn exit_all_positions(
+        &self,
+    ) -> impl Future<Output = Result<ExitPositionResult, FyersError>> + Send {
+        self.paper.exit_all_positions()
+    }
+}
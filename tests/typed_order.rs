@@ -0,0 +1,93 @@
+use fyers::{Entry, OrderRequest, OrderType, ProductType, Side, Validity};
+use serde_json::Value;
+
+const SBIN: &str = "NSE:SBIN-EQ";
+
+fn json(order: &OrderRequest) -> Value {
+    serde_json::to_value(order).unwrap()
+}
+
+fn builder(order_type: OrderType, product_type: ProductType) -> fyers::OrderBuilder {
+    OrderRequest::builder(SBIN, 10, order_type, Side::Buy, product_type, Validity::Day)
+}
+
+#[test]
+fn regular_orders_match_the_builder() {
+    let cases = [
+        (
+            OrderRequest::market(SBIN, 10, Side::Buy, ProductType::Intraday).build(),
+            builder(OrderType::Market, ProductType::Intraday).build(),
+        ),
+        (
+            OrderRequest::limit(SBIN, 10, Side::Buy, ProductType::Cnc, 580.5).build(),
+            builder(OrderType::Limit, ProductType::Cnc)
+                .limit_price(580.5)
+                .build(),
+        ),
+        (
+            OrderRequest::stop(SBIN, 10, Side::Buy, ProductType::Intraday, 590.0).build(),
+            builder(OrderType::Stop, ProductType::Intraday)
+                .stop_price(590.0)
+                .build(),
+        ),
+        (
+            OrderRequest::stop_limit(SBIN, 10, Side::Buy, ProductType::Intraday, 590.0, 591.0)
+                .build(),
+            builder(OrderType::StopLimit, ProductType::Intraday)
+                .stop_price(590.0)
+                .limit_price(591.0)
+                .build(),
+        ),
+    ];
+
+    for (typed, built) in cases {
+        assert_eq!(json(&typed), json(&built));
+    }
+}
+
+#[test]
+fn bracket_and_cover_orders_match_the_builder() {
+    let bracket = OrderRequest::bracket(SBIN, 10, Side::Buy, Entry::Limit(580.0), 5.0, 10.0)
+        .order_tag("bo1")
+        .build();
+    let expected = builder(OrderType::Limit, ProductType::Bo)
+        .limit_price(580.0)
+        .stop_loss(5.0)
+        .take_profit(10.0)
+        .order_tag("bo1")
+        .build();
+    assert_eq!(json(&bracket), json(&expected));
+
+    let cover = OrderRequest::cover(SBIN, 10, Side::Buy, Entry::Market, 5.0).build();
+    let expected = builder(OrderType::Market, ProductType::Co)
+        .stop_loss(5.0)
+        .build();
+    assert_eq!(json(&cover), json(&expected));
+}
+
+#[test]
+fn regular_options_can_be_set() {
+    let order = OrderRequest::market(SBIN, 10, Side::Sell, ProductType::Cnc)
+        .validity(Validity::Ioc)
+        .disclosed_qty(5)
+        .offline_order(true)
+        .slice_order(true)
+        .build();
+
+    let json = json(&order);
+    assert_eq!(json["validity"], "IOC");
+    assert_eq!(json["disclosedQty"], 5);
+    assert_eq!(json["offlineOrder"], true);
+    assert_eq!(json["isSliceOrder"], true);
+}
+
+#[test]
+fn typed_orders_pass_validation() {
+    let orders = [
+        OrderRequest::limit(SBIN, 10, Side::Buy, ProductType::Intraday, 580.5).try_build(),
+        OrderRequest::bracket(SBIN, 10, Side::Sell, Entry::Market, 5.0, 10.0).try_build(),
+        OrderRequest::cover(SBIN, 10, Side::Sell, Entry::Limit(580.0), 5.0).try_build(),
+    ];
+
+    assert!(orders.iter().all(Result::is_ok));
+}